@@ -0,0 +1,207 @@
+//! Minimal archive writers for `bevy package`.
+//!
+//! Distributables only need two formats — zip and ustar tar — and both are
+//! simple enough to write directly, keeping the CLI dependency-free. Zip
+//! entries are stored uncompressed: game binaries and assets are large and
+//! mostly incompressible, and every platform can open a stored zip.
+
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+
+/// Writes `root`'s contents (recursively) as a stored zip at `dest`.
+pub fn zip_dir(root: &Path, dest: &Path) -> anyhow::Result<()> {
+    let mut out = Vec::new();
+    let mut central = Vec::new();
+    let mut count = 0u16;
+    for (relative, absolute) in files_under(root)? {
+        let data = std::fs::read(&absolute)
+            .with_context(|| format!("failed to read {}", absolute.display()))?;
+        let name = relative.to_string_lossy().replace('\\', "/");
+        let crc = crc32(&data);
+        let offset = out.len() as u32;
+        let size = data.len() as u32;
+
+        out.extend_from_slice(&0x0403_4b50u32.to_le_bytes());
+        out.extend_from_slice(&20u16.to_le_bytes()); // version needed
+        out.extend_from_slice(&[0; 6]); // flags, method (store), time, date
+        out.extend_from_slice(&crc.to_le_bytes());
+        out.extend_from_slice(&size.to_le_bytes());
+        out.extend_from_slice(&size.to_le_bytes());
+        out.extend_from_slice(&(name.len() as u16).to_le_bytes());
+        out.extend_from_slice(&0u16.to_le_bytes()); // extra length
+        out.extend_from_slice(name.as_bytes());
+        out.extend_from_slice(&data);
+
+        central.extend_from_slice(&0x0201_4b50u32.to_le_bytes());
+        central.extend_from_slice(&0x031eu16.to_le_bytes()); // made by: unix
+        central.extend_from_slice(&20u16.to_le_bytes());
+        central.extend_from_slice(&[0; 6]);
+        central.extend_from_slice(&crc.to_le_bytes());
+        central.extend_from_slice(&size.to_le_bytes());
+        central.extend_from_slice(&size.to_le_bytes());
+        central.extend_from_slice(&(name.len() as u16).to_le_bytes());
+        central.extend_from_slice(&[0; 8]); // extra, comment, disk, internal
+        central.extend_from_slice(&(unix_mode(&absolute) << 16).to_le_bytes());
+        central.extend_from_slice(&offset.to_le_bytes());
+        central.extend_from_slice(name.as_bytes());
+        count += 1;
+    }
+    let central_offset = out.len() as u32;
+    out.extend_from_slice(&central);
+    out.extend_from_slice(&0x0605_4b50u32.to_le_bytes());
+    out.extend_from_slice(&[0; 4]); // disk numbers
+    out.extend_from_slice(&count.to_le_bytes());
+    out.extend_from_slice(&count.to_le_bytes());
+    out.extend_from_slice(&(central.len() as u32).to_le_bytes());
+    out.extend_from_slice(&central_offset.to_le_bytes());
+    out.extend_from_slice(&0u16.to_le_bytes()); // comment length
+    write_archive(dest, &out)
+}
+
+/// Writes `root`'s contents (recursively) as a POSIX ustar tar at `dest`.
+pub fn tar_dir(root: &Path, dest: &Path) -> anyhow::Result<()> {
+    let mut out = Vec::new();
+    for (relative, absolute) in files_under(root)? {
+        let data = std::fs::read(&absolute)
+            .with_context(|| format!("failed to read {}", absolute.display()))?;
+        let name = relative.to_string_lossy().replace('\\', "/");
+        anyhow::ensure!(name.len() <= 100, "path too long for tar: {name}");
+
+        let mut header = [0u8; 512];
+        header[..name.len()].copy_from_slice(name.as_bytes());
+        write_octal(&mut header[100..108], u64::from(unix_mode(&absolute)));
+        write_octal(&mut header[108..116], 0); // uid
+        write_octal(&mut header[116..124], 0); // gid
+        write_octal(&mut header[124..136], data.len() as u64);
+        write_octal(&mut header[136..148], 0); // mtime
+        header[148..156].fill(b' '); // checksum placeholder
+        header[156] = b'0'; // regular file
+        header[257..263].copy_from_slice(b"ustar\0");
+        header[263..265].copy_from_slice(b"00");
+        let checksum: u64 = header.iter().map(|&byte| u64::from(byte)).sum();
+        write_octal(&mut header[148..155], checksum);
+        header[155] = b' ';
+
+        out.extend_from_slice(&header);
+        out.extend_from_slice(&data);
+        let padding = (512 - data.len() % 512) % 512;
+        out.extend_from_slice(&vec![0; padding]);
+    }
+    out.extend_from_slice(&[0; 1024]); // end-of-archive marker
+    write_archive(dest, &out)
+}
+
+fn write_archive(dest: &Path, bytes: &[u8]) -> anyhow::Result<()> {
+    let mut file = std::fs::File::create(dest)
+        .with_context(|| format!("failed to create {}", dest.display()))?;
+    file.write_all(bytes)
+        .with_context(|| format!("failed to write {}", dest.display()))?;
+    Ok(())
+}
+
+/// Every file under `root`, sorted, as `(relative, absolute)` pairs.
+fn files_under(root: &Path) -> anyhow::Result<Vec<(PathBuf, PathBuf)>> {
+    let mut files = Vec::new();
+    let mut stack = vec![root.to_path_buf()];
+    while let Some(dir) = stack.pop() {
+        for entry in
+            std::fs::read_dir(&dir).with_context(|| format!("failed to read {}", dir.display()))?
+        {
+            let path = entry?.path();
+            if path.is_dir() {
+                stack.push(path);
+            } else {
+                let relative = path
+                    .strip_prefix(root)
+                    .expect("walked paths sit under the root")
+                    .to_path_buf();
+                files.push((relative, path));
+            }
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+/// `0o100755` for executables, `0o100644` otherwise; zip external
+/// attributes and tar modes both want the full unix file mode.
+fn unix_mode(path: &Path) -> u32 {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        if let Ok(metadata) = path.metadata() {
+            if metadata.permissions().mode() & 0o111 != 0 {
+                return 0o100_755;
+            }
+        }
+    }
+    #[cfg(not(unix))]
+    let _ = path;
+    0o100_644
+}
+
+/// `value` as a NUL-terminated octal field of the given width.
+fn write_octal(field: &mut [u8], value: u64) {
+    let octal = format!("{value:0width$o}", width = field.len() - 1);
+    field[..octal.len()].copy_from_slice(octal.as_bytes());
+}
+
+/// CRC-32 (IEEE, reflected), as the zip format requires.
+pub(crate) fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for &byte in bytes {
+        crc ^= u32::from(byte);
+        for _ in 0..8 {
+            let mask = (crc & 1).wrapping_neg();
+            crc = (crc >> 1) ^ (0xedb8_8320 & mask);
+        }
+    }
+    !crc
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fixture(label: &str) -> PathBuf {
+        let root = std::env::temp_dir().join(format!("bevy_cli_archive_{label}"));
+        let _ = std::fs::remove_dir_all(&root);
+        std::fs::create_dir_all(root.join("assets")).unwrap();
+        std::fs::write(root.join("game"), b"binary").unwrap();
+        std::fs::write(root.join("assets/level.ron"), b"(rooms: 3)").unwrap();
+        root
+    }
+
+    #[test]
+    fn crc32_matches_the_reference_vector() {
+        assert_eq!(crc32(b"123456789"), 0xcbf4_3926);
+    }
+
+    #[test]
+    fn zips_carry_local_and_central_records() {
+        let root = fixture("zip");
+        let dest = root.with_extension("zip");
+        zip_dir(&root, &dest).unwrap();
+        let bytes = std::fs::read(&dest).unwrap();
+        assert_eq!(&bytes[..4], &0x0403_4b50u32.to_le_bytes());
+        let eocd = &bytes[bytes.len() - 22..];
+        assert_eq!(&eocd[..4], &0x0605_4b50u32.to_le_bytes());
+        assert_eq!(u16::from_le_bytes([eocd[10], eocd[11]]), 2); // entries
+        std::fs::remove_dir_all(&root).unwrap();
+        std::fs::remove_file(&dest).unwrap();
+    }
+
+    #[test]
+    fn tars_are_block_aligned_ustar() {
+        let root = fixture("tar");
+        let dest = root.with_extension("tar");
+        tar_dir(&root, &dest).unwrap();
+        let bytes = std::fs::read(&dest).unwrap();
+        assert_eq!(bytes.len() % 512, 0);
+        assert_eq!(&bytes[257..262], b"ustar");
+        std::fs::remove_dir_all(&root).unwrap();
+        std::fs::remove_file(&dest).unwrap();
+    }
+}
@@ -0,0 +1,246 @@
+//! The programmatic project-creation API: a builder over the same options
+//! `bevy new` exposes as flags, so embedders and the CLI cannot drift apart.
+
+use std::path::PathBuf;
+
+use crate::commands::new::{self, NewArgs, ProjectKind, TargetPlatform, Vcs};
+use crate::output::{self, ProgressEvent};
+use crate::scaffold::{GitignoreSection, Layout};
+
+/// Builds a new Bevy project programmatically.
+///
+/// Every option mirrors a `bevy new` flag and defaults the same way; see
+/// the crate-level example. [`create`](Self::create) runs the identical
+/// generation path as the CLI, including atomic staging: on failure nothing
+/// is left behind at the target.
+pub struct ProjectBuilder {
+    args: NewArgs,
+}
+
+impl ProjectBuilder {
+    /// Starts a builder for a project named `name`, which is validated
+    /// against cargo's crate-name rules when [`create`](Self::create) runs.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            args: NewArgs {
+                name: Some(name.into()),
+                interactive: false,
+                replay: None,
+                i_know_what_im_doing: false,
+                template: None,
+                workspace: false,
+                kind: ProjectKind::Game,
+                with_assets: false,
+                with_states: false,
+                with_examples: false,
+                with_benches: false,
+                bevy_version: "latest".to_string(),
+                bevy_features: Vec::new(),
+                author: None,
+                email: None,
+                target_dir: None,
+                toolchain: None,
+                fast_compiles: false,
+                edition: "2021".to_string(),
+                msrv: None,
+                ci: false,
+                ci_provider: crate::scaffold::ContinuousIntegration::default(),
+                ci_options: crate::scaffold::CiOptions::default(),
+                vcs: Vcs::None,
+                xtask: false,
+                bins: Vec::new(),
+                target: None,
+                layout: Layout::Flat,
+                with_editor_config: false,
+                gitignore: Vec::new(),
+                builtin_only: false,
+                extra_context: Vec::new(),
+                vars: Vec::new(),
+            },
+        }
+    }
+
+    /// Uses a template directory or installed template name instead of the
+    /// built-in default.
+    pub fn template(mut self, template: impl Into<PathBuf>) -> Self {
+        self.args.template = Some(template.into());
+        self
+    }
+
+    /// Generates a workspace (game binary plus gameplay library crate).
+    pub fn workspace(mut self, workspace: bool) -> Self {
+        self.args.workspace = workspace;
+        self
+    }
+
+    pub fn kind(mut self, kind: ProjectKind) -> Self {
+        self.args.kind = kind;
+        self
+    }
+
+    /// An explicit Bevy version requirement, or `latest`.
+    pub fn bevy_version(mut self, version: impl Into<String>) -> Self {
+        self.args.bevy_version = version.into();
+        self
+    }
+
+    pub fn bevy_features(mut self, features: impl IntoIterator<Item = String>) -> Self {
+        self.args.bevy_features = features.into_iter().collect();
+        self
+    }
+
+    pub fn author(mut self, author: impl Into<String>) -> Self {
+        self.args.author = Some(author.into());
+        self
+    }
+
+    pub fn email(mut self, email: impl Into<String>) -> Self {
+        self.args.email = Some(email.into());
+        self
+    }
+
+    /// Creates the project here instead of `./<name>`.
+    pub fn target_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.args.target_dir = Some(dir.into());
+        self
+    }
+
+    pub fn toolchain(mut self, channel: impl Into<String>) -> Self {
+        self.args.toolchain = Some(channel.into());
+        self
+    }
+
+    pub fn fast_compiles(mut self, fast_compiles: bool) -> Self {
+        self.args.fast_compiles = fast_compiles;
+        self
+    }
+
+    pub fn edition(mut self, edition: impl Into<String>) -> Self {
+        self.args.edition = edition.into();
+        self
+    }
+
+    pub fn msrv(mut self, msrv: impl Into<String>) -> Self {
+        self.args.msrv = Some(msrv.into());
+        self
+    }
+
+    pub fn ci(mut self, ci: bool) -> Self {
+        self.args.ci = ci;
+        self
+    }
+
+    pub fn vcs(mut self, vcs: Vcs) -> Self {
+        self.args.vcs = vcs;
+        self
+    }
+
+    pub fn xtask(mut self, xtask: bool) -> Self {
+        self.args.xtask = xtask;
+        self
+    }
+
+    /// Adds a `[[bin]]` target, `name` or `name:feature+feature`.
+    pub fn bin(mut self, spec: impl Into<String>) -> Self {
+        self.args.bins.push(spec.into());
+        self
+    }
+
+    pub fn target(mut self, target: TargetPlatform) -> Self {
+        self.args.target = Some(target);
+        self
+    }
+
+    pub fn layout(mut self, layout: Layout) -> Self {
+        self.args.layout = layout;
+        self
+    }
+
+    pub fn editor_config(mut self, editor_config: bool) -> Self {
+        self.args.with_editor_config = editor_config;
+        self
+    }
+
+    pub fn gitignore(mut self, sections: impl IntoIterator<Item = GitignoreSection>) -> Self {
+        self.args.gitignore = sections.into_iter().collect();
+        self
+    }
+
+    pub fn with_assets(mut self, with_assets: bool) -> Self {
+        self.args.with_assets = with_assets;
+        self
+    }
+
+    pub fn with_states(mut self, with_states: bool) -> Self {
+        self.args.with_states = with_states;
+        self
+    }
+
+    pub fn with_examples(mut self, with_examples: bool) -> Self {
+        self.args.with_examples = with_examples;
+        self
+    }
+
+    pub fn with_benches(mut self, with_benches: bool) -> Self {
+        self.args.with_benches = with_benches;
+        self
+    }
+
+    /// Skips the refreshed official template and uses the embedded one.
+    pub fn builtin_only(mut self, builtin_only: bool) -> Self {
+        self.args.builtin_only = builtin_only;
+        self
+    }
+
+    /// Sets a template variable, the library form of `--var key=value`.
+    pub fn var(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.args
+            .vars
+            .push(format!("{}={}", key.into(), value.into()));
+        self
+    }
+
+    /// Routes progress events to `callback` instead of the terminal. The
+    /// hook is process-wide and the first one installed wins, matching how
+    /// the CLI configures output once at startup.
+    pub fn progress(self, callback: impl Fn(&ProgressEvent) + Send + Sync + 'static) -> Self {
+        output::set_progress_hook(callback);
+        self
+    }
+
+    /// The assembled `NewArgs`, for tests that need a fully-defaulted set.
+    #[cfg(test)]
+    pub(crate) fn into_args(self) -> NewArgs {
+        self.args
+    }
+
+    /// Creates the project and returns its directory.
+    pub fn create(self) -> anyhow::Result<PathBuf> {
+        let dir = self
+            .args
+            .target_dir
+            .clone()
+            .unwrap_or_else(|| PathBuf::from(self.args.name.as_deref().unwrap_or_default()));
+        new::run(self.args)?;
+        Ok(dir)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builder_defaults_match_the_cli_defaults() {
+        let builder = ProjectBuilder::new("my_game")
+            .bevy_version("0.12")
+            .bin("editor:devtools")
+            .var("license", "MIT");
+        assert_eq!(builder.args.name.as_deref(), Some("my_game"));
+        assert_eq!(builder.args.edition, "2021");
+        assert_eq!(builder.args.vcs, Vcs::None);
+        assert_eq!(builder.args.bins, vec!["editor:devtools"]);
+        assert_eq!(builder.args.vars, vec!["license=MIT"]);
+        assert!(!builder.args.interactive);
+    }
+}
@@ -0,0 +1,169 @@
+//! `bevy add`: wire an ecosystem crate into the project.
+//!
+//! Resolves the crate to a version compatible with the project's Bevy
+//! (from the same pin table `upgrade` uses, falling back to crates.io),
+//! adds it to `Cargo.toml` via `toml_edit`, and — for crates whose plugin
+//! needs no type parameters — inserts the `add_plugins(..)` call into the
+//! app builder in `main.rs`.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+use clap::Args;
+
+use crate::i18n::localize;
+use crate::output;
+
+#[derive(Args)]
+pub struct AddArgs {
+    /// Name of the crate to add, e.g. `bevy_rapier2d`
+    pub name: String,
+
+    /// Project directory; defaults to the nearest project root
+    #[arg(long)]
+    pub project: Option<PathBuf>,
+
+    /// Version requirement to use instead of the resolved one
+    #[arg(long)]
+    pub version: Option<String>,
+
+    /// Only edit Cargo.toml; skip the add_plugins insertion
+    #[arg(long)]
+    pub no_plugin: bool,
+}
+
+/// Crates whose plugin registration is mechanical: the `use` line and the
+/// expression to pass to `add_plugins`. Crates needing type parameters
+/// (input managers and the like) are left to the user with a note.
+pub(crate) const PLUGIN_SNIPPETS: &[(&str, &str, &str)] = &[
+    (
+        "bevy_rapier2d",
+        "use bevy_rapier2d::prelude::*;",
+        "RapierPhysicsPlugin::<NoUserData>::default()",
+    ),
+    (
+        "bevy_rapier3d",
+        "use bevy_rapier3d::prelude::*;",
+        "RapierPhysicsPlugin::<NoUserData>::default()",
+    ),
+    ("bevy_egui", "use bevy_egui::EguiPlugin;", "EguiPlugin"),
+];
+
+pub fn run(args: AddArgs) -> anyhow::Result<()> {
+    let project = args
+        .project
+        .clone()
+        .unwrap_or_else(|| crate::project::locate(Path::new(".")));
+    let manifest_path = project.join("Cargo.toml");
+    anyhow::ensure!(
+        manifest_path.is_file(),
+        "{} does not contain a Cargo.toml",
+        project.display()
+    );
+
+    let version = match &args.version {
+        Some(version) => version.clone(),
+        None => resolve_version(&args.name, &project)?,
+    };
+
+    let contents = std::fs::read_to_string(&manifest_path)?;
+    let mut doc: toml_edit::Document = contents.parse().context("invalid Cargo.toml")?;
+    let dependencies = doc["dependencies"]
+        .or_insert(toml_edit::table())
+        .as_table_like_mut()
+        .context("[dependencies] is not a table")?;
+    if dependencies.get(&args.name).is_some() {
+        anyhow::bail!("`{}` is already a dependency", args.name);
+    }
+    dependencies.insert(&args.name, toml_edit::value(&version));
+    std::fs::write(&manifest_path, doc.to_string())?;
+    println!(
+        "{}",
+        localize!("add-dependency", name = args.name, version = version)
+    );
+
+    if args.no_plugin {
+        return Ok(());
+    }
+    let Some((_, use_line, plugin)) = PLUGIN_SNIPPETS
+        .iter()
+        .find(|(name, _, _)| *name == args.name)
+    else {
+        println!("{}", localize!("add-no-snippet", name = args.name));
+        return Ok(());
+    };
+    let main = project.join("src/main.rs");
+    let Ok(source) = std::fs::read_to_string(&main) else {
+        println!("{}", localize!("add-no-main", plugin = *plugin));
+        return Ok(());
+    };
+    match insert_plugin(&source, use_line, plugin) {
+        Some(edited) => {
+            std::fs::write(&main, edited)?;
+            output::ok(&localize!("add-plugin-inserted", plugin = *plugin));
+        }
+        None => println!("{}", localize!("add-no-main", plugin = *plugin)),
+    }
+    Ok(())
+}
+
+/// A version compatible with the project's Bevy: the pin table first,
+/// crates.io's latest stable as the fallback.
+fn resolve_version(name: &str, project: &Path) -> anyhow::Result<String> {
+    if let Some(minor) = super::migrate::detected_bevy_minor(project) {
+        let pinned = super::upgrade::ECOSYSTEM
+            .iter()
+            .find(|(bevy_minor, _)| *bevy_minor == minor)
+            .and_then(|(_, pins)| pins.iter().find(|(pin, _)| *pin == name));
+        if let Some((_, version)) = pinned {
+            return Ok((*version).to_string());
+        }
+    }
+    crate::versions::latest_of(name)
+        .with_context(|| format!("could not resolve a version for `{name}`; pass --version"))
+}
+
+/// Adds the `use` line and chains `.add_plugins(..)` onto the first
+/// `App::new()`. `None` when there is no app builder to edit or the plugin
+/// is already registered.
+fn insert_plugin(source: &str, use_line: &str, plugin: &str) -> Option<String> {
+    if source.contains(plugin) || !source.contains("App::new()") {
+        return None;
+    }
+    let mut edited = source.replacen(
+        "App::new()",
+        &format!("App::new().add_plugins({plugin})"),
+        1,
+    );
+    if !edited.contains(use_line) {
+        // After the last existing `use`, or at the very top.
+        let insert_at = edited
+            .match_indices("use ")
+            .filter(|(index, _)| *index == 0 || edited[..*index].ends_with('\n'))
+            .last()
+            .and_then(|(index, _)| edited[index..].find('\n').map(|end| index + end + 1))
+            .unwrap_or(0);
+        edited.insert_str(insert_at, &format!("{use_line}\n"));
+    }
+    Some(edited)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn plugin_call_and_use_line_are_inserted() {
+        let source = "use bevy::prelude::*;\n\nfn main() {\n    App::new().run();\n}\n";
+        let edited = insert_plugin(source, "use bevy_egui::EguiPlugin;", "EguiPlugin").unwrap();
+        assert!(edited.contains("use bevy::prelude::*;\nuse bevy_egui::EguiPlugin;\n"));
+        assert!(edited.contains("App::new().add_plugins(EguiPlugin).run();"));
+    }
+
+    #[test]
+    fn already_registered_plugins_are_left_alone() {
+        let source = "fn main() { App::new().add_plugins(EguiPlugin).run(); }\n";
+        assert!(insert_plugin(source, "use bevy_egui::EguiPlugin;", "EguiPlugin").is_none());
+        assert!(insert_plugin("fn other() {}\n", "use x;", "XPlugin").is_none());
+    }
+}
@@ -0,0 +1,310 @@
+//! Sprite-sheet packing metadata: turns a folder of frame PNGs or an
+//! Aseprite JSON export into an animation descriptor RON plus an optional
+//! generated loader module.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+use clap::Args;
+
+use crate::fs_util;
+use crate::i18n::localize;
+
+#[derive(Args)]
+pub struct AtlasArgs {
+    /// A directory of equally-sized frame PNGs (tags derived from filename
+    /// prefixes like `run_01.png`), or an Aseprite JSON export
+    pub input: PathBuf,
+
+    /// Directory the descriptor, frames, and sheet are written to
+    #[arg(long, default_value = "assets/sprites")]
+    pub out: PathBuf,
+
+    /// Playback rate used for frame folders, where no per-frame durations
+    /// exist
+    #[arg(long, default_value_t = 10.0)]
+    pub fps: f32,
+
+    /// Also generate a loader module (e.g. `src/sprite_sheets.rs`) that
+    /// builds the atlas and plays the tagged animations
+    #[arg(long, value_name = "FILE")]
+    pub module: Option<PathBuf>,
+}
+
+/// One animation tag: a named range into the frame list.
+#[derive(Debug, PartialEq, Eq)]
+struct Tag {
+    name: String,
+    from: usize,
+    to: usize,
+}
+
+pub fn run(args: AtlasArgs) -> anyhow::Result<()> {
+    std::fs::create_dir_all(&args.out)?;
+    let descriptor = if args.input.is_dir() {
+        from_frames(&args.input, &args.out, args.fps)?
+    } else if args
+        .input
+        .extension()
+        .is_some_and(|extension| extension == "json")
+    {
+        from_aseprite(&args.input, &args.out)?
+    } else {
+        anyhow::bail!(
+            "{} is neither a frame directory nor an Aseprite .json export",
+            args.input.display()
+        );
+    };
+    println!(
+        "{}",
+        localize!("assets-atlas-written", descriptor = descriptor.display())
+    );
+    if let Some(module) = &args.module {
+        if let Some(parent) = module.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        fs_util::write_file(
+            module,
+            include_str!("../../../templates/scaffold/sprite_sheets.rs").as_bytes(),
+            false,
+        )?;
+    }
+    Ok(())
+}
+
+/// Builds a descriptor from a folder of frames. The frames themselves are
+/// copied next to the descriptor; the generated loader packs them with
+/// `TextureAtlasBuilder` at load time, so no image compositing happens here.
+fn from_frames(input: &Path, out: &Path, fps: f32) -> anyhow::Result<PathBuf> {
+    let pattern = input.join("*.png");
+    let mut frames: Vec<PathBuf> =
+        glob::glob(pattern.to_str().context("frame path is not valid UTF-8")?)?
+            .flatten()
+            .collect();
+    frames.sort();
+    anyhow::ensure!(
+        !frames.is_empty(),
+        "{} contains no .png frames",
+        input.display()
+    );
+
+    let name = input
+        .file_name()
+        .context("frame directory has no name")?
+        .to_string_lossy()
+        .into_owned();
+    let frame_dir = out.join(&name);
+    std::fs::create_dir_all(&frame_dir)?;
+
+    let mut size = None;
+    let mut listed = String::new();
+    let mut tags: Vec<Tag> = Vec::new();
+    for (index, frame) in frames.iter().enumerate() {
+        let dimensions = png_dimensions(&std::fs::read(frame)?)
+            .with_context(|| format!("{} is not a valid PNG", frame.display()))?;
+        match size {
+            None => size = Some(dimensions),
+            Some(expected) => anyhow::ensure!(
+                dimensions == expected,
+                "{} is {}x{}, but earlier frames are {}x{}",
+                frame.display(),
+                dimensions.0,
+                dimensions.1,
+                expected.0,
+                expected.1
+            ),
+        }
+        let file_name = frame.file_name().unwrap_or_default();
+        std::fs::copy(frame, frame_dir.join(file_name))?;
+        listed.push_str(&format!(
+            "        \"{}/{}\",\n",
+            name,
+            file_name.to_string_lossy()
+        ));
+        let tag = tag_of(&frame.file_stem().unwrap_or_default().to_string_lossy());
+        match tags.last_mut() {
+            Some(last) if last.name == tag => last.to = index,
+            _ => tags.push(Tag {
+                name: tag,
+                from: index,
+                to: index,
+            }),
+        }
+    }
+
+    let (width, height) = size.unwrap_or_default();
+    let descriptor = out.join(format!("{name}.anim.ron"));
+    let ron = format!(
+        "(\n    image: None,\n    frame_size: ({width}, {height}),\n    \
+         frame_duration_ms: {},\n    frames: [\n{listed}    ],\n    tags: [\n{}    ],\n)\n",
+        (1000.0 / fps).round() as u64,
+        format_tags(&tags),
+    );
+    fs_util::write_file(&descriptor, ron.as_bytes(), false)?;
+    println!(
+        "{}",
+        localize!(
+            "assets-atlas-frames",
+            count = frames.len(),
+            tags = tags.len()
+        )
+    );
+    Ok(descriptor)
+}
+
+/// Converts an Aseprite JSON export (array or hash `frames`): the packed
+/// sheet already exists, so it is copied and the frame rects, durations, and
+/// `frameTags` become the descriptor.
+fn from_aseprite(input: &Path, out: &Path) -> anyhow::Result<PathBuf> {
+    let export: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(input)?)
+        .with_context(|| format!("{} is not valid JSON", input.display()))?;
+    let base = input.parent().unwrap_or(Path::new("."));
+
+    let image = export["meta"]["image"]
+        .as_str()
+        .context("the export has no meta.image sheet reference")?;
+    let sheet = base.join(image);
+    anyhow::ensure!(
+        sheet.is_file(),
+        "{} references sheet `{image}`, which does not exist",
+        input.display()
+    );
+    let sheet_name = sheet.file_name().unwrap_or_default().to_os_string();
+    std::fs::copy(&sheet, out.join(&sheet_name))?;
+
+    // Aseprite exports `frames` as an array or as a filename-keyed hash
+    // depending on the chosen format; order is by filename either way.
+    let frames: Vec<&serde_json::Value> = match &export["frames"] {
+        serde_json::Value::Array(frames) => frames.iter().collect(),
+        serde_json::Value::Object(map) => {
+            let sorted: BTreeMap<_, _> = map.iter().collect();
+            sorted.into_values().collect()
+        }
+        _ => anyhow::bail!("{} has no frames", input.display()),
+    };
+    anyhow::ensure!(!frames.is_empty(), "{} has no frames", input.display());
+
+    let mut listed = String::new();
+    for frame in &frames {
+        let rect = &frame["frame"];
+        listed.push_str(&format!(
+            "        (x: {}, y: {}, w: {}, h: {}, duration_ms: {}),\n",
+            rect["x"], rect["y"], rect["w"], rect["h"], frame["duration"]
+        ));
+    }
+    let tags: Vec<Tag> = export["meta"]["frameTags"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .map(|tag| Tag {
+            name: tag["name"].as_str().unwrap_or_default().to_string(),
+            from: tag["from"].as_u64().unwrap_or_default() as usize,
+            to: tag["to"].as_u64().unwrap_or_default() as usize,
+        })
+        .collect();
+
+    let name = input
+        .file_stem()
+        .unwrap_or_default()
+        .to_string_lossy()
+        .into_owned();
+    let descriptor = out.join(format!("{name}.anim.ron"));
+    let ron = format!(
+        "(\n    image: Some(\"{}\"),\n    rects: [\n{listed}    ],\n    tags: [\n{}    ],\n)\n",
+        sheet_name.to_string_lossy(),
+        format_tags(&tags),
+    );
+    fs_util::write_file(&descriptor, ron.as_bytes(), false)?;
+    println!(
+        "{}",
+        localize!(
+            "assets-atlas-frames",
+            count = frames.len(),
+            tags = tags.len()
+        )
+    );
+    Ok(descriptor)
+}
+
+fn format_tags(tags: &[Tag]) -> String {
+    let mut out = String::new();
+    for tag in tags {
+        out.push_str(&format!(
+            "        (name: \"{}\", from: {}, to: {}),\n",
+            tag.name, tag.from, tag.to
+        ));
+    }
+    out
+}
+
+/// `run_01` -> `run`: the tag is the stem with its trailing frame number
+/// (and separator) removed.
+fn tag_of(stem: &str) -> String {
+    let trimmed = stem.trim_end_matches(|c: char| c.is_ascii_digit());
+    let trimmed = trimmed.trim_end_matches(['_', '-']);
+    if trimmed.is_empty() {
+        stem.to_string()
+    } else {
+        trimmed.to_string()
+    }
+}
+
+/// Reads the dimensions out of a PNG's IHDR chunk without decoding it.
+fn png_dimensions(bytes: &[u8]) -> Option<(u32, u32)> {
+    const SIGNATURE: [u8; 8] = [0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n'];
+    if bytes.len() < 24 || bytes[..8] != SIGNATURE || &bytes[12..16] != b"IHDR" {
+        return None;
+    }
+    let width = u32::from_be_bytes(bytes[16..20].try_into().ok()?);
+    let height = u32::from_be_bytes(bytes[20..24].try_into().ok()?);
+    Some((width, height))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tags_come_from_frame_number_prefixes() {
+        assert_eq!(tag_of("run_01"), "run");
+        assert_eq!(tag_of("idle-12"), "idle");
+        assert_eq!(tag_of("7"), "7");
+    }
+
+    #[test]
+    fn png_dimensions_read_the_ihdr_chunk() {
+        let placeholder = include_bytes!("../../../templates/scaffold/placeholder.png");
+        assert_eq!(png_dimensions(placeholder), Some((1, 1)));
+        assert_eq!(png_dimensions(b"not a png"), None);
+    }
+
+    #[test]
+    fn aseprite_exports_become_descriptors() {
+        let dir = std::env::temp_dir().join("bevy_cli_atlas_test");
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("hero.png"), b"png").unwrap();
+        let export = serde_json::json!({
+            "frames": [
+                { "frame": { "x": 0, "y": 0, "w": 16, "h": 16 }, "duration": 100 },
+                { "frame": { "x": 16, "y": 0, "w": 16, "h": 16 }, "duration": 150 }
+            ],
+            "meta": {
+                "image": "hero.png",
+                "frameTags": [{ "name": "walk", "from": 0, "to": 1 }]
+            }
+        });
+        let input = dir.join("hero.json");
+        std::fs::write(&input, export.to_string()).unwrap();
+        let out = dir.join("out");
+        std::fs::create_dir_all(&out).unwrap();
+
+        let descriptor = from_aseprite(&input, &out).unwrap();
+        let ron = std::fs::read_to_string(descriptor).unwrap();
+        assert!(ron.contains("image: Some(\"hero.png\")"));
+        assert!(ron.contains("(x: 16, y: 0, w: 16, h: 16, duration_ms: 150)"));
+        assert!(ron.contains("(name: \"walk\", from: 0, to: 1)"));
+        assert!(out.join("hero.png").is_file());
+    }
+}
@@ -0,0 +1,327 @@
+//! `bevy assets transcode-audio`: shipping-quality OGG from lossless
+//! sources.
+//!
+//! WAV and FLAC masters stay in the repository; this transcodes them to
+//! OGG Vorbis at a configurable bitrate into the same compressed output
+//! tree `compress-textures` uses, so a build can ship that tree wholesale.
+//! Files are encoded in parallel — audio encoding is CPU bound and the
+//! files are independent — and unchanged sources are skipped through the
+//! same content-hash mapping scheme.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+
+use anyhow::Context;
+use clap::Args;
+use serde::{Deserialize, Serialize};
+
+use crate::i18n::localize;
+use crate::{fs_util, output};
+
+#[derive(Args)]
+pub struct TranscodeAudioArgs {
+    /// Project directory; defaults to the nearest project root
+    #[arg(long)]
+    pub project: Option<PathBuf>,
+
+    /// Output tree for the transcoded audio
+    #[arg(long, default_value = "assets-compressed")]
+    pub out_dir: PathBuf,
+
+    /// Encoders to run at once; defaults to the CPU count
+    #[arg(long)]
+    pub jobs: Option<usize>,
+
+    /// Re-encode everything, ignoring the mapping file
+    #[arg(long)]
+    pub force: bool,
+}
+
+/// Where the source-to-transcoded mapping is kept.
+const MAP_FILE: &str = ".bevy/audio-map.json";
+
+/// Source extensions worth transcoding; OGG and MP3 sources are already
+/// lossy and are left alone.
+const AUDIO_EXTENSIONS: &[&str] = &["wav", "flac"];
+
+/// The `[assets.audio]` section of `Bevy.toml`.
+#[derive(Debug, Deserialize)]
+struct AudioSection {
+    /// Vorbis bitrate in kbit/s; 128 is transparent for most game audio,
+    /// music-heavy projects may want 192.
+    #[serde(default = "default_bitrate")]
+    bitrate_kbps: u32,
+}
+
+fn default_bitrate() -> u32 {
+    128
+}
+
+impl Default for AudioSection {
+    fn default() -> Self {
+        Self {
+            bitrate_kbps: default_bitrate(),
+        }
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct AssetsSection {
+    #[serde(default)]
+    audio: AudioSection,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct ProjectConfig {
+    #[serde(default)]
+    assets: AssetsSection,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct MapEntry {
+    output: String,
+    hash: u64,
+}
+
+/// One pending encode, prepared serially before the workers start.
+struct Job {
+    source: PathBuf,
+    out: PathBuf,
+    key: String,
+    hash: u64,
+}
+
+pub fn run(args: TranscodeAudioArgs) -> anyhow::Result<()> {
+    let project = args
+        .project
+        .clone()
+        .unwrap_or_else(|| crate::project::locate(Path::new(".")));
+    let assets = project.join("assets");
+    anyhow::ensure!(
+        assets.is_dir(),
+        "{} has no assets directory",
+        project.display()
+    );
+    let config: ProjectConfig = load_config(&project)?;
+    let encoder = find_encoder().context("no OGG encoder found; install `ffmpeg` or `oggenc`")?;
+
+    let map_path = project.join(MAP_FILE);
+    let mut map: BTreeMap<String, MapEntry> = std::fs::read_to_string(&map_path)
+        .ok()
+        .and_then(|contents| serde_json::from_str(&contents).ok())
+        .unwrap_or_default();
+
+    let mut jobs = Vec::new();
+    for source in audio_sources(&assets) {
+        let relative = source
+            .strip_prefix(&assets)
+            .expect("sources come from the assets walk")
+            .to_path_buf();
+        let key = relative.to_string_lossy().into_owned();
+        let hash = content_hash(&std::fs::read(&source)?);
+        let out = project
+            .join(&args.out_dir)
+            .join(relative.with_extension("ogg"));
+        let unchanged = map.get(&key).is_some_and(|entry| entry.hash == hash) && out.is_file();
+        if unchanged && !args.force {
+            continue;
+        }
+        if let Some(parent) = out.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        jobs.push(Job {
+            source,
+            out,
+            key,
+            hash,
+        });
+    }
+
+    let bitrate = config.assets.audio.bitrate_kbps;
+    let workers = worker_count(args.jobs, jobs.len());
+    let next = AtomicUsize::new(0);
+    let failures: Mutex<Vec<String>> = Mutex::new(Vec::new());
+    std::thread::scope(|scope| {
+        for _ in 0..workers {
+            scope.spawn(|| loop {
+                let index = next.fetch_add(1, Ordering::Relaxed);
+                let Some(job) = jobs.get(index) else { return };
+                let result = crate::subprocess::Subprocess::new(encoder)
+                    .args(encoder_args(encoder, &job.source, &job.out, bitrate))
+                    .run();
+                if let Err(error) = result {
+                    failures
+                        .lock()
+                        .expect("failure list poisoned")
+                        .push(format!("{}: {error:#}", job.key));
+                }
+            });
+        }
+    });
+    let failures = failures.into_inner().expect("failure list poisoned");
+    anyhow::ensure!(
+        failures.is_empty(),
+        "audio transcoding failed for:\n{}",
+        failures.join("\n")
+    );
+
+    let encoded = jobs.len();
+    for job in jobs {
+        map.insert(
+            job.key,
+            MapEntry {
+                output: job
+                    .out
+                    .strip_prefix(&project)
+                    .unwrap_or(&job.out)
+                    .to_string_lossy()
+                    .into_owned(),
+                hash: job.hash,
+            },
+        );
+    }
+    if let Some(parent) = map_path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    fs_util::write_file(
+        &map_path,
+        serde_json::to_string_pretty(&map)?.as_bytes(),
+        false,
+    )?;
+    output::ok(&localize!(
+        "audio-transcoded",
+        count = encoded,
+        bitrate = bitrate
+    ));
+    Ok(())
+}
+
+/// The first installed encoder, in order of preference.
+fn find_encoder() -> Option<&'static str> {
+    ["ffmpeg", "oggenc"]
+        .into_iter()
+        .find(|encoder| super::super::doctor::on_path(encoder))
+}
+
+/// The argument vector for one encode; separated for testing.
+fn encoder_args(encoder: &str, source: &Path, out: &Path, bitrate: u32) -> Vec<String> {
+    match encoder {
+        "ffmpeg" => vec![
+            "-y".to_string(),
+            "-loglevel".to_string(),
+            "error".to_string(),
+            "-i".to_string(),
+            source.to_string_lossy().into_owned(),
+            "-c:a".to_string(),
+            "libvorbis".to_string(),
+            "-b:a".to_string(),
+            format!("{bitrate}k"),
+            out.to_string_lossy().into_owned(),
+        ],
+        _ => vec![
+            "--quiet".to_string(),
+            "-b".to_string(),
+            bitrate.to_string(),
+            "-o".to_string(),
+            out.to_string_lossy().into_owned(),
+            source.to_string_lossy().into_owned(),
+        ],
+    }
+}
+
+/// How many workers to start: the requested count, capped by the job
+/// count, defaulting to the CPU count.
+fn worker_count(requested: Option<usize>, jobs: usize) -> usize {
+    let default = std::thread::available_parallelism()
+        .map(std::num::NonZeroUsize::get)
+        .unwrap_or(1);
+    requested.unwrap_or(default).clamp(1, jobs.max(1))
+}
+
+fn audio_sources(assets: &Path) -> Vec<PathBuf> {
+    let mut sources = Vec::new();
+    let mut stack = vec![assets.to_path_buf()];
+    while let Some(path) = stack.pop() {
+        if path.is_dir() {
+            if let Ok(entries) = std::fs::read_dir(&path) {
+                stack.extend(entries.flatten().map(|entry| entry.path()));
+            }
+            continue;
+        }
+        let transcodable = path
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .is_some_and(|extension| AUDIO_EXTENSIONS.contains(&extension.to_lowercase().as_str()));
+        if transcodable {
+            sources.push(path);
+        }
+    }
+    sources.sort();
+    sources
+}
+
+fn content_hash(bytes: &[u8]) -> u64 {
+    use std::hash::{Hash, Hasher};
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    bytes.hash(&mut hasher);
+    hasher.finish()
+}
+
+fn load_config(project: &Path) -> anyhow::Result<ProjectConfig> {
+    let manifest_path = project.join(crate::project::MANIFEST);
+    if !manifest_path.is_file() {
+        return Ok(ProjectConfig::default());
+    }
+    toml::from_str(&std::fs::read_to_string(&manifest_path)?)
+        .with_context(|| format!("failed to parse {}", manifest_path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encoder_invocations_match_each_tool() {
+        let args = encoder_args(
+            "ffmpeg",
+            Path::new("a/hit.wav"),
+            Path::new("out/hit.ogg"),
+            160,
+        );
+        assert_eq!(
+            args,
+            vec![
+                "-y",
+                "-loglevel",
+                "error",
+                "-i",
+                "a/hit.wav",
+                "-c:a",
+                "libvorbis",
+                "-b:a",
+                "160k",
+                "out/hit.ogg"
+            ]
+        );
+        let args = encoder_args(
+            "oggenc",
+            Path::new("a/hit.flac"),
+            Path::new("out/hit.ogg"),
+            128,
+        );
+        assert_eq!(
+            args,
+            vec!["--quiet", "-b", "128", "-o", "out/hit.ogg", "a/hit.flac"]
+        );
+    }
+
+    #[test]
+    fn worker_count_respects_jobs_and_the_request() {
+        assert_eq!(worker_count(Some(8), 3), 3);
+        assert_eq!(worker_count(Some(2), 100), 2);
+        assert_eq!(worker_count(Some(0), 5), 1);
+        assert!(worker_count(None, 100) >= 1);
+    }
+}
@@ -0,0 +1,321 @@
+//! `bevy assets audit`: license hygiene for third-party assets.
+//!
+//! Every asset gets a license from one of two places: a `<file>.license`
+//! sidecar next to it, or a matching `[[assets]]` rule in
+//! `assets/licenses.toml`. The audit flags assets with no license at all
+//! and licenses that clash with the project's own — non-commercial or
+//! no-derivative terms, and share-alike or GPL terms the project does not
+//! reciprocate — then writes the combined `assets/NOTICE.md` so the credit
+//! lines third-party licenses require actually ship with the game.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+use clap::Args;
+use serde::Deserialize;
+
+use crate::i18n::localize;
+use crate::{fs_util, output};
+
+#[derive(Args)]
+pub struct AuditArgs {
+    /// Project directory; defaults to the nearest project root
+    #[arg(long)]
+    pub project: Option<PathBuf>,
+
+    /// Report only; do not write assets/NOTICE.md
+    #[arg(long)]
+    pub no_notice: bool,
+}
+
+/// The per-pattern license manifest, read from `assets/licenses.toml`.
+const LICENSES_FILE: &str = "licenses.toml";
+
+/// The generated third-party notice, written into `assets/`.
+const NOTICE_FILE: &str = "NOTICE.md";
+
+/// Files the audit itself produces or reads; never audited.
+const AUDIT_FILES: &[&str] = &[LICENSES_FILE, NOTICE_FILE, "ATTRIBUTION.md", "manifest.ron"];
+
+#[derive(Debug, Default, Deserialize)]
+struct LicenseManifest {
+    #[serde(default)]
+    assets: Vec<LicenseRule>,
+}
+
+/// One manifest rule: the first rule whose pattern matches an asset's
+/// relative path supplies its license.
+#[derive(Debug, Clone, Deserialize)]
+struct LicenseRule {
+    pattern: String,
+    license: String,
+    #[serde(default)]
+    author: Option<String>,
+    #[serde(default)]
+    source: Option<String>,
+}
+
+/// License metadata resolved for one asset.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct LicenseInfo {
+    license: String,
+    author: Option<String>,
+    source: Option<String>,
+}
+
+/// How an asset license relates to the project license.
+#[derive(Debug, PartialEq, Eq)]
+enum Compatibility {
+    Ok,
+    Incompatible(&'static str),
+}
+
+pub fn run(args: AuditArgs) -> anyhow::Result<()> {
+    let project = args
+        .project
+        .clone()
+        .unwrap_or_else(|| crate::project::locate(Path::new(".")));
+    let assets = project.join("assets");
+    anyhow::ensure!(
+        assets.is_dir(),
+        "{} has no assets directory",
+        project.display()
+    );
+    let project_license = project_license(&project)?.unwrap_or_default();
+    let rules = load_rules(&assets)?;
+
+    let mut unknown = 0usize;
+    let mut incompatible = 0usize;
+    let mut notices: Vec<(String, LicenseInfo)> = Vec::new();
+    for file in super::manifest::sorted_files(&assets)? {
+        let rel = file
+            .strip_prefix(&assets)
+            .expect("files come from the assets walk")
+            .to_string_lossy()
+            .replace('\\', "/");
+        if is_audit_file(&rel) {
+            continue;
+        }
+        let Some(info) = resolve(&file, &rel, &rules)? else {
+            output::warn(&format!("no license recorded for {rel}"));
+            unknown += 1;
+            continue;
+        };
+        if let Compatibility::Incompatible(reason) = compatibility(&info.license, &project_license)
+        {
+            output::warn(&format!("{rel}: {} — {reason}", info.license));
+            incompatible += 1;
+        }
+        notices.push((rel, info));
+    }
+
+    if !args.no_notice && !notices.is_empty() {
+        fs_util::write_file(
+            &assets.join(NOTICE_FILE),
+            notice(&notices).as_bytes(),
+            false,
+        )?;
+        println!("{}", localize!("audit-notice-written", file = NOTICE_FILE));
+    }
+    if unknown == 0 && incompatible == 0 {
+        output::ok(&localize!("audit-clean", count = notices.len()));
+        return Ok(());
+    }
+    anyhow::bail!(localize!(
+        "audit-problems",
+        count = unknown + incompatible,
+        unknown = unknown,
+        incompatible = incompatible
+    ));
+}
+
+/// Whether the relative path is audit bookkeeping rather than an asset.
+fn is_audit_file(rel: &str) -> bool {
+    AUDIT_FILES.contains(&rel) || rel.ends_with(".license")
+}
+
+/// The license for one asset: its sidecar wins over the manifest rules,
+/// which apply in declaration order.
+fn resolve(file: &Path, rel: &str, rules: &[LicenseRule]) -> anyhow::Result<Option<LicenseInfo>> {
+    let sidecar = sidecar_path(file);
+    if sidecar.is_file() {
+        let contents = std::fs::read_to_string(&sidecar)?;
+        return Ok(Some(parse_sidecar(&contents).with_context(|| {
+            format!("empty license sidecar {}", sidecar.display())
+        })?));
+    }
+    for rule in rules {
+        let matches = glob::Pattern::new(&rule.pattern)
+            .with_context(|| format!("bad pattern `{}` in {LICENSES_FILE}", rule.pattern))?
+            .matches(rel);
+        if matches {
+            return Ok(Some(LicenseInfo {
+                license: rule.license.clone(),
+                author: rule.author.clone(),
+                source: rule.source.clone(),
+            }));
+        }
+    }
+    Ok(None)
+}
+
+/// `sprites/hero.png` is licensed by `sprites/hero.png.license`.
+fn sidecar_path(file: &Path) -> PathBuf {
+    let mut name = file.file_name().unwrap_or_default().to_os_string();
+    name.push(".license");
+    file.with_file_name(name)
+}
+
+/// Parses a sidecar: the SPDX identifier on the first line, optional
+/// `Author:` and `Source:` lines after it.
+fn parse_sidecar(contents: &str) -> Option<LicenseInfo> {
+    let mut lines = contents.lines().map(str::trim);
+    let license = lines.find(|line| !line.is_empty())?.to_string();
+    let mut info = LicenseInfo {
+        license,
+        author: None,
+        source: None,
+    };
+    for line in lines {
+        if let Some(author) = line.strip_prefix("Author:") {
+            info.author = Some(author.trim().to_string());
+        } else if let Some(source) = line.strip_prefix("Source:") {
+            info.source = Some(source.trim().to_string());
+        }
+    }
+    Some(info)
+}
+
+/// The asset-license checks that matter for shipping a game: terms that
+/// forbid selling or modifying, and reciprocal terms the project does not
+/// meet. SPDX identifiers are compared case-insensitively.
+fn compatibility(asset: &str, project: &str) -> Compatibility {
+    let upper = asset.to_uppercase();
+    let project_upper = project.to_uppercase();
+    if upper.contains("-NC") {
+        return Compatibility::Incompatible("non-commercial terms forbid selling the game");
+    }
+    if upper.contains("-ND") {
+        return Compatibility::Incompatible("no-derivatives terms forbid editing the asset");
+    }
+    if upper.contains("-SA") && upper != project_upper {
+        return Compatibility::Incompatible(
+            "share-alike terms require the project to use the same license",
+        );
+    }
+    if upper.contains("GPL") && !project_upper.contains("GPL") {
+        return Compatibility::Incompatible("GPL assets require a GPL-compatible project");
+    }
+    Compatibility::Ok
+}
+
+/// The project's own SPDX license from `Cargo.toml`, if declared.
+fn project_license(project: &Path) -> anyhow::Result<Option<String>> {
+    let manifest: toml::Value = toml::from_str(
+        &std::fs::read_to_string(project.join("Cargo.toml"))
+            .with_context(|| format!("{} does not contain a Cargo.toml", project.display()))?,
+    )?;
+    Ok(manifest
+        .get("package")
+        .and_then(|package| package.get("license"))
+        .and_then(|license| license.as_str())
+        .map(str::to_string))
+}
+
+fn load_rules(assets: &Path) -> anyhow::Result<Vec<LicenseRule>> {
+    let path = assets.join(LICENSES_FILE);
+    if !path.is_file() {
+        return Ok(Vec::new());
+    }
+    let manifest: LicenseManifest = toml::from_str(&std::fs::read_to_string(&path)?)
+        .with_context(|| format!("failed to parse {}", path.display()))?;
+    Ok(manifest.assets)
+}
+
+/// The combined notice: one section per license, listing its assets with
+/// authors and sources.
+fn notice(entries: &[(String, LicenseInfo)]) -> String {
+    let mut by_license: std::collections::BTreeMap<&str, Vec<&(String, LicenseInfo)>> =
+        std::collections::BTreeMap::new();
+    for entry in entries {
+        by_license.entry(&entry.1.license).or_default().push(entry);
+    }
+    let mut notice = String::from(
+        "# Third-party asset notices\n\nGenerated by `bevy assets audit`; do not edit by hand.\n",
+    );
+    for (license, group) in by_license {
+        notice.push_str(&format!("\n## {license}\n\n"));
+        for (path, info) in group {
+            notice.push_str(&format!("- {path}"));
+            if let Some(author) = &info.author {
+                notice.push_str(&format!(" — {author}"));
+            }
+            if let Some(source) = &info.source {
+                notice.push_str(&format!(" ({source})"));
+            }
+            notice.push('\n');
+        }
+    }
+    notice
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn restrictive_terms_are_flagged_against_the_project_license() {
+        assert_eq!(compatibility("CC0-1.0", "MIT"), Compatibility::Ok);
+        assert_eq!(compatibility("CC-BY-4.0", ""), Compatibility::Ok);
+        assert!(matches!(
+            compatibility("CC-BY-NC-4.0", "MIT"),
+            Compatibility::Incompatible(_)
+        ));
+        assert!(matches!(
+            compatibility("CC-BY-SA-4.0", "MIT"),
+            Compatibility::Incompatible(_)
+        ));
+        assert_eq!(
+            compatibility("CC-BY-SA-4.0", "CC-BY-SA-4.0"),
+            Compatibility::Ok
+        );
+        assert_eq!(
+            compatibility("GPL-3.0-only", "GPL-3.0-only"),
+            Compatibility::Ok
+        );
+    }
+
+    #[test]
+    fn sidecars_carry_license_author_and_source() {
+        let info = parse_sidecar("CC-BY-4.0\nAuthor: Kenney\nSource: https://kenney.nl\n").unwrap();
+        assert_eq!(info.license, "CC-BY-4.0");
+        assert_eq!(info.author.as_deref(), Some("Kenney"));
+        assert_eq!(info.source.as_deref(), Some("https://kenney.nl"));
+        assert!(parse_sidecar("  \n").is_none());
+    }
+
+    #[test]
+    fn notices_group_assets_by_license() {
+        let entries = vec![
+            (
+                "sprites/hero.png".to_string(),
+                LicenseInfo {
+                    license: "CC0-1.0".to_string(),
+                    author: None,
+                    source: None,
+                },
+            ),
+            (
+                "music/theme.ogg".to_string(),
+                LicenseInfo {
+                    license: "CC-BY-4.0".to_string(),
+                    author: Some("Kenney".to_string()),
+                    source: None,
+                },
+            ),
+        ];
+        let notice = notice(&entries);
+        assert!(notice.contains("## CC0-1.0\n\n- sprites/hero.png\n"));
+        assert!(notice.contains("## CC-BY-4.0\n\n- music/theme.ogg — Kenney\n"));
+    }
+}
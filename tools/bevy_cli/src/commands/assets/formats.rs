@@ -0,0 +1,255 @@
+//! Format-level asset checks for `bevy assets validate`.
+//!
+//! Parses the container formats Bevy loads most — PNG, JPEG, KTX2, glTF
+//! and GLB, WAV, OGG, FLAC — far enough to catch what blows up at runtime:
+//! files the loaders cannot parse, glTF buffers and images pointing at
+//! files that do not exist, and textures that are oversized or
+//! non-power-of-two. All of it is header-level parsing; nothing here needs
+//! a GPU or an asset server.
+
+use std::path::Path;
+
+/// One finding about one file.
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) enum Problem {
+    /// The file cannot be parsed at all; the loader will error at runtime.
+    Unparseable(String),
+    /// A glTF references a buffer or image that is not on disk.
+    MissingReference(String),
+    /// Larger than common GPU limits; fails outright on older hardware.
+    Oversized { width: u32, height: u32 },
+    /// Works on desktop, but mobile GPUs may refuse to mipmap it.
+    NonPowerOfTwo { width: u32, height: u32 },
+}
+
+impl Problem {
+    /// Whether this finding should fail validation or only warn.
+    pub(crate) fn is_error(&self) -> bool {
+        !matches!(self, Problem::NonPowerOfTwo { .. })
+    }
+}
+
+/// The largest texture edge accepted without complaint; matches the
+/// guaranteed minimum of current desktop GPUs.
+const MAX_TEXTURE_EDGE: u32 = 8192;
+
+/// Checks one file, dispatching on its extension; extensions without a
+/// parser here are accepted silently.
+pub(crate) fn check(path: &Path) -> Vec<Problem> {
+    let Some(extension) = path
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .map(str::to_lowercase)
+    else {
+        return Vec::new();
+    };
+    let Ok(bytes) = std::fs::read(path) else {
+        return vec![Problem::Unparseable("file is unreadable".to_string())];
+    };
+    match extension.as_str() {
+        "png" => image_problems(png_dimensions(&bytes)),
+        "jpg" | "jpeg" => image_problems(jpeg_dimensions(&bytes)),
+        "ktx2" => magic_problems(&bytes, b"\xabKTX 20\xbb\r\n\x1a\n", "not a KTX2 file"),
+        "wav" => wav_problems(&bytes),
+        "ogg" => magic_problems(&bytes, b"OggS", "not an OGG stream"),
+        "flac" => magic_problems(&bytes, b"fLaC", "not a FLAC stream"),
+        "gltf" => gltf_problems(&bytes, path),
+        "glb" => glb_problems(&bytes, path),
+        _ => Vec::new(),
+    }
+}
+
+fn image_problems(dimensions: Result<(u32, u32), String>) -> Vec<Problem> {
+    let (width, height) = match dimensions {
+        Ok(dimensions) => dimensions,
+        Err(message) => return vec![Problem::Unparseable(message)],
+    };
+    let mut problems = Vec::new();
+    if width > MAX_TEXTURE_EDGE || height > MAX_TEXTURE_EDGE {
+        problems.push(Problem::Oversized { width, height });
+    } else if !width.is_power_of_two() || !height.is_power_of_two() {
+        problems.push(Problem::NonPowerOfTwo { width, height });
+    }
+    problems
+}
+
+fn magic_problems(bytes: &[u8], magic: &[u8], message: &str) -> Vec<Problem> {
+    if bytes.starts_with(magic) {
+        Vec::new()
+    } else {
+        vec![Problem::Unparseable(message.to_string())]
+    }
+}
+
+fn wav_problems(bytes: &[u8]) -> Vec<Problem> {
+    let valid = bytes.len() >= 12 && &bytes[..4] == b"RIFF" && &bytes[8..12] == b"WAVE";
+    if valid {
+        Vec::new()
+    } else {
+        vec![Problem::Unparseable("not a RIFF/WAVE file".to_string())]
+    }
+}
+
+/// PNG dimensions from the IHDR chunk, which the spec requires first.
+fn png_dimensions(bytes: &[u8]) -> Result<(u32, u32), String> {
+    if !bytes.starts_with(b"\x89PNG\r\n\x1a\n") || bytes.len() < 24 {
+        return Err("not a PNG file".to_string());
+    }
+    if &bytes[12..16] != b"IHDR" {
+        return Err("PNG is missing its IHDR chunk".to_string());
+    }
+    let width = u32::from_be_bytes(bytes[16..20].try_into().expect("length checked"));
+    let height = u32::from_be_bytes(bytes[20..24].try_into().expect("length checked"));
+    Ok((width, height))
+}
+
+/// JPEG dimensions from the first start-of-frame marker.
+fn jpeg_dimensions(bytes: &[u8]) -> Result<(u32, u32), String> {
+    if !bytes.starts_with(b"\xff\xd8") {
+        return Err("not a JPEG file".to_string());
+    }
+    let mut offset = 2usize;
+    while offset + 9 <= bytes.len() {
+        if bytes[offset] != 0xff {
+            break;
+        }
+        let marker = bytes[offset + 1];
+        // SOF0..SOF15, minus the DHT/JPG/DAC markers interleaved in that
+        // range, all carry the frame dimensions.
+        if (0xc0..=0xcf).contains(&marker) && ![0xc4, 0xc8, 0xcc].contains(&marker) {
+            let height = u16::from_be_bytes([bytes[offset + 5], bytes[offset + 6]]);
+            let width = u16::from_be_bytes([bytes[offset + 7], bytes[offset + 8]]);
+            return Ok((u32::from(width), u32::from(height)));
+        }
+        let length = u16::from_be_bytes([bytes[offset + 2], bytes[offset + 3]]) as usize;
+        offset += 2 + length;
+    }
+    Err("JPEG has no start-of-frame marker".to_string())
+}
+
+/// Parses a `.gltf` as JSON and checks its external references.
+fn gltf_problems(bytes: &[u8], path: &Path) -> Vec<Problem> {
+    let json: serde_json::Value = match serde_json::from_slice(bytes) {
+        Ok(json) => json,
+        Err(error) => return vec![Problem::Unparseable(format!("invalid JSON: {error}"))],
+    };
+    gltf_reference_problems(&json, path)
+}
+
+/// Parses a `.glb` container and checks the embedded glTF the same way.
+fn glb_problems(bytes: &[u8], path: &Path) -> Vec<Problem> {
+    if !bytes.starts_with(b"glTF") || bytes.len() < 20 {
+        return vec![Problem::Unparseable("not a GLB container".to_string())];
+    }
+    let chunk_length =
+        u32::from_le_bytes(bytes[12..16].try_into().expect("length checked")) as usize;
+    if &bytes[16..20] != b"JSON" || bytes.len() < 20 + chunk_length {
+        return vec![Problem::Unparseable(
+            "GLB is missing its JSON chunk".to_string(),
+        )];
+    }
+    let json: serde_json::Value = match serde_json::from_slice(&bytes[20..20 + chunk_length]) {
+        Ok(json) => json,
+        Err(error) => return vec![Problem::Unparseable(format!("invalid JSON chunk: {error}"))],
+    };
+    gltf_reference_problems(&json, path)
+}
+
+/// Missing `buffers[].uri` and `images[].uri` targets, resolved relative
+/// to the glTF itself; embedded `data:` URIs are always fine.
+fn gltf_reference_problems(json: &serde_json::Value, path: &Path) -> Vec<Problem> {
+    let base = path.parent().unwrap_or(Path::new(""));
+    let mut problems = Vec::new();
+    for section in ["buffers", "images"] {
+        let Some(entries) = json.get(section).and_then(|value| value.as_array()) else {
+            continue;
+        };
+        for entry in entries {
+            let Some(uri) = entry.get("uri").and_then(|value| value.as_str()) else {
+                continue;
+            };
+            if uri.starts_with("data:") {
+                continue;
+            }
+            if !base.join(uri).is_file() {
+                problems.push(Problem::MissingReference(uri.to_string()));
+            }
+        }
+    }
+    problems
+}
+
+/// One warn line per problem, stable shape for CI logs.
+pub(crate) fn describe(problem: &Problem) -> String {
+    match problem {
+        Problem::Unparseable(message) => format!("unparseable: {message}"),
+        Problem::MissingReference(uri) => format!("missing reference: `{uri}`"),
+        Problem::Oversized { width, height } => {
+            format!("{width}x{height} exceeds the {MAX_TEXTURE_EDGE} texture limit")
+        }
+        Problem::NonPowerOfTwo { width, height } => {
+            format!("{width}x{height} is not power-of-two; mobile GPUs may not mipmap it")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn png(width: u32, height: u32) -> Vec<u8> {
+        let mut bytes = b"\x89PNG\r\n\x1a\n".to_vec();
+        bytes.extend_from_slice(&13u32.to_be_bytes());
+        bytes.extend_from_slice(b"IHDR");
+        bytes.extend_from_slice(&width.to_be_bytes());
+        bytes.extend_from_slice(&height.to_be_bytes());
+        bytes
+    }
+
+    #[test]
+    fn png_dimensions_drive_the_texture_checks() {
+        assert_eq!(png_dimensions(&png(256, 256)).unwrap(), (256, 256));
+        assert!(image_problems(Ok((256, 256))).is_empty());
+        assert_eq!(
+            image_problems(Ok((300, 256))),
+            vec![Problem::NonPowerOfTwo {
+                width: 300,
+                height: 256
+            }]
+        );
+        assert_eq!(
+            image_problems(Ok((16384, 256))),
+            vec![Problem::Oversized {
+                width: 16384,
+                height: 256
+            }]
+        );
+        assert!(png_dimensions(b"not a png").is_err());
+    }
+
+    #[test]
+    fn gltf_references_are_resolved_beside_the_file() {
+        let dir = std::env::temp_dir().join(format!("bevy_cli_gltf_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("mesh.bin"), b"bin").unwrap();
+        let json: serde_json::Value = serde_json::from_str(
+            "{\"buffers\": [{\"uri\": \"mesh.bin\"}], \
+              \"images\": [{\"uri\": \"missing.png\"}, {\"uri\": \"data:image/png;base64,\"}]}",
+        )
+        .unwrap();
+        let problems = gltf_reference_problems(&json, &dir.join("scene.gltf"));
+        assert_eq!(
+            problems,
+            vec![Problem::MissingReference("missing.png".to_string())]
+        );
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn audio_magic_is_checked_per_container() {
+        assert!(wav_problems(b"RIFF\0\0\0\0WAVEfmt ").is_empty());
+        assert!(!wav_problems(b"OggS").is_empty());
+        assert!(magic_problems(b"OggS\0rest", b"OggS", "not ogg").is_empty());
+        assert!(jpeg_dimensions(b"\xff\xd8\xff\xc0\x00\x11\x08\x01\x00\x02\x00").is_ok());
+    }
+}
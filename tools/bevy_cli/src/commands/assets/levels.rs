@@ -0,0 +1,356 @@
+//! Level import: converts Tiled `.tmx` maps and LDtk projects into a
+//! Bevy-friendly representation, validating tileset references and copying
+//! the images a level depends on.
+
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use anyhow::Context;
+use clap::{Args, Subcommand, ValueEnum};
+
+use crate::fs_util;
+use crate::i18n::localize;
+
+#[derive(Args)]
+pub struct LevelsArgs {
+    #[command(subcommand)]
+    pub command: LevelsCommand,
+}
+
+#[derive(Subcommand)]
+pub enum LevelsCommand {
+    /// Convert a Tiled `.tmx` map or LDtk project into the assets directory
+    Import {
+        /// The `.tmx` or `.ldtk` file to import
+        map: PathBuf,
+
+        /// Representation to produce
+        #[arg(long, value_enum, default_value_t = LevelFormat::Ron)]
+        format: LevelFormat,
+
+        /// Directory the level and its dependencies are written to
+        #[arg(long, default_value = "assets/levels")]
+        out: PathBuf,
+
+        /// Keep running and re-import whenever the source map changes
+        #[arg(long)]
+        watch: bool,
+
+        /// Poll interval while watching
+        #[arg(long, default_value_t = 2000, value_name = "MILLISECONDS")]
+        interval_ms: u64,
+    },
+}
+
+/// Output representations `levels import` can produce.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum LevelFormat {
+    /// A plain RON description of the layers and tiles, loadable without
+    /// extra plugins
+    Ron,
+    /// Keep the editor's own format: the map file (plus external tilesets)
+    /// is copied verbatim for `bevy_ecs_tilemap`'s Tiled/LDtk loaders
+    BevyEcsTilemap,
+}
+
+pub fn run(args: LevelsArgs) -> anyhow::Result<()> {
+    match args.command {
+        LevelsCommand::Import {
+            map,
+            format,
+            out,
+            watch,
+            interval_ms,
+        } => {
+            if !watch {
+                return import(&map, format, &out);
+            }
+            let mut last_hash = String::new();
+            loop {
+                let hash = super::hash_file(&map)?;
+                if hash != last_hash {
+                    if let Err(error) = import(&map, format, &out) {
+                        // In watch mode an import error (a half-saved file,
+                        // say) is reported and retried, not fatal.
+                        crate::output::warn(&format!("{error:#}"));
+                    }
+                    last_hash = hash;
+                }
+                std::thread::sleep(Duration::from_millis(interval_ms));
+            }
+        }
+    }
+}
+
+fn import(map: &Path, format: LevelFormat, out: &Path) -> anyhow::Result<()> {
+    let extension = map
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .unwrap_or_default();
+    let contents = std::fs::read_to_string(map)
+        .with_context(|| format!("failed to read {}", map.display()))?;
+    std::fs::create_dir_all(out)?;
+    let copied = match extension {
+        "tmx" => import_tmx(map, &contents, format, out)?,
+        "ldtk" => import_ldtk(map, &contents, format, out)?,
+        other => anyhow::bail!(
+            "unsupported level format `.{other}`; expected a Tiled .tmx or an LDtk project"
+        ),
+    };
+    println!(
+        "{}",
+        localize!(
+            "assets-level-imported",
+            map = map.display(),
+            out = out.display()
+        )
+    );
+    println!("{}", localize!("assets-level-deps-copied", count = copied));
+    Ok(())
+}
+
+/// Imports a Tiled map. Tileset references (external `.tsx` files and their
+/// images) are resolved relative to the map, validated, and copied next to
+/// the output.
+fn import_tmx(
+    map: &Path,
+    contents: &str,
+    format: LevelFormat,
+    out: &Path,
+) -> anyhow::Result<usize> {
+    let base = map.parent().unwrap_or(Path::new("."));
+    let mut copied = 0usize;
+    // Both external tilesets (`<tileset source="x.tsx">`) and embedded
+    // tileset images (`<image source="x.png">`) use a `source` attribute.
+    let source_attr = regex::Regex::new(r#"<(tileset|image)[^>]*\bsource="([^"]+)""#)?;
+    for capture in source_attr.captures_iter(contents) {
+        let reference = &capture[2];
+        let path = base.join(reference);
+        anyhow::ensure!(
+            path.is_file(),
+            "{} references `{reference}`, which does not exist",
+            map.display()
+        );
+        copied += copy_dep(&path, out)?;
+        if reference.ends_with(".tsx") {
+            // External tilesets carry their own image references.
+            let tileset = std::fs::read_to_string(&path)?;
+            for capture in source_attr.captures_iter(&tileset) {
+                let image = path.parent().unwrap_or(base).join(&capture[2]);
+                anyhow::ensure!(
+                    image.is_file(),
+                    "{} references `{}`, which does not exist",
+                    path.display(),
+                    &capture[2]
+                );
+                copied += copy_dep(&image, out)?;
+            }
+        }
+    }
+
+    match format {
+        LevelFormat::BevyEcsTilemap => {
+            copied += copy_dep(map, out)?;
+        }
+        LevelFormat::Ron => {
+            let attr = |name: &str| -> Option<String> {
+                regex::Regex::new(&format!(r#"<map[^>]*\b{name}="([^"]+)""#))
+                    .ok()?
+                    .captures(contents)
+                    .map(|capture| capture[1].to_string())
+            };
+            let mut layers = String::new();
+            let layer_data = regex::Regex::new(
+                r#"(?s)<layer[^>]*\bname="([^"]+)"[^>]*>.*?<data encoding="csv">\s*(.*?)\s*</data>"#,
+            )?;
+            for capture in layer_data.captures_iter(contents) {
+                let tiles: Vec<&str> = capture[2]
+                    .split(|c: char| c == ',' || c.is_whitespace())
+                    .filter(|tile| !tile.is_empty())
+                    .collect();
+                layers.push_str(&format!(
+                    "        (name: \"{}\", tiles: [{}]),\n",
+                    &capture[1],
+                    tiles.join(", ")
+                ));
+            }
+            let ron = format!(
+                "(\n    width: {},\n    height: {},\n    layers: [\n{layers}    ],\n)\n",
+                attr("width").unwrap_or_default(),
+                attr("height").unwrap_or_default(),
+            );
+            write_level(map, out, &ron)?;
+        }
+    }
+    Ok(copied)
+}
+
+/// Imports an LDtk project: validates and copies the tileset images from
+/// `defs.tilesets`, then converts each level's tile layers.
+fn import_ldtk(
+    map: &Path,
+    contents: &str,
+    format: LevelFormat,
+    out: &Path,
+) -> anyhow::Result<usize> {
+    let base = map.parent().unwrap_or(Path::new("."));
+    let project: serde_json::Value = serde_json::from_str(contents)
+        .with_context(|| format!("{} is not valid LDtk JSON", map.display()))?;
+    let mut copied = 0usize;
+    for tileset in project["defs"]["tilesets"].as_array().into_iter().flatten() {
+        let Some(rel_path) = tileset["relPath"].as_str() else {
+            continue;
+        };
+        let path = base.join(rel_path);
+        anyhow::ensure!(
+            path.is_file(),
+            "{} references tileset `{rel_path}`, which does not exist",
+            map.display()
+        );
+        copied += copy_dep(&path, out)?;
+    }
+
+    match format {
+        LevelFormat::BevyEcsTilemap => {
+            copied += copy_dep(map, out)?;
+        }
+        LevelFormat::Ron => {
+            let mut levels = String::new();
+            for level in project["levels"].as_array().into_iter().flatten() {
+                let mut layers = String::new();
+                for layer in level["layerInstances"].as_array().into_iter().flatten() {
+                    let tiles: Vec<String> = layer["gridTiles"]
+                        .as_array()
+                        .into_iter()
+                        .flatten()
+                        .map(|tile| {
+                            format!(
+                                "(x: {}, y: {}, id: {})",
+                                tile["px"][0], tile["px"][1], tile["t"]
+                            )
+                        })
+                        .collect();
+                    layers.push_str(&format!(
+                        "            (name: \"{}\", tiles: [{}]),\n",
+                        layer["__identifier"].as_str().unwrap_or_default(),
+                        tiles.join(", ")
+                    ));
+                }
+                levels.push_str(&format!(
+                    "        (name: \"{}\", width: {}, height: {}, layers: [\n{layers}        ]),\n",
+                    level["identifier"].as_str().unwrap_or_default(),
+                    level["pxWid"],
+                    level["pxHei"],
+                ));
+            }
+            write_level(map, out, &format!("(\n    levels: [\n{levels}    ],\n)\n"))?;
+        }
+    }
+    Ok(copied)
+}
+
+fn write_level(map: &Path, out: &Path, ron: &str) -> anyhow::Result<()> {
+    let stem = map
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().into_owned())
+        .unwrap_or_else(|| "level".to_string());
+    fs_util::write_file(
+        &out.join(format!("{stem}.level.ron")),
+        ron.as_bytes(),
+        false,
+    )
+}
+
+/// Copies one dependency into the output directory, flattening its path.
+fn copy_dep(path: &Path, out: &Path) -> anyhow::Result<usize> {
+    let file_name = path.file_name().context("dependency has no file name")?;
+    std::fs::copy(path, out.join(file_name))
+        .with_context(|| format!("failed to copy {}", path.display()))?;
+    Ok(1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tmx_imports_validate_references_and_convert_layers() {
+        let dir = std::env::temp_dir().join("bevy_cli_levels_test");
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("tiles.png"), b"png").unwrap();
+        let map = dir.join("level1.tmx");
+        std::fs::write(
+            &map,
+            r#"<map width="2" height="2">
+  <tileset firstgid="1"><image source="tiles.png"/></tileset>
+  <layer name="ground" width="2" height="2">
+    <data encoding="csv">1,2,
+3,4</data>
+  </layer>
+</map>"#,
+        )
+        .unwrap();
+        let out = dir.join("out");
+        std::fs::create_dir_all(&out).unwrap();
+
+        let copied = import_tmx(
+            &map,
+            &std::fs::read_to_string(&map).unwrap(),
+            LevelFormat::Ron,
+            &out,
+        )
+        .unwrap();
+        assert_eq!(copied, 1);
+        assert!(out.join("tiles.png").is_file());
+        let ron = std::fs::read_to_string(out.join("level1.level.ron")).unwrap();
+        assert!(ron.contains("width: 2"));
+        assert!(ron.contains("(name: \"ground\", tiles: [1, 2, 3, 4])"));
+
+        let broken = dir.join("broken.tmx");
+        std::fs::write(&broken, r#"<map><tileset source="missing.tsx"/></map>"#).unwrap();
+        let error = import_tmx(
+            &broken,
+            &std::fs::read_to_string(&broken).unwrap(),
+            LevelFormat::Ron,
+            &out,
+        )
+        .unwrap_err();
+        assert!(error.to_string().contains("missing.tsx"));
+    }
+
+    #[test]
+    fn ldtk_imports_convert_grid_tiles() {
+        let dir = std::env::temp_dir().join("bevy_cli_ldtk_test");
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("atlas.png"), b"png").unwrap();
+        let project = serde_json::json!({
+            "defs": { "tilesets": [{ "relPath": "atlas.png" }] },
+            "levels": [{
+                "identifier": "Level_0",
+                "pxWid": 16,
+                "pxHei": 16,
+                "layerInstances": [{
+                    "__identifier": "Tiles",
+                    "gridTiles": [{ "px": [0, 8], "t": 3 }]
+                }]
+            }]
+        });
+        let map = dir.join("world.ldtk");
+        std::fs::write(&map, project.to_string()).unwrap();
+        let out = dir.join("out");
+        std::fs::create_dir_all(&out).unwrap();
+
+        import_ldtk(
+            &map,
+            &std::fs::read_to_string(&map).unwrap(),
+            LevelFormat::Ron,
+            &out,
+        )
+        .unwrap();
+        let ron = std::fs::read_to_string(out.join("world.level.ron")).unwrap();
+        assert!(ron.contains("name: \"Level_0\""));
+        assert!(ron.contains("(x: 0, y: 8, id: 3)"));
+    }
+}
@@ -0,0 +1,205 @@
+//! The `assets/manifest.ron` manifest: written into the stage by `bevy
+//! bundle` and `bevy package` (or standalone by `bevy assets manifest`),
+//! checked against a built bundle by `bevy assets verify`, and readable at
+//! runtime by the generated `asset_verify.rs` module. Per-file hashes also
+//! give patchers and web caches something stable to key on.
+//!
+//! Hashes are FNV-1a so the manifest a CI bundler writes still matches what
+//! a shipped game recomputes; see [`crate::fs_util::fnv1a64`].
+
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+use clap::Args;
+
+use crate::i18n::localize;
+use crate::{fs_util, output};
+
+#[derive(Args)]
+pub struct ManifestArgs {
+    /// Project directory; defaults to the current directory
+    #[arg(long)]
+    pub project: Option<PathBuf>,
+
+    /// Assets directory, relative to the project
+    #[arg(long, default_value = "assets")]
+    pub assets: PathBuf,
+}
+
+/// File name of the manifest, relative to the bundled assets directory.
+pub const MANIFEST_FILE: &str = "manifest.ron";
+
+/// One manifest row: a file's path relative to `assets/`, with `/`
+/// separators on every platform, plus its coarse type, size, and content
+/// hash.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ManifestEntry {
+    pub path: String,
+    pub kind: String,
+    pub size: u64,
+    pub hash: u64,
+}
+
+pub fn run(args: ManifestArgs) -> anyhow::Result<()> {
+    let project = args
+        .project
+        .unwrap_or_else(|| crate::project::locate(Path::new(".")));
+    let assets = project.join(&args.assets);
+    anyhow::ensure!(assets.is_dir(), "{} is not a directory", assets.display());
+    let recorded = write(&assets)?;
+    output::ok(&localize!(
+        "assets-manifest-written",
+        count = recorded,
+        file = assets.join(MANIFEST_FILE).display()
+    ));
+    Ok(())
+}
+
+/// The coarse asset type recorded per manifest row, from the extension.
+pub(crate) fn kind_of(path: &str) -> &'static str {
+    let extension = path.rsplit('.').next().unwrap_or("").to_lowercase();
+    match extension.as_str() {
+        "png" | "jpg" | "jpeg" | "ktx2" | "basis" | "dds" | "tga" | "bmp" => "texture",
+        "ogg" | "wav" | "flac" | "mp3" => "audio",
+        "gltf" | "glb" | "obj" | "fbx" => "model",
+        "ron" | "scn" => "scene",
+        "ttf" | "otf" => "font",
+        "wgsl" | "glsl" | "vert" | "frag" => "shader",
+        _ => "other",
+    }
+}
+
+/// Renders manifest RON for the given entries.
+pub fn format(entries: &[ManifestEntry]) -> String {
+    let mut ron = String::from(
+        "// Asset integrity manifest written by `bevy bundle`.\n// Check a built bundle with `bevy assets verify`.\n(\n    files: [\n",
+    );
+    for entry in entries {
+        ron.push_str(&format!(
+            "        (path: \"{}\", kind: {}, size: {}, hash: \"{:016x}\"),\n",
+            entry.path, entry.kind, entry.size, entry.hash
+        ));
+    }
+    ron.push_str("    ],\n)\n");
+    ron
+}
+
+/// Parses a manifest back into entries. The parser is line-oriented over
+/// the exact shape [`format`] writes — the CLI owns both ends — and rejects
+/// anything that only looks similar.
+pub fn parse(contents: &str) -> anyhow::Result<Vec<ManifestEntry>> {
+    let row = regex::Regex::new(
+        r#"^\(path: "([^"]+)", kind: ([a-z]+), size: (\d+), hash: "([0-9a-f]{16})"\),$"#,
+    )
+    .expect("manifest row pattern compiles");
+    let mut entries = Vec::new();
+    for line in contents.lines() {
+        let line = line.trim();
+        if line.is_empty()
+            || line.starts_with("//")
+            || matches!(line, "(" | ")" | "files: [" | "],")
+        {
+            continue;
+        }
+        let captures = row
+            .captures(line)
+            .with_context(|| format!("unrecognized manifest line: {line}"))?;
+        entries.push(ManifestEntry {
+            path: captures[1].to_string(),
+            kind: captures[2].to_string(),
+            size: captures[3].parse()?,
+            hash: u64::from_str_radix(&captures[4], 16)?,
+        });
+    }
+    Ok(entries)
+}
+
+/// Hashes every file under `assets` (except the manifest itself) and writes
+/// `manifest.ron` alongside them. Returns the number of files recorded.
+pub fn write(assets: &Path) -> anyhow::Result<usize> {
+    let mut entries = Vec::new();
+    for path in sorted_files(assets)? {
+        if path.file_name().is_some_and(|name| name == MANIFEST_FILE) {
+            continue;
+        }
+        let rel = path.strip_prefix(assets)?;
+        let contents =
+            std::fs::read(&path).with_context(|| format!("failed to read {}", path.display()))?;
+        let path = rel
+            .components()
+            .map(|component| component.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/");
+        entries.push(ManifestEntry {
+            kind: kind_of(&path).to_string(),
+            size: contents.len() as u64,
+            hash: fs_util::fnv1a64(&contents),
+            path,
+        });
+    }
+    std::fs::create_dir_all(assets)?;
+    fs_util::write_file(
+        &assets.join(MANIFEST_FILE),
+        format(&entries).as_bytes(),
+        false,
+    )?;
+    Ok(entries.len())
+}
+
+/// Every file under `dir`, recursively, in a deterministic order.
+pub(crate) fn sorted_files(dir: &Path) -> anyhow::Result<Vec<std::path::PathBuf>> {
+    fn visit(dir: &Path, files: &mut Vec<std::path::PathBuf>) -> anyhow::Result<()> {
+        for entry in std::fs::read_dir(dir)? {
+            let path = entry?.path();
+            if path.is_dir() {
+                visit(&path, files)?;
+            } else {
+                files.push(path);
+            }
+        }
+        Ok(())
+    }
+    let mut files = Vec::new();
+    if dir.is_dir() {
+        visit(dir, &mut files)?;
+    }
+    files.sort();
+    Ok(files)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn manifests_roundtrip_through_format_and_parse() {
+        let entries = vec![
+            ManifestEntry {
+                path: "sprites/hero.png".to_string(),
+                kind: "texture".to_string(),
+                size: 1234,
+                hash: 0xdead_beef_dead_beef,
+            },
+            ManifestEntry {
+                path: "music/theme.ogg".to_string(),
+                kind: "audio".to_string(),
+                size: 0,
+                hash: 0,
+            },
+        ];
+        assert_eq!(parse(&format(&entries)).unwrap(), entries);
+    }
+
+    #[test]
+    fn tampered_manifest_lines_are_rejected() {
+        assert!(parse("(path: \"a.png\", kind: texture, size: x, hash: \"00\"),").is_err());
+    }
+
+    #[test]
+    fn kinds_come_from_the_extension() {
+        assert_eq!(kind_of("sprites/hero.png"), "texture");
+        assert_eq!(kind_of("models/ship.glb"), "model");
+        assert_eq!(kind_of("levels/overworld.ron"), "scene");
+        assert_eq!(kind_of("README"), "other");
+    }
+}
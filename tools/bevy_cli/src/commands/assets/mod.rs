@@ -0,0 +1,356 @@
+use std::collections::BTreeMap;
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use anyhow::Context;
+use clap::{Args, Subcommand};
+use serde::Deserialize;
+
+use crate::fs_util;
+use crate::i18n::localize;
+
+pub mod atlas;
+pub mod audio;
+pub mod audit;
+pub(crate) mod formats;
+pub mod levels;
+pub mod manifest;
+pub mod notify;
+pub mod optimize;
+pub mod packs;
+pub mod pipeline;
+pub mod placeholder;
+pub mod starter;
+pub mod textures;
+pub mod validate;
+pub mod verify;
+
+#[derive(Args)]
+pub struct AssetsArgs {
+    #[command(subcommand)]
+    pub command: AssetsCommand,
+}
+
+#[derive(Subcommand)]
+pub enum AssetsCommand {
+    /// Watch configured source directories (e.g. `art-src/*.blend`) and run
+    /// their export commands into `assets/` whenever a source changes
+    WatchSources {
+        /// Project directory; defaults to the current directory
+        #[arg(long)]
+        project: Option<PathBuf>,
+
+        /// Export everything that changed, then exit instead of watching
+        #[arg(long)]
+        once: bool,
+
+        /// Poll interval while watching
+        #[arg(long, default_value_t = 2000, value_name = "MILLISECONDS")]
+        interval_ms: u64,
+    },
+
+    /// Pack sprite frames into an atlas descriptor and loader
+    Atlas(atlas::AtlasArgs),
+
+    /// Audit asset licenses and generate the third-party notice file
+    Audit(audit::AuditArgs),
+
+    /// Import levels from external editors (Tiled, LDtk)
+    Levels(levels::LevelsArgs),
+
+    /// Compress PNG/JPG textures to KTX2 with an incremental mapping
+    CompressTextures(textures::CompressTexturesArgs),
+
+    /// Transcode WAV/FLAC sources to OGG for shipping builds
+    TranscodeAudio(audio::TranscodeAudioArgs),
+
+    /// Set up the asset processor: features, output dir, meta files
+    InitPipeline(pipeline::PipelineArgs),
+
+    /// Write the assets/manifest.ron of paths, types, sizes, and hashes
+    Manifest(manifest::ManifestArgs),
+
+    /// Broadcast asset changes to a running game over TCP for hot reloads
+    Notify(notify::NotifyArgs),
+
+    /// Rewrite assets into delivery-friendly formats and report the sizes
+    Optimize(optimize::OptimizeArgs),
+
+    /// Search the registries' asset entries
+    Search(packs::SearchArgs),
+
+    /// Install an asset pack or crate-based asset into the project
+    Install(packs::InstallArgs),
+
+    /// Install a curated starter pack and record its attribution
+    Starter(starter::StarterArgs),
+
+    /// Generate a labeled placeholder texture, mesh, or audio file
+    Placeholder(placeholder::PlaceholderArgs),
+
+    /// Find the placeholder assets still in the project
+    Placeholders(placeholder::PlaceholdersArgs),
+
+    /// Check the assets directory for duplicates and unreferenced files
+    Validate(validate::ValidateArgs),
+
+    /// Check a built bundle against its integrity manifest
+    Verify(verify::VerifyArgs),
+}
+
+/// Cache of source-content hashes, so unchanged sources are never
+/// re-exported.
+const HASH_CACHE: &str = ".bevy/source-hashes.json";
+
+/// The `Bevy.toml` sections the asset tooling reads.
+#[derive(Debug, Default, Deserialize)]
+struct ProjectConfig {
+    #[serde(default)]
+    assets: AssetsSection,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct AssetsSection {
+    /// Per-pattern export rules for source assets.
+    #[serde(default)]
+    sources: Vec<SourceRule>,
+    /// Naming rules `assets validate` enforces.
+    #[serde(default)]
+    naming: Option<NamingRules>,
+}
+
+/// Naming conventions for asset files, declared in `Bevy.toml`:
+///
+/// ```toml
+/// [assets.naming]
+/// style = "snake_case"
+/// max_path_length = 120
+/// severity = "error"
+///
+/// [assets.naming.prefixes]
+/// sprites = "spr_"
+/// sounds = "snd_"
+/// ```
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct NamingRules {
+    /// `snake_case`, `kebab-case`, or `any`.
+    #[serde(default = "default_style")]
+    pub style: String,
+    /// Longest allowed path relative to `assets/`; console SDKs commonly
+    /// cap this well below what desktop filesystems allow.
+    #[serde(default)]
+    pub max_path_length: Option<usize>,
+    /// Whether violations fail the command or only warn.
+    #[serde(default)]
+    pub severity: Severity,
+    /// Required file-name prefix per top-level assets folder.
+    #[serde(default)]
+    pub prefixes: std::collections::BTreeMap<String, String>,
+}
+
+fn default_style() -> String {
+    "any".to_string()
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Severity {
+    #[default]
+    Error,
+    Warning,
+}
+
+/// One export rule: which sources it covers, the command that exports one
+/// of them, and where the result lands.
+///
+/// ```toml
+/// [[assets.sources]]
+/// pattern = "art-src/*.blend"
+/// command = "blender --background {source} --export-gltf {dest}"
+/// dest = "assets/models/{stem}.gltf"
+/// ```
+///
+/// `{source}` and `{dest}` in the command, and `{stem}` in `dest`, are
+/// replaced per file.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SourceRule {
+    pub pattern: String,
+    pub command: String,
+    pub dest: String,
+}
+
+pub fn run(args: AssetsArgs) -> anyhow::Result<()> {
+    match args.command {
+        AssetsCommand::WatchSources {
+            project,
+            once,
+            interval_ms,
+        } => watch_sources(
+            &project.unwrap_or_else(|| crate::project::locate(Path::new("."))),
+            once,
+            interval_ms,
+        ),
+        AssetsCommand::Atlas(args) => atlas::run(args),
+        AssetsCommand::Audit(args) => audit::run(args),
+        AssetsCommand::Levels(args) => levels::run(args),
+        AssetsCommand::CompressTextures(args) => textures::run(args),
+        AssetsCommand::TranscodeAudio(args) => audio::run(args),
+        AssetsCommand::InitPipeline(args) => pipeline::run(args),
+        AssetsCommand::Manifest(args) => manifest::run(args),
+        AssetsCommand::Notify(args) => notify::run(args),
+        AssetsCommand::Optimize(args) => optimize::run(args),
+        AssetsCommand::Search(args) => packs::run_search(args),
+        AssetsCommand::Install(args) => packs::run_install(args),
+        AssetsCommand::Starter(args) => starter::run(args),
+        AssetsCommand::Placeholder(args) => placeholder::run(args),
+        AssetsCommand::Placeholders(args) => placeholder::run_list(args),
+        AssetsCommand::Validate(args) => validate::run(args),
+        AssetsCommand::Verify(args) => verify::run(args),
+    }
+}
+
+fn watch_sources(project: &Path, once: bool, interval_ms: u64) -> anyhow::Result<()> {
+    let manifest_path = project.join(crate::project::MANIFEST);
+    let contents = std::fs::read_to_string(&manifest_path)
+        .with_context(|| format!("failed to read {}", manifest_path.display()))?;
+    let config: ProjectConfig = toml::from_str(&contents)
+        .with_context(|| format!("failed to parse {}", manifest_path.display()))?;
+    anyhow::ensure!(
+        !config.assets.sources.is_empty(),
+        "Bevy.toml declares no [[assets.sources]] rules"
+    );
+
+    let cache_path = project.join(HASH_CACHE);
+    let mut cache: BTreeMap<String, String> = std::fs::read_to_string(&cache_path)
+        .ok()
+        .and_then(|contents| serde_json::from_str(&contents).ok())
+        .unwrap_or_default();
+    loop {
+        sync_once(project, &config.assets.sources, &cache_path, &mut cache)?;
+        if once {
+            return Ok(());
+        }
+        std::thread::sleep(Duration::from_millis(interval_ms));
+    }
+}
+
+/// One pass over every rule: exports sources whose content hash changed
+/// since the last successful export and reports what happened.
+fn sync_once(
+    project: &Path,
+    rules: &[SourceRule],
+    cache_path: &Path,
+    cache: &mut BTreeMap<String, String>,
+) -> anyhow::Result<()> {
+    let mut exported = 0usize;
+    let mut skipped = 0usize;
+    for rule in rules {
+        let pattern = project.join(&rule.pattern);
+        let pattern = pattern
+            .to_str()
+            .context("source pattern is not valid UTF-8")?;
+        for source in glob::glob(pattern)?.flatten() {
+            let key = source.to_string_lossy().into_owned();
+            let hash = hash_file(&source)?;
+            if cache.get(&key) == Some(&hash) {
+                skipped += 1;
+                continue;
+            }
+            export(project, rule, &source)?;
+            // Record per file right after its export, so a cancelled watch
+            // never repeats finished work.
+            cache.insert(key, hash);
+            save_cache(cache_path, cache)?;
+            exported += 1;
+        }
+    }
+    if exported > 0 || skipped > 0 {
+        println!(
+            "{}",
+            localize!(
+                "assets-sources-processed",
+                count = exported,
+                skipped = skipped
+            )
+        );
+    }
+    Ok(())
+}
+
+/// Exports one source file by running the rule's command with the
+/// placeholders substituted.
+fn export(project: &Path, rule: &SourceRule, source: &Path) -> anyhow::Result<()> {
+    let stem = source
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let dest = project.join(rule.dest.replace("{stem}", &stem));
+    if let Some(parent) = dest.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let command = rule
+        .command
+        .replace("{source}", &source.to_string_lossy())
+        .replace("{dest}", &dest.to_string_lossy());
+    println!(
+        "{}",
+        localize!(
+            "assets-source-exported",
+            source = source.display(),
+            dest = dest.display()
+        )
+    );
+    let status = shell_command(&command)
+        .status()
+        .with_context(|| format!("failed to run `{command}`"))?;
+    anyhow::ensure!(status.success(), "export command failed: `{command}`");
+    Ok(())
+}
+
+#[cfg(unix)]
+fn shell_command(command: &str) -> std::process::Command {
+    let mut shell = std::process::Command::new("sh");
+    shell.arg("-c").arg(command);
+    shell
+}
+
+#[cfg(windows)]
+fn shell_command(command: &str) -> std::process::Command {
+    let mut shell = std::process::Command::new("cmd");
+    shell.arg("/C").arg(command);
+    shell
+}
+
+fn hash_file(path: &Path) -> anyhow::Result<String> {
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    std::fs::read(path)?.hash(&mut hasher);
+    Ok(format!("{:016x}", hasher.finish()))
+}
+
+fn save_cache(path: &Path, cache: &BTreeMap<String, String>) -> anyhow::Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    fs_util::write_file(path, serde_json::to_string_pretty(cache)?.as_bytes(), false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rules_parse_from_bevy_toml() {
+        let config: ProjectConfig = toml::from_str(
+            "[[assets.sources]]\n\
+             pattern = \"art-src/*.blend\"\n\
+             command = \"blender --background {source} --export-gltf {dest}\"\n\
+             dest = \"assets/models/{stem}.gltf\"\n",
+        )
+        .unwrap();
+        assert_eq!(config.assets.sources.len(), 1);
+        assert_eq!(config.assets.sources[0].pattern, "art-src/*.blend");
+    }
+}
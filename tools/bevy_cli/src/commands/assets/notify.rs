@@ -0,0 +1,204 @@
+//! `bevy assets notify`: a hot-reload companion for running games.
+//!
+//! Watches `assets/` and broadcasts each changed path over a plain TCP
+//! channel, one line per file, so a running game can reload assets without
+//! restarting. Games built with Bevy's `file_watcher` feature do not need
+//! this; the channel exists for platforms where that feature is unavailable
+//! (notably pre-built or remote sessions) and for editor tooling that wants
+//! the same change feed. `--snippet` prints the drop-in client system for
+//! the game side. Dependency-free for the same reason `bevy serve` is.
+
+use std::collections::BTreeMap;
+use std::io::Write;
+use std::net::{TcpListener, TcpStream};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use anyhow::Context;
+use clap::Args;
+
+use crate::i18n::localize;
+
+#[derive(Args)]
+pub struct NotifyArgs {
+    /// Project directory; defaults to the nearest project root
+    #[arg(long)]
+    pub project: Option<PathBuf>,
+
+    /// Port the change feed listens on
+    #[arg(long, default_value_t = 7878)]
+    pub port: u16,
+
+    /// Poll interval while watching
+    #[arg(long, default_value_t = 1000, value_name = "MILLISECONDS")]
+    pub interval_ms: u64,
+
+    /// Print the Rust client system to paste into the game, then exit
+    #[arg(long)]
+    pub snippet: bool,
+}
+
+/// Size and mtime fingerprints of every file under `assets/`, keyed by the
+/// `/`-separated path relative to the assets root — the form Bevy's asset
+/// server uses for reloads.
+type Snapshot = BTreeMap<String, u64>;
+
+pub fn run(args: NotifyArgs) -> anyhow::Result<()> {
+    if args.snippet {
+        print!("{}", client_snippet(args.port));
+        return Ok(());
+    }
+    let project = args
+        .project
+        .clone()
+        .unwrap_or_else(|| crate::project::locate(Path::new(".")));
+    let assets = project.join("assets");
+    anyhow::ensure!(
+        assets.is_dir(),
+        "{} has no assets directory",
+        project.display()
+    );
+
+    let listener = TcpListener::bind(("127.0.0.1", args.port))
+        .with_context(|| format!("failed to listen on port {}", args.port))?;
+    let clients: Arc<Mutex<Vec<TcpStream>>> = Arc::new(Mutex::new(Vec::new()));
+    let acceptor = Arc::clone(&clients);
+    std::thread::spawn(move || {
+        for stream in listener.incoming().flatten() {
+            acceptor.lock().expect("client list lock").push(stream);
+        }
+    });
+    println!("{}", localize!("assets-notify-listening", port = args.port));
+
+    let mut last = snapshot(&assets);
+    loop {
+        std::thread::sleep(Duration::from_millis(args.interval_ms));
+        let current = snapshot(&assets);
+        for path in changed_paths(&last, &current) {
+            println!("{}", localize!("assets-notify-changed", path = path));
+            broadcast(&clients, &path);
+        }
+        last = current;
+    }
+}
+
+/// The paths present in `current` that are new or differ from `last`;
+/// deletions are not announced — there is nothing for the game to reload.
+fn changed_paths(last: &Snapshot, current: &Snapshot) -> Vec<String> {
+    current
+        .iter()
+        .filter(|(path, stamp)| last.get(*path) != Some(stamp))
+        .map(|(path, _)| path.clone())
+        .collect()
+}
+
+fn snapshot(assets: &Path) -> Snapshot {
+    use std::hash::{Hash, Hasher};
+    let mut files = Snapshot::new();
+    let mut stack = vec![assets.to_path_buf()];
+    while let Some(path) = stack.pop() {
+        if path.is_dir() {
+            if let Ok(entries) = std::fs::read_dir(&path) {
+                stack.extend(entries.flatten().map(|entry| entry.path()));
+            }
+            continue;
+        }
+        let Ok(metadata) = path.metadata() else {
+            continue;
+        };
+        let Ok(relative) = path.strip_prefix(assets) else {
+            continue;
+        };
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        metadata.len().hash(&mut hasher);
+        if let Ok(modified) = metadata.modified() {
+            modified.hash(&mut hasher);
+        }
+        let name: Vec<String> = relative
+            .components()
+            .map(|component| component.as_os_str().to_string_lossy().into_owned())
+            .collect();
+        files.insert(name.join("/"), hasher.finish());
+    }
+    files
+}
+
+/// Writes one changed path to every connected client, dropping the ones
+/// whose connection has gone away.
+fn broadcast(clients: &Mutex<Vec<TcpStream>>, path: &str) {
+    let line = format!("{path}\n");
+    clients
+        .lock()
+        .expect("client list lock")
+        .retain_mut(|stream| stream.write_all(line.as_bytes()).is_ok());
+}
+
+/// The game-side client: a system that drains the feed and asks the asset
+/// server to reload each announced path.
+fn client_snippet(port: u16) -> String {
+    format!(
+        r#"// Paste into the game and add `asset_notify_client` to Update.
+// Connects to `bevy assets notify` and reloads each announced asset.
+use std::io::{{BufRead, BufReader}};
+use std::sync::mpsc::{{channel, Receiver}};
+
+use bevy::asset::AssetServer;
+use bevy::prelude::*;
+
+#[derive(Resource)]
+struct AssetNotify(Receiver<String>);
+
+fn asset_notify_setup(mut commands: Commands) {{
+    let (sender, receiver) = channel();
+    std::thread::spawn(move || {{
+        let Ok(stream) = std::net::TcpStream::connect("127.0.0.1:{port}") else {{
+            return;
+        }};
+        for line in BufReader::new(stream).lines().map_while(Result::ok) {{
+            if sender.send(line).is_err() {{
+                return;
+            }}
+        }}
+    }});
+    commands.insert_resource(AssetNotify(receiver));
+}}
+
+fn asset_notify_client(notify: Res<AssetNotify>, asset_server: Res<AssetServer>) {{
+    for path in notify.0.try_iter() {{
+        asset_server.reload(path);
+    }}
+}}
+"#
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_new_and_modified_paths_are_announced() {
+        let last = Snapshot::from([
+            ("theme.ogg".to_string(), 1),
+            ("sprites/hero.png".to_string(), 2),
+            ("old.ron".to_string(), 3),
+        ]);
+        let current = Snapshot::from([
+            ("theme.ogg".to_string(), 1),
+            ("sprites/hero.png".to_string(), 9),
+            ("fresh.ron".to_string(), 4),
+        ]);
+        assert_eq!(
+            changed_paths(&last, &current),
+            vec!["fresh.ron", "sprites/hero.png"]
+        );
+    }
+
+    #[test]
+    fn the_client_snippet_targets_the_configured_port() {
+        let snippet = client_snippet(9191);
+        assert!(snippet.contains("127.0.0.1:9191"));
+        assert!(snippet.contains("asset_server.reload"));
+    }
+}
@@ -0,0 +1,226 @@
+//! `bevy assets optimize`: the whole web-delivery pipeline in one pass.
+//!
+//! Composes the existing converters — `compress-textures` for KTX2 and
+//! `transcode-audio` for OGG — copies everything else into the same output
+//! tree, pre-compresses the formats web servers serve compressed (brotli
+//! when installed, gzip otherwise), and reports the download size before
+//! and after against the `[size] assets` budget from `Bevy.toml`.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+use clap::Args;
+use serde::Deserialize;
+
+use crate::i18n::localize;
+use crate::{fs_util, output};
+
+#[derive(Args)]
+pub struct OptimizeArgs {
+    /// Project directory; defaults to the nearest project root
+    #[arg(long)]
+    pub project: Option<PathBuf>,
+
+    /// Delivery target; only `web` is currently supported
+    #[arg(long, default_value = "web")]
+    pub target: OptimizeTarget,
+
+    /// Output tree for the optimized assets
+    #[arg(long, default_value = "assets-compressed")]
+    pub out_dir: PathBuf,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum OptimizeTarget {
+    Web,
+}
+
+/// Extensions the texture and audio passes replace; their sources are not
+/// copied into the output tree.
+const CONVERTED: &[&str] = &["png", "jpg", "jpeg", "wav", "flac"];
+
+/// Extensions worth serving pre-compressed; image and audio containers are
+/// already entropy-coded and skip this.
+const PRECOMPRESS: &[&str] = &[
+    "ron", "scn", "json", "toml", "txt", "md", "csv", "wgsl", "glsl", "gltf", "glb", "ttf", "otf",
+];
+
+#[derive(Debug, Default, Deserialize)]
+struct SizeSection {
+    #[serde(default)]
+    assets: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct ProjectConfig {
+    #[serde(default)]
+    size: SizeSection,
+}
+
+pub fn run(args: OptimizeArgs) -> anyhow::Result<()> {
+    let project = args
+        .project
+        .clone()
+        .unwrap_or_else(|| crate::project::locate(Path::new(".")));
+    let assets = project.join("assets");
+    anyhow::ensure!(
+        assets.is_dir(),
+        "{} has no assets directory",
+        project.display()
+    );
+    let config: ProjectConfig = load_config(&project)?;
+
+    super::textures::run(super::textures::CompressTexturesArgs {
+        project: Some(project.clone()),
+        out_dir: args.out_dir.clone(),
+        force: false,
+    })?;
+    super::audio::run(super::audio::TranscodeAudioArgs {
+        project: Some(project.clone()),
+        out_dir: args.out_dir.clone(),
+        jobs: None,
+        force: false,
+    })?;
+
+    let out = project.join(&args.out_dir);
+    for source in super::manifest::sorted_files(&assets)? {
+        let rel = source
+            .strip_prefix(&assets)
+            .expect("files come from the assets walk");
+        if is_converted(rel) || rel.to_string_lossy().ends_with(".license") {
+            continue;
+        }
+        let dest = out.join(rel);
+        if let Some(parent) = dest.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::copy(&source, &dest)
+            .with_context(|| format!("failed to copy {}", source.display()))?;
+    }
+
+    let compressor = compressor();
+    if let Some(compressor) = compressor {
+        for file in super::manifest::sorted_files(&out)? {
+            if !wants_precompression(&file) {
+                continue;
+            }
+            crate::subprocess::Subprocess::new(compressor)
+                .args(compressor_args(compressor, &file))
+                .run()
+                .with_context(|| format!("while pre-compressing {}", file.display()))?;
+        }
+    } else {
+        output::warn(&localize!("optimize-no-compressor"));
+    }
+
+    let before = fs_util::dir_size(&assets);
+    let after = download_size(&out)?;
+    println!(
+        "{}",
+        localize!(
+            "optimize-report",
+            before = fs_util::human_size(before),
+            after = fs_util::human_size(after),
+            dir = args.out_dir.display()
+        )
+    );
+    super::super::size::check_budget("assets", Some(after), config.size.assets.as_deref())?;
+    output::ok(&localize!("optimize-done"));
+    Ok(())
+}
+
+fn is_converted(rel: &Path) -> bool {
+    rel.extension()
+        .and_then(|extension| extension.to_str())
+        .is_some_and(|extension| CONVERTED.contains(&extension.to_lowercase().as_str()))
+}
+
+fn wants_precompression(file: &Path) -> bool {
+    file.extension()
+        .and_then(|extension| extension.to_str())
+        .is_some_and(|extension| PRECOMPRESS.contains(&extension.to_lowercase().as_str()))
+}
+
+/// The best installed pre-compressor; brotli beats gzip on every asset
+/// type and all current browsers accept it.
+fn compressor() -> Option<&'static str> {
+    ["brotli", "gzip"]
+        .into_iter()
+        .find(|compressor| super::super::doctor::on_path(compressor))
+}
+
+/// Writes `<file>.br` or `<file>.gz` beside the file, keeping the
+/// original for servers that negotiate encodings per request.
+fn compressor_args(compressor: &str, file: &Path) -> Vec<String> {
+    let path = file.to_string_lossy().into_owned();
+    match compressor {
+        "brotli" => vec![
+            "-f".to_string(),
+            "-k".to_string(),
+            "-q".to_string(),
+            "11".to_string(),
+            path,
+        ],
+        _ => vec!["-f".to_string(), "-k".to_string(), "-9".to_string(), path],
+    }
+}
+
+/// What a browser actually downloads: per file, the pre-compressed sibling
+/// when one exists, the file itself otherwise.
+fn download_size(out: &Path) -> anyhow::Result<u64> {
+    let mut total = 0u64;
+    for file in super::manifest::sorted_files(out)? {
+        let name = file.to_string_lossy().into_owned();
+        if name.ends_with(".br") || name.ends_with(".gz") {
+            continue;
+        }
+        let own = file.metadata()?.len();
+        let compressed = ["br", "gz"]
+            .iter()
+            .filter_map(|suffix| {
+                std::fs::metadata(format!("{name}.{suffix}"))
+                    .ok()
+                    .map(|metadata| metadata.len())
+            })
+            .min();
+        total += compressed.map_or(own, |compressed| compressed.min(own));
+    }
+    Ok(total)
+}
+
+fn load_config(project: &Path) -> anyhow::Result<ProjectConfig> {
+    let manifest_path = project.join(crate::project::MANIFEST);
+    if !manifest_path.is_file() {
+        return Ok(ProjectConfig::default());
+    }
+    toml::from_str(&std::fs::read_to_string(&manifest_path)?)
+        .with_context(|| format!("failed to parse {}", manifest_path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn converted_and_precompressed_extensions_are_disjoint() {
+        assert!(is_converted(Path::new("sprites/hero.PNG")));
+        assert!(!is_converted(Path::new("levels/overworld.ron")));
+        assert!(wants_precompression(Path::new("levels/overworld.ron")));
+        assert!(!wants_precompression(Path::new("music/theme.ogg")));
+        for extension in CONVERTED {
+            assert!(!PRECOMPRESS.contains(extension));
+        }
+    }
+
+    #[test]
+    fn compressor_invocations_keep_the_original() {
+        assert_eq!(
+            compressor_args("brotli", Path::new("a.ron")),
+            vec!["-f", "-k", "-q", "11", "a.ron"]
+        );
+        assert_eq!(
+            compressor_args("gzip", Path::new("a.ron")),
+            vec!["-f", "-k", "-9", "a.ron"]
+        );
+    }
+}
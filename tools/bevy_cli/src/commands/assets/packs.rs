@@ -0,0 +1,115 @@
+//! `bevy assets search` and `bevy assets install`: the non-template side
+//! of the registry index.
+//!
+//! A registry's `templates.toml` can list `[[assets]]` entries alongside
+//! its templates: packs of files that get copied into the project's
+//! `assets/` directory, and crate-based assets that get added to
+//! `Cargo.toml` like `bevy add` would. Both reuse the registry fetching
+//! and caching machinery, so private registries and offline cache hits
+//! behave exactly as they do for templates.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+use clap::Args;
+
+use crate::config::CliConfig;
+use crate::i18n::localize;
+use crate::{fs_util, output, registry};
+
+#[derive(Args)]
+pub struct SearchArgs {
+    /// Text to look for in asset names and descriptions; lists everything
+    /// when omitted
+    pub query: Option<String>,
+}
+
+#[derive(Args)]
+pub struct InstallArgs {
+    /// Name of the asset entry to install
+    pub name: String,
+
+    /// Project directory; defaults to the nearest project root
+    #[arg(long)]
+    pub project: Option<PathBuf>,
+}
+
+pub fn run_search(args: SearchArgs) -> anyhow::Result<()> {
+    let config = CliConfig::load()?;
+    let mut found =
+        registry::search_assets(&config.registries, args.query.as_deref().unwrap_or(""))?;
+    if found.is_empty() {
+        println!("{}", localize!("no-assets-found"));
+        return Ok(());
+    }
+    output::sort_localized(&mut found, |asset| asset.entry.name.as_str());
+    let count = found.len();
+    for asset in found {
+        let kind = if asset.entry.crate_name.is_some() {
+            "crate"
+        } else {
+            "pack"
+        };
+        println!(
+            "{}",
+            localize!(
+                "assets-search-result",
+                name = asset.entry.name,
+                kind = kind,
+                registry = asset.registry,
+                description = asset.entry.description.as_deref().unwrap_or("-")
+            )
+        );
+    }
+    println!("{}", localize!("assets-found", count = count));
+    Ok(())
+}
+
+pub fn run_install(args: InstallArgs) -> anyhow::Result<()> {
+    let project = args
+        .project
+        .clone()
+        .unwrap_or_else(|| crate::project::locate(Path::new(".")));
+    anyhow::ensure!(
+        project.join("Cargo.toml").is_file(),
+        "{} does not contain a Cargo.toml",
+        project.display()
+    );
+    let config = CliConfig::load()?;
+    let found = registry::find_asset(&config.registries, &args.name)?;
+    if let Some(crate_name) = &found.entry.crate_name {
+        // Crate-based assets go through `bevy add`: same version
+        // resolution, same manifest edit, no plugin insertion — asset
+        // crates register their content through the asset server.
+        super::super::add::run(super::super::add::AddArgs {
+            name: crate_name.clone(),
+            project: Some(project),
+            version: None,
+            no_plugin: true,
+        })?;
+        return Ok(());
+    }
+    let dir = found
+        .dir
+        .as_ref()
+        .with_context(|| format!("asset `{}` has neither a path nor a crate", args.name))?;
+    anyhow::ensure!(
+        dir.is_dir(),
+        "registry entry `{}` points at a missing directory {}",
+        args.name,
+        dir.display()
+    );
+    let dest = project.join("assets").join(&found.entry.name);
+    anyhow::ensure!(
+        !dest.exists(),
+        "{} already exists; remove it to reinstall",
+        dest.display()
+    );
+    fs_util::copy_dir(dir, &dest)?;
+    output::ok(&localize!(
+        "assets-pack-installed",
+        name = found.entry.name,
+        path = dest.strip_prefix(&project).unwrap_or(&dest).display()
+    ));
+    Ok(())
+}
@@ -0,0 +1,212 @@
+//! `bevy assets init-pipeline`: turn on Bevy's asset processor.
+//!
+//! Processed assets need three things wired up: the `asset_processor` and
+//! `file_watcher` Cargo features, the processed-output directory, and
+//! `.meta` files telling the processor how to load each source asset. This
+//! sets all three up in an existing project — the features are edited into
+//! `Cargo.toml` in place, the output directory is created and gitignored,
+//! and default meta files are generated per extension for assets that lack
+//! one.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+use clap::Args;
+
+use crate::i18n::localize;
+use crate::{fs_util, output};
+
+#[derive(Args)]
+pub struct PipelineArgs {
+    /// Project directory; defaults to the nearest project root
+    #[arg(long)]
+    pub project: Option<PathBuf>,
+
+    /// Directory processed assets are written to
+    #[arg(long, default_value = "imported_assets")]
+    pub processed_dir: PathBuf,
+}
+
+/// Cargo features the asset processor needs on the `bevy` dependency.
+const PIPELINE_FEATURES: &[&str] = &["asset_processor", "file_watcher"];
+
+/// Default loader per source extension, for generated `.meta` files.
+/// Extensions not listed are left meta-less; their loaders are inferred at
+/// runtime as before.
+const DEFAULT_LOADERS: &[(&str, &str)] = &[
+    ("png", "bevy_render::texture::image_loader::ImageLoader"),
+    ("jpg", "bevy_render::texture::image_loader::ImageLoader"),
+    ("ktx2", "bevy_render::texture::image_loader::ImageLoader"),
+    ("ogg", "bevy_audio::audio_loader::AudioLoader"),
+    ("wav", "bevy_audio::audio_loader::AudioLoader"),
+    ("gltf", "bevy_gltf::loader::GltfLoader"),
+    ("glb", "bevy_gltf::loader::GltfLoader"),
+];
+
+pub fn run(args: PipelineArgs) -> anyhow::Result<()> {
+    let project = args
+        .project
+        .clone()
+        .unwrap_or_else(|| crate::project::locate(Path::new(".")));
+    let manifest_path = project.join("Cargo.toml");
+    anyhow::ensure!(
+        manifest_path.is_file(),
+        "{} does not contain a Cargo.toml",
+        project.display()
+    );
+
+    let manifest = std::fs::read_to_string(&manifest_path)?;
+    let edited = enable_bevy_features(&manifest, PIPELINE_FEATURES)?;
+    if edited != manifest {
+        fs_util::write_file(&manifest_path, edited.as_bytes(), false)?;
+        println!(
+            "{}",
+            localize!("pipeline-features", features = PIPELINE_FEATURES.join(", "))
+        );
+    }
+
+    let processed = project.join(&args.processed_dir);
+    std::fs::create_dir_all(&processed)?;
+    ignore_dir(&project, &args.processed_dir)?;
+
+    let mut written = 0usize;
+    let assets = project.join("assets");
+    if assets.is_dir() {
+        let mut stack = vec![assets];
+        while let Some(dir) = stack.pop() {
+            let Ok(entries) = std::fs::read_dir(&dir) else {
+                continue;
+            };
+            for entry in entries.flatten() {
+                let path = entry.path();
+                if path.is_dir() {
+                    stack.push(path);
+                    continue;
+                }
+                let Some(meta) = default_meta(&path) else {
+                    continue;
+                };
+                let meta_path = meta_path(&path);
+                if meta_path.exists() {
+                    continue;
+                }
+                fs_util::write_file(&meta_path, meta.as_bytes(), false)?;
+                written += 1;
+            }
+        }
+    }
+    output::ok(&localize!(
+        "pipeline-ready",
+        dir = args.processed_dir.display(),
+        count = written
+    ));
+    Ok(())
+}
+
+/// Adds the given features to the `bevy` dependency, converting a bare
+/// version string into a table when needed; already-present features are
+/// left alone and the rest of the manifest is untouched.
+fn enable_bevy_features(manifest: &str, features: &[&str]) -> anyhow::Result<String> {
+    let mut document: toml_edit::Document =
+        manifest.parse().context("Cargo.toml is not valid TOML")?;
+    let bevy = document
+        .get_mut("dependencies")
+        .and_then(|dependencies| dependencies.get_mut("bevy"))
+        .context("the project has no `bevy` dependency")?;
+    if let Some(version) = bevy.as_str().map(str::to_string) {
+        let mut table = toml_edit::InlineTable::new();
+        table.insert("version", version.into());
+        *bevy = toml_edit::value(table);
+    }
+    let table = bevy
+        .as_table_like_mut()
+        .context("unsupported `bevy` dependency form")?;
+    let list = match table
+        .get_mut("features")
+        .and_then(|item| item.as_array_mut())
+    {
+        Some(list) => list,
+        None => {
+            table.insert("features", toml_edit::value(toml_edit::Array::new()));
+            table
+                .get_mut("features")
+                .and_then(|item| item.as_array_mut())
+                .expect("features was just inserted as an array")
+        }
+    };
+    for feature in features {
+        let present = list.iter().any(|value| value.as_str() == Some(feature));
+        if !present {
+            list.push(*feature);
+        }
+    }
+    Ok(document.to_string())
+}
+
+/// The default `.meta` for a source asset, when its extension has a known
+/// loader.
+fn default_meta(asset: &Path) -> Option<String> {
+    let extension = asset.extension()?.to_str()?.to_lowercase();
+    let (_, loader) = DEFAULT_LOADERS
+        .iter()
+        .find(|(known, _)| *known == extension)?;
+    Some(format!(
+        "(\n    meta_format_version: \"1.0\",\n    asset: Load(\n        loader: \"{loader}\",\n        settings: (),\n    ),\n)\n"
+    ))
+}
+
+/// `sprites/hero.png` gets `sprites/hero.png.meta` beside it.
+fn meta_path(asset: &Path) -> PathBuf {
+    let mut name = asset.file_name().unwrap_or_default().to_os_string();
+    name.push(".meta");
+    asset.with_file_name(name)
+}
+
+/// Appends the processed directory to `.gitignore` unless already there.
+fn ignore_dir(project: &Path, dir: &Path) -> anyhow::Result<()> {
+    let path = project.join(".gitignore");
+    let line = format!("/{}/", dir.display());
+    let mut contents = std::fs::read_to_string(&path).unwrap_or_default();
+    if contents.lines().any(|existing| existing.trim() == line) {
+        return Ok(());
+    }
+    if !contents.is_empty() && !contents.ends_with('\n') {
+        contents.push('\n');
+    }
+    contents.push_str(&line);
+    contents.push('\n');
+    fs_util::write_file(&path, contents.as_bytes(), false)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn features_merge_into_string_and_table_dependencies() {
+        let edited =
+            enable_bevy_features("[dependencies]\nbevy = \"0.12\"\n", PIPELINE_FEATURES).unwrap();
+        assert!(edited.contains("version = \"0.12\""));
+        assert!(edited.contains("asset_processor"));
+
+        let edited = enable_bevy_features(
+            "[dependencies]\nbevy = { version = \"0.12\", features = [\"file_watcher\"] }\n",
+            PIPELINE_FEATURES,
+        )
+        .unwrap();
+        assert_eq!(edited.matches("file_watcher").count(), 1);
+        assert!(edited.contains("asset_processor"));
+    }
+
+    #[test]
+    fn metas_are_generated_only_for_known_extensions() {
+        let meta = default_meta(Path::new("assets/sprites/Hero.PNG")).unwrap();
+        assert!(meta.contains("ImageLoader"));
+        assert!(default_meta(Path::new("assets/levels/overworld.custom")).is_none());
+        assert_eq!(
+            meta_path(Path::new("assets/sprites/hero.png")),
+            Path::new("assets/sprites/hero.png.meta")
+        );
+    }
+}
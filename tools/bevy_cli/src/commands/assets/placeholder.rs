@@ -0,0 +1,346 @@
+//! Procedural placeholder assets: labeled checkerboard textures, simple
+//! meshes, and silent audio, all carrying a marker so they can be found (and
+//! replaced) later with `bevy assets placeholders list`.
+
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+use clap::{Args, Subcommand, ValueEnum};
+
+use crate::i18n::localize;
+
+/// Marker embedded in every generated file; `placeholders list` scans for
+/// it, so real art must not contain it. The name ends at the `;` the
+/// writers append, since binary data can follow immediately.
+const MARKER: &str = "bevy-placeholder:";
+
+#[derive(Args)]
+pub struct PlaceholderArgs {
+    /// What kind of asset to generate
+    #[arg(value_enum)]
+    pub kind: PlaceholderKind,
+
+    /// Name of the placeholder; baked into the texture label and the marker
+    pub name: String,
+
+    /// Directory the asset is written to
+    #[arg(long, default_value = "assets")]
+    pub out: PathBuf,
+
+    /// Texture edge length in pixels
+    #[arg(long, default_value_t = 256)]
+    pub size: u32,
+
+    /// Audio duration in seconds
+    #[arg(long, default_value_t = 1.0)]
+    pub duration: f32,
+}
+
+#[derive(Args)]
+pub struct PlaceholdersArgs {
+    #[command(subcommand)]
+    pub command: PlaceholdersCommand,
+}
+
+#[derive(Subcommand)]
+pub enum PlaceholdersCommand {
+    /// List every placeholder asset under the assets directory
+    List {
+        /// Assets directory to scan
+        #[arg(long, default_value = "assets")]
+        assets: PathBuf,
+    },
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum PlaceholderKind {
+    /// A checkerboard PNG with the name drawn across the middle
+    Texture,
+    /// A unit cube as a Wavefront `.obj`
+    Mesh,
+    /// Silent 16-bit mono WAV of the given duration
+    Audio,
+}
+
+pub fn run(args: PlaceholderArgs) -> anyhow::Result<()> {
+    std::fs::create_dir_all(&args.out)?;
+    let path = match args.kind {
+        PlaceholderKind::Texture => texture(&args)?,
+        PlaceholderKind::Mesh => mesh(&args)?,
+        PlaceholderKind::Audio => audio(&args)?,
+    };
+    println!(
+        "{}",
+        localize!("placeholder-created", path = path.display())
+    );
+    Ok(())
+}
+
+pub fn run_list(args: PlaceholdersArgs) -> anyhow::Result<()> {
+    match args.command {
+        PlaceholdersCommand::List { assets } => list(&assets),
+    }
+}
+
+fn list(assets: &Path) -> anyhow::Result<()> {
+    anyhow::ensure!(assets.is_dir(), "{} is not a directory", assets.display());
+    let mut found = 0usize;
+    let mut stack = vec![assets.to_path_buf()];
+    while let Some(dir) = stack.pop() {
+        let mut entries: Vec<_> = std::fs::read_dir(&dir)?.flatten().collect();
+        entries.sort_by_key(|entry| entry.path());
+        for entry in entries {
+            let path = entry.path();
+            if path.is_dir() {
+                stack.push(path);
+            } else if let Some(name) = marker_in(&path)? {
+                let rel = path.strip_prefix(assets).unwrap_or(&path);
+                println!("{}  ({name})", rel.display());
+                found += 1;
+            }
+        }
+    }
+    println!("{}", localize!("placeholders-found", count = found));
+    Ok(())
+}
+
+/// Returns the marked name if the file's head or tail contains the
+/// placeholder marker (the audio marker trails the sample data).
+fn marker_in(path: &Path) -> anyhow::Result<Option<String>> {
+    use std::io::{Read, Seek, SeekFrom};
+    const WINDOW: u64 = 64 * 1024;
+    let mut head = vec![0u8; WINDOW as usize];
+    let mut file = std::fs::File::open(path)?;
+    let read = file.read(&mut head)?;
+    head.truncate(read);
+    let len = file.metadata()?.len();
+    if len > WINDOW {
+        let mut tail = vec![0u8; WINDOW.min(len - WINDOW) as usize];
+        file.seek(SeekFrom::End(-(tail.len() as i64)))?;
+        file.read_exact(&mut tail)?;
+        head.extend_from_slice(&tail);
+    }
+    let Some(start) = head
+        .windows(MARKER.len())
+        .position(|window| window == MARKER.as_bytes())
+    else {
+        return Ok(None);
+    };
+    let name: String = head[start + MARKER.len()..]
+        .iter()
+        .take_while(|byte| byte.is_ascii_graphic() && **byte != b';')
+        .take(64)
+        .map(|byte| *byte as char)
+        .collect();
+    Ok(Some(name))
+}
+
+/// Writes a magenta/black checkerboard with the name drawn across the
+/// middle in a scaled 5x7 pixel font, plus the marker in a tEXt chunk.
+fn texture(args: &PlaceholderArgs) -> anyhow::Result<PathBuf> {
+    let size = args.size.max(16);
+    let cell = (size / 8).max(1);
+    let mut pixels = vec![0u8; (size * size * 3) as usize];
+    for y in 0..size {
+        for x in 0..size {
+            let magenta = ((x / cell) + (y / cell)).is_multiple_of(2);
+            let offset = ((y * size + x) * 3) as usize;
+            pixels[offset] = if magenta { 0xff } else { 0x20 };
+            pixels[offset + 2] = if magenta { 0xff } else { 0x20 };
+        }
+    }
+    draw_label(&mut pixels, size, &args.name.to_uppercase());
+
+    let path = args.out.join(format!("{}.png", args.name));
+    let file = std::fs::File::create(&path)
+        .with_context(|| format!("failed to create {}", path.display()))?;
+    let mut encoder = png::Encoder::new(file, size, size);
+    encoder.set_color(png::ColorType::Rgb);
+    encoder.set_depth(png::BitDepth::Eight);
+    encoder.add_text_chunk("Comment".to_string(), format!("{MARKER}{};", args.name))?;
+    encoder.write_header()?.write_image_data(&pixels)?;
+    Ok(path)
+}
+
+/// Draws `label` centered in white, scaled so it spans most of the texture.
+fn draw_label(pixels: &mut [u8], size: u32, label: &str) {
+    let glyphs: Vec<&[u8; 7]> = label.chars().map(glyph).collect();
+    if glyphs.is_empty() {
+        return;
+    }
+    let scale = (size as usize / (6 * glyphs.len() + 2)).clamp(1, 8) as u32;
+    let width = 6 * scale * glyphs.len() as u32;
+    let left = size.saturating_sub(width) / 2;
+    let top = size.saturating_sub(7 * scale) / 2;
+    for (index, rows) in glyphs.iter().enumerate() {
+        for (row, bits) in rows.iter().enumerate() {
+            for column in 0..5u32 {
+                if bits & (0x10 >> column) == 0 {
+                    continue;
+                }
+                for dy in 0..scale {
+                    for dx in 0..scale {
+                        let x = left + (index as u32 * 6 + column) * scale + dx;
+                        let y = top + row as u32 * scale + dy;
+                        if x < size && y < size {
+                            let offset = ((y * size + x) * 3) as usize;
+                            pixels[offset..offset + 3].fill(0xff);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn mesh(args: &PlaceholderArgs) -> anyhow::Result<PathBuf> {
+    let path = args.out.join(format!("{}.obj", args.name));
+    let mut obj = format!("# {MARKER}{};\no {}\n", args.name, args.name);
+    for z in [-0.5, 0.5] {
+        for y in [-0.5, 0.5] {
+            for x in [-0.5, 0.5] {
+                obj.push_str(&format!("v {x} {y} {z}\n"));
+            }
+        }
+    }
+    for face in [
+        [1, 2, 4, 3],
+        [5, 7, 8, 6],
+        [1, 5, 6, 2],
+        [3, 4, 8, 7],
+        [1, 3, 7, 5],
+        [2, 6, 8, 4],
+    ] {
+        obj.push_str(&format!(
+            "f {} {} {} {}\n",
+            face[0], face[1], face[2], face[3]
+        ));
+    }
+    crate::fs_util::write_file(&path, obj.as_bytes(), false)?;
+    Ok(path)
+}
+
+/// Writes a silent 16-bit mono WAV, with the marker in a trailing custom
+/// chunk RIFF readers skip.
+fn audio(args: &PlaceholderArgs) -> anyhow::Result<PathBuf> {
+    const SAMPLE_RATE: u32 = 22050;
+    let samples = (args.duration.max(0.01) * SAMPLE_RATE as f32) as u32;
+    let data_len = samples * 2;
+    let mut comment = format!("{MARKER}{};", args.name).into_bytes();
+    if comment.len() % 2 == 1 {
+        comment.push(0);
+    }
+    let riff_len = 4 + (8 + 16) + (8 + data_len) + (8 + comment.len() as u32);
+
+    let path = args.out.join(format!("{}.wav", args.name));
+    let mut file = std::fs::File::create(&path)
+        .with_context(|| format!("failed to create {}", path.display()))?;
+    file.write_all(b"RIFF")?;
+    file.write_all(&riff_len.to_le_bytes())?;
+    file.write_all(b"WAVEfmt ")?;
+    file.write_all(&16u32.to_le_bytes())?;
+    file.write_all(&1u16.to_le_bytes())?; // PCM
+    file.write_all(&1u16.to_le_bytes())?; // mono
+    file.write_all(&SAMPLE_RATE.to_le_bytes())?;
+    file.write_all(&(SAMPLE_RATE * 2).to_le_bytes())?;
+    file.write_all(&2u16.to_le_bytes())?;
+    file.write_all(&16u16.to_le_bytes())?;
+    file.write_all(b"data")?;
+    file.write_all(&data_len.to_le_bytes())?;
+    file.write_all(&vec![0u8; data_len as usize])?;
+    file.write_all(b"cmnt")?;
+    file.write_all(&(comment.len() as u32).to_le_bytes())?;
+    file.write_all(&comment)?;
+    Ok(path)
+}
+
+/// 5x7 bitmap font covering `A-Z`, `0-9`, `_`, and `-`; anything else draws
+/// as a filled block.
+fn glyph(c: char) -> &'static [u8; 7] {
+    match c {
+        'A' => &[0x0e, 0x11, 0x11, 0x1f, 0x11, 0x11, 0x11],
+        'B' => &[0x1e, 0x11, 0x11, 0x1e, 0x11, 0x11, 0x1e],
+        'C' => &[0x0e, 0x11, 0x10, 0x10, 0x10, 0x11, 0x0e],
+        'D' => &[0x1e, 0x11, 0x11, 0x11, 0x11, 0x11, 0x1e],
+        'E' => &[0x1f, 0x10, 0x10, 0x1e, 0x10, 0x10, 0x1f],
+        'F' => &[0x1f, 0x10, 0x10, 0x1e, 0x10, 0x10, 0x10],
+        'G' => &[0x0e, 0x11, 0x10, 0x17, 0x11, 0x11, 0x0f],
+        'H' => &[0x11, 0x11, 0x11, 0x1f, 0x11, 0x11, 0x11],
+        'I' => &[0x0e, 0x04, 0x04, 0x04, 0x04, 0x04, 0x0e],
+        'J' => &[0x07, 0x02, 0x02, 0x02, 0x02, 0x12, 0x0c],
+        'K' => &[0x11, 0x12, 0x14, 0x18, 0x14, 0x12, 0x11],
+        'L' => &[0x10, 0x10, 0x10, 0x10, 0x10, 0x10, 0x1f],
+        'M' => &[0x11, 0x1b, 0x15, 0x15, 0x11, 0x11, 0x11],
+        'N' => &[0x11, 0x19, 0x15, 0x13, 0x11, 0x11, 0x11],
+        'O' => &[0x0e, 0x11, 0x11, 0x11, 0x11, 0x11, 0x0e],
+        'P' => &[0x1e, 0x11, 0x11, 0x1e, 0x10, 0x10, 0x10],
+        'Q' => &[0x0e, 0x11, 0x11, 0x11, 0x15, 0x12, 0x0d],
+        'R' => &[0x1e, 0x11, 0x11, 0x1e, 0x14, 0x12, 0x11],
+        'S' => &[0x0f, 0x10, 0x10, 0x0e, 0x01, 0x01, 0x1e],
+        'T' => &[0x1f, 0x04, 0x04, 0x04, 0x04, 0x04, 0x04],
+        'U' => &[0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x0e],
+        'V' => &[0x11, 0x11, 0x11, 0x11, 0x11, 0x0a, 0x04],
+        'W' => &[0x11, 0x11, 0x11, 0x15, 0x15, 0x15, 0x0a],
+        'X' => &[0x11, 0x11, 0x0a, 0x04, 0x0a, 0x11, 0x11],
+        'Y' => &[0x11, 0x11, 0x0a, 0x04, 0x04, 0x04, 0x04],
+        'Z' => &[0x1f, 0x01, 0x02, 0x04, 0x08, 0x10, 0x1f],
+        '0' => &[0x0e, 0x11, 0x13, 0x15, 0x19, 0x11, 0x0e],
+        '1' => &[0x04, 0x0c, 0x04, 0x04, 0x04, 0x04, 0x0e],
+        '2' => &[0x0e, 0x11, 0x01, 0x06, 0x08, 0x10, 0x1f],
+        '3' => &[0x1f, 0x02, 0x04, 0x02, 0x01, 0x11, 0x0e],
+        '4' => &[0x02, 0x06, 0x0a, 0x12, 0x1f, 0x02, 0x02],
+        '5' => &[0x1f, 0x10, 0x1e, 0x01, 0x01, 0x11, 0x0e],
+        '6' => &[0x06, 0x08, 0x10, 0x1e, 0x11, 0x11, 0x0e],
+        '7' => &[0x1f, 0x01, 0x02, 0x04, 0x08, 0x08, 0x08],
+        '8' => &[0x0e, 0x11, 0x11, 0x0e, 0x11, 0x11, 0x0e],
+        '9' => &[0x0e, 0x11, 0x11, 0x0f, 0x01, 0x02, 0x0c],
+        '_' => &[0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1f],
+        '-' => &[0x00, 0x00, 0x00, 0x1f, 0x00, 0x00, 0x00],
+        _ => &[0x1f, 0x1f, 0x1f, 0x1f, 0x1f, 0x1f, 0x1f],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(kind: PlaceholderKind, name: &str, out: &Path) -> PlaceholderArgs {
+        PlaceholderArgs {
+            kind,
+            name: name.to_string(),
+            out: out.to_path_buf(),
+            size: 64,
+            duration: 0.1,
+        }
+    }
+
+    #[test]
+    fn placeholders_carry_a_findable_marker() {
+        let dir = std::env::temp_dir().join("bevy_cli_placeholder_test");
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let png = texture(&args(PlaceholderKind::Texture, "hero", &dir)).unwrap();
+        let obj = mesh(&args(PlaceholderKind::Mesh, "crate_box", &dir)).unwrap();
+        let wav = audio(&args(PlaceholderKind::Audio, "explosion", &dir)).unwrap();
+
+        assert_eq!(marker_in(&png).unwrap().as_deref(), Some("hero"));
+        assert_eq!(marker_in(&obj).unwrap().as_deref(), Some("crate_box"));
+        assert_eq!(marker_in(&wav).unwrap().as_deref(), Some("explosion"));
+    }
+
+    #[test]
+    fn silent_audio_matches_the_requested_duration() {
+        let dir = std::env::temp_dir().join("bevy_cli_placeholder_wav_test");
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        let mut wav_args = args(PlaceholderKind::Audio, "beep", &dir);
+        wav_args.duration = 0.5;
+        let wav = audio(&wav_args).unwrap();
+        let bytes = std::fs::read(wav).unwrap();
+        assert_eq!(&bytes[..4], b"RIFF");
+        let data_len = u32::from_le_bytes(bytes[40..44].try_into().unwrap());
+        assert_eq!(data_len, (0.5 * 22050.0) as u32 * 2);
+    }
+}
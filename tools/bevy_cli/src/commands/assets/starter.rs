@@ -0,0 +1,170 @@
+//! `bevy assets starter`: curated starter packs with honest attribution.
+//!
+//! The packs live in a dedicated repository — CC0 and CC-BY sprites, fonts
+//! and sounds vetted for prototyping — cloned and cached like any other
+//! registry. Installing a pack copies it into `assets/` and records its
+//! license notice in `assets/ATTRIBUTION.md`, assembled from the pack
+//! metadata, so CC-BY credit lines ship with the game instead of being
+//! rediscovered at release time.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+use clap::Args;
+use serde::Deserialize;
+
+use crate::i18n::localize;
+use crate::{fs_util, output, registry, vcs};
+
+#[derive(Args)]
+pub struct StarterArgs {
+    /// Pack to install; omitted lists the available packs
+    pub name: Option<String>,
+
+    /// Project directory; defaults to the nearest project root
+    #[arg(long)]
+    pub project: Option<PathBuf>,
+}
+
+/// Repository hosting the curated packs and their `packs.toml` index.
+const STARTER_REPO: &str = "https://github.com/bevyengine/bevy_cli_starter_assets";
+
+/// The attribution file maintained inside the assets directory.
+const ATTRIBUTION_FILE: &str = "ATTRIBUTION.md";
+
+/// The parsed `packs.toml` index of the starter repository.
+#[derive(Debug, Default, Deserialize)]
+struct StarterIndex {
+    #[serde(default)]
+    packs: Vec<StarterPack>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct StarterPack {
+    name: String,
+    #[serde(default)]
+    description: Option<String>,
+    /// SPDX identifier, e.g. `CC0-1.0` or `CC-BY-4.0`.
+    license: String,
+    author: String,
+    /// Where the pack originally came from.
+    source: String,
+    /// Pack directory, relative to the repository root.
+    path: String,
+}
+
+pub fn run(args: StarterArgs) -> anyhow::Result<()> {
+    let checkout = registry::cache_dir()?.join("starter-assets");
+    vcs::ensure_checkout(&vcs::ShellGit::default(), STARTER_REPO, None, &checkout)
+        .context("failed to fetch the starter asset packs")?;
+    let index: StarterIndex =
+        toml::from_str(&std::fs::read_to_string(checkout.join("packs.toml"))?)
+            .context("failed to parse the starter pack index")?;
+
+    let Some(name) = &args.name else {
+        for pack in &index.packs {
+            println!(
+                "{}",
+                localize!(
+                    "starter-pack",
+                    name = pack.name,
+                    license = pack.license,
+                    description = pack.description.as_deref().unwrap_or("-")
+                )
+            );
+        }
+        return Ok(());
+    };
+
+    let project = args
+        .project
+        .clone()
+        .unwrap_or_else(|| crate::project::locate(Path::new(".")));
+    anyhow::ensure!(
+        project.join("Cargo.toml").is_file(),
+        "{} does not contain a Cargo.toml",
+        project.display()
+    );
+    let pack = index
+        .packs
+        .iter()
+        .find(|pack| &pack.name == name)
+        .with_context(|| format!("no starter pack named `{name}`"))?;
+    let assets = project.join("assets");
+    let dest = assets.join(&pack.name);
+    anyhow::ensure!(
+        !dest.exists(),
+        "{} already exists; remove it to reinstall",
+        dest.display()
+    );
+    fs_util::copy_dir(&checkout.join(&pack.path), &dest)?;
+    record_attribution(&assets, pack)?;
+    output::ok(&localize!(
+        "starter-installed",
+        name = pack.name,
+        license = pack.license
+    ));
+    Ok(())
+}
+
+/// Appends the pack's notice to `assets/ATTRIBUTION.md`, creating the file
+/// on first use; a pack already listed is not listed twice.
+fn record_attribution(assets: &Path, pack: &StarterPack) -> anyhow::Result<()> {
+    let path = assets.join(ATTRIBUTION_FILE);
+    let mut contents = match std::fs::read_to_string(&path) {
+        Ok(contents) => contents,
+        Err(_) => "# Asset attribution\n".to_string(),
+    };
+    let section = attribution_section(pack);
+    if contents.contains(&format!("## {}", pack.name)) {
+        return Ok(());
+    }
+    contents.push('\n');
+    contents.push_str(&section);
+    fs_util::write_file(&path, contents.as_bytes(), false)?;
+    Ok(())
+}
+
+/// The markdown notice for one pack: what, by whom, under which license.
+fn attribution_section(pack: &StarterPack) -> String {
+    format!(
+        "## {}\n\n- Author: {}\n- License: {}\n- Source: {}\n",
+        pack.name, pack.author, pack.license, pack.source
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pack() -> StarterPack {
+        StarterPack {
+            name: "pixel-ui".to_string(),
+            description: None,
+            license: "CC-BY-4.0".to_string(),
+            author: "Kenney".to_string(),
+            source: "https://kenney.nl/assets/ui-pack".to_string(),
+            path: "packs/pixel-ui".to_string(),
+        }
+    }
+
+    #[test]
+    fn attribution_sections_carry_author_license_and_source() {
+        let section = attribution_section(&pack());
+        assert!(section.starts_with("## pixel-ui"));
+        assert!(section.contains("- License: CC-BY-4.0"));
+        assert!(section.contains("https://kenney.nl/assets/ui-pack"));
+    }
+
+    #[test]
+    fn attribution_is_recorded_once_per_pack() {
+        let assets = std::env::temp_dir().join(format!("bevy_cli_attr_{}", std::process::id()));
+        std::fs::create_dir_all(&assets).unwrap();
+        record_attribution(&assets, &pack()).unwrap();
+        record_attribution(&assets, &pack()).unwrap();
+        let written = std::fs::read_to_string(assets.join(ATTRIBUTION_FILE)).unwrap();
+        assert_eq!(written.matches("## pixel-ui").count(), 1);
+        assert!(written.starts_with("# Asset attribution"));
+        std::fs::remove_dir_all(&assets).unwrap();
+    }
+}
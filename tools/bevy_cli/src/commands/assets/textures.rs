@@ -0,0 +1,254 @@
+//! `bevy assets compress-textures`: GPU-ready copies of source textures.
+//!
+//! Converts the PNG and JPG sources under `assets/` to KTX2 through an
+//! installed encoder — `toktx` from KTX-Software, falling back to `basisu`
+//! — into a parallel output tree, and keeps a mapping file with content
+//! hashes so unchanged sources are skipped and builds can ship the
+//! compressed tree while the originals stay in the repository.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+use clap::Args;
+use serde::{Deserialize, Serialize};
+
+use crate::i18n::localize;
+use crate::{fs_util, output};
+
+#[derive(Args)]
+pub struct CompressTexturesArgs {
+    /// Project directory; defaults to the nearest project root
+    #[arg(long)]
+    pub project: Option<PathBuf>,
+
+    /// Output tree for the compressed textures
+    #[arg(long, default_value = "assets-compressed")]
+    pub out_dir: PathBuf,
+
+    /// Re-encode everything, ignoring the mapping file
+    #[arg(long)]
+    pub force: bool,
+}
+
+/// Where the source-to-compressed mapping is kept.
+const MAP_FILE: &str = ".bevy/texture-map.json";
+
+/// Source extensions worth compressing.
+const TEXTURE_EXTENSIONS: &[&str] = &["png", "jpg", "jpeg"];
+
+/// The `[assets.textures]` section of `Bevy.toml`.
+#[derive(Debug, Default, Deserialize)]
+struct TexturesSection {
+    /// UASTC keeps quality for normal maps and UI at larger sizes; ETC1S
+    /// (the default) is smaller and fine for most color textures.
+    #[serde(default)]
+    uastc: bool,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct AssetsSection {
+    #[serde(default)]
+    textures: TexturesSection,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct ProjectConfig {
+    #[serde(default)]
+    assets: AssetsSection,
+}
+
+/// One line of the mapping file: where a source ended up, and the content
+/// hash it had when encoded.
+#[derive(Debug, Serialize, Deserialize)]
+struct MapEntry {
+    output: String,
+    hash: u64,
+}
+
+pub fn run(args: CompressTexturesArgs) -> anyhow::Result<()> {
+    let project = args
+        .project
+        .clone()
+        .unwrap_or_else(|| crate::project::locate(Path::new(".")));
+    let assets = project.join("assets");
+    anyhow::ensure!(
+        assets.is_dir(),
+        "{} has no assets directory",
+        project.display()
+    );
+    let config: ProjectConfig = load_config(&project)?;
+    let encoder = find_encoder()
+        .context("no KTX2 encoder found; install `toktx` (KTX-Software) or `basisu`")?;
+
+    let map_path = project.join(MAP_FILE);
+    let mut map: BTreeMap<String, MapEntry> = std::fs::read_to_string(&map_path)
+        .ok()
+        .and_then(|contents| serde_json::from_str(&contents).ok())
+        .unwrap_or_default();
+
+    let mut encoded = 0usize;
+    for source in texture_sources(&assets) {
+        let relative = source
+            .strip_prefix(&assets)
+            .expect("sources come from the assets walk")
+            .to_path_buf();
+        let key = relative.to_string_lossy().into_owned();
+        let hash = content_hash(&std::fs::read(&source)?);
+        let out = project.join(&args.out_dir).join(output_name(&relative));
+        let unchanged = map.get(&key).is_some_and(|entry| entry.hash == hash) && out.is_file();
+        if unchanged && !args.force {
+            continue;
+        }
+        if let Some(parent) = out.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        crate::subprocess::Subprocess::new(encoder)
+            .args(encoder_args(
+                encoder,
+                &source,
+                &out,
+                config.assets.textures.uastc,
+            ))
+            .run()
+            .with_context(|| format!("while compressing {}", relative.display()))?;
+        map.insert(
+            key,
+            MapEntry {
+                output: out
+                    .strip_prefix(&project)
+                    .unwrap_or(&out)
+                    .to_string_lossy()
+                    .into_owned(),
+                hash,
+            },
+        );
+        encoded += 1;
+    }
+
+    if let Some(parent) = map_path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    fs_util::write_file(
+        &map_path,
+        serde_json::to_string_pretty(&map)?.as_bytes(),
+        false,
+    )?;
+    output::ok(&localize!("textures-compressed", count = encoded));
+    Ok(())
+}
+
+/// The first installed encoder, in order of preference.
+fn find_encoder() -> Option<&'static str> {
+    ["toktx", "basisu"]
+        .into_iter()
+        .find(|encoder| super::super::doctor::on_path(encoder))
+}
+
+/// The argument vector for one encode; separated for testing. Both
+/// encoders produce Basis-compressed KTX2, which Bevy loads with the
+/// `ktx2` + `zstd` features.
+fn encoder_args(encoder: &str, source: &Path, out: &Path, uastc: bool) -> Vec<String> {
+    match encoder {
+        "toktx" => {
+            let mut args = vec!["--t2".to_string(), "--encode".to_string()];
+            args.push(if uastc { "uastc" } else { "etc1s" }.to_string());
+            args.push(out.to_string_lossy().into_owned());
+            args.push(source.to_string_lossy().into_owned());
+            args
+        }
+        _ => {
+            let mut args = vec!["-ktx2".to_string()];
+            if uastc {
+                args.push("-uastc".to_string());
+            }
+            args.push(source.to_string_lossy().into_owned());
+            args.push("-output_file".to_string());
+            args.push(out.to_string_lossy().into_owned());
+            args
+        }
+    }
+}
+
+/// `sprites/hero.png` compresses to `sprites/hero.ktx2`.
+fn output_name(relative: &Path) -> PathBuf {
+    relative.with_extension("ktx2")
+}
+
+fn texture_sources(assets: &Path) -> Vec<PathBuf> {
+    let mut sources = Vec::new();
+    let mut stack = vec![assets.to_path_buf()];
+    while let Some(path) = stack.pop() {
+        if path.is_dir() {
+            if let Ok(entries) = std::fs::read_dir(&path) {
+                stack.extend(entries.flatten().map(|entry| entry.path()));
+            }
+            continue;
+        }
+        let compressible = path
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .is_some_and(|extension| {
+                TEXTURE_EXTENSIONS.contains(&extension.to_lowercase().as_str())
+            });
+        if compressible {
+            sources.push(path);
+        }
+    }
+    sources.sort();
+    sources
+}
+
+fn content_hash(bytes: &[u8]) -> u64 {
+    use std::hash::{Hash, Hasher};
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    bytes.hash(&mut hasher);
+    hasher.finish()
+}
+
+fn load_config(project: &Path) -> anyhow::Result<ProjectConfig> {
+    let manifest_path = project.join(crate::project::MANIFEST);
+    if !manifest_path.is_file() {
+        return Ok(ProjectConfig::default());
+    }
+    toml::from_str(&std::fs::read_to_string(&manifest_path)?)
+        .with_context(|| format!("failed to parse {}", manifest_path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encoder_invocations_match_each_tool() {
+        let args = encoder_args(
+            "toktx",
+            Path::new("a/hero.png"),
+            Path::new("out/hero.ktx2"),
+            true,
+        );
+        assert_eq!(
+            args,
+            vec!["--t2", "--encode", "uastc", "out/hero.ktx2", "a/hero.png"]
+        );
+        let args = encoder_args(
+            "basisu",
+            Path::new("a/hero.png"),
+            Path::new("out/hero.ktx2"),
+            false,
+        );
+        assert_eq!(
+            args,
+            vec!["-ktx2", "a/hero.png", "-output_file", "out/hero.ktx2"]
+        );
+    }
+
+    #[test]
+    fn outputs_mirror_the_source_tree_with_ktx2_extensions() {
+        assert_eq!(
+            output_name(Path::new("sprites/hero.png")),
+            Path::new("sprites/hero.ktx2")
+        );
+        assert_ne!(content_hash(b"one"), content_hash(b"two"));
+    }
+}
@@ -0,0 +1,417 @@
+//! Asset validation: content-hash duplicate detection across `assets/`,
+//! orphan detection against the paths the project's sources actually
+//! reference, and the format-level checks in [`super::formats`].
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+use clap::Args;
+
+use crate::i18n::localize;
+use crate::output;
+
+#[derive(Args)]
+pub struct ValidateArgs {
+    /// Project directory; defaults to the current directory
+    #[arg(long)]
+    pub project: Option<PathBuf>,
+
+    /// Assets directory, relative to the project
+    #[arg(long, default_value = "assets")]
+    pub assets: PathBuf,
+
+    /// Delete the unreferenced files instead of only reporting them
+    #[arg(long)]
+    pub delete_orphans: bool,
+
+    /// Move the unreferenced files into `assets-quarantine/` instead;
+    /// reversible, unlike --delete-orphans
+    #[arg(long, conflicts_with = "delete_orphans")]
+    pub quarantine: bool,
+}
+
+/// Where quarantined orphans go, relative to the project; the tree below
+/// mirrors `assets/` so moving a file back is a plain rename.
+const QUARANTINE_DIR: &str = "assets-quarantine";
+
+/// Extensions treated as text when scanning for asset references.
+const REFERENCE_SOURCES: &[&str] = &["rs", "ron", "toml", "json", "tera", "md"];
+
+pub fn run(args: ValidateArgs) -> anyhow::Result<()> {
+    let project = args
+        .project
+        .unwrap_or_else(|| crate::project::locate(Path::new(".")));
+    let assets = project.join(&args.assets);
+    anyhow::ensure!(assets.is_dir(), "{} is not a directory", assets.display());
+
+    let files = collect_files(&assets)?;
+
+    let naming = naming_rules(&project)?;
+    let mut naming_errors = 0usize;
+    if let Some(rules) = &naming {
+        for (file, message, suggestion) in naming_violations(&files, &assets, rules) {
+            // One line per violation, stable shape, so CI logs diff cleanly.
+            let mut line = format!("naming: {}: {message}", file.display());
+            if let Some(suggestion) = suggestion {
+                line.push_str(&format!(" (try `{suggestion}`)"));
+            }
+            output::warn(&line);
+            if rules.severity == super::Severity::Error {
+                naming_errors += 1;
+            }
+        }
+    }
+
+    let duplicates = find_duplicates(&files)?;
+    for group in &duplicates {
+        output::warn(&format!(
+            "identical content: {}",
+            group
+                .iter()
+                .map(|path| path.display().to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        ));
+    }
+
+    let mut format_errors = 0usize;
+    for file in &files {
+        for problem in super::formats::check(file) {
+            output::warn(&format!(
+                "format: {}: {}",
+                file.display(),
+                super::formats::describe(&problem)
+            ));
+            if problem.is_error() {
+                format_errors += 1;
+            }
+        }
+    }
+
+    let haystack = reference_haystack(&project, &assets)?;
+    let mut orphans: Vec<(PathBuf, u64)> = Vec::new();
+    for file in &files {
+        let rel = file.strip_prefix(&assets).unwrap_or(file);
+        // Matching on the path relative to `assets/` (with `/` separators,
+        // as asset paths are written in code) keeps dynamic directories
+        // honest; a bare file-name match would accept stale copies anywhere.
+        let needle = rel.to_string_lossy().replace('\\', "/");
+        if !haystack.contains(&needle) {
+            orphans.push((file.clone(), file.metadata()?.len()));
+        }
+    }
+
+    // The reverse direction: asset paths the sources mention that point at
+    // nothing on disk fail at runtime with a load error.
+    let mut broken = 0usize;
+    for needle in referenced_paths(&haystack) {
+        if !assets.join(&needle).is_file() {
+            output::warn(&format!("broken reference: `{needle}` does not exist"));
+            broken += 1;
+        }
+    }
+
+    let reclaimable: u64 = orphans.iter().map(|(_, size)| size).sum();
+    for (orphan, size) in &orphans {
+        if args.delete_orphans {
+            std::fs::remove_file(orphan)?;
+            println!(
+                "{}",
+                localize!("validate-orphan-deleted", file = orphan.display())
+            );
+        } else if args.quarantine {
+            let rel = orphan.strip_prefix(&assets).unwrap_or(orphan);
+            let dest = project.join(QUARANTINE_DIR).join(rel);
+            if let Some(parent) = dest.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            std::fs::rename(orphan, &dest)?;
+            println!(
+                "{}",
+                localize!(
+                    "validate-orphan-quarantined",
+                    file = orphan.display(),
+                    dest = dest.display()
+                )
+            );
+        } else {
+            output::warn(&format!(
+                "unreferenced: {} ({size} bytes)",
+                orphan.display()
+            ));
+        }
+    }
+
+    let handled = args.delete_orphans || args.quarantine;
+    if duplicates.is_empty()
+        && orphans.is_empty()
+        && naming_errors == 0
+        && format_errors == 0
+        && broken == 0
+    {
+        output::ok(&localize!("validate-clean", count = files.len()));
+        return Ok(());
+    }
+    if handled && duplicates.is_empty() && naming_errors == 0 && format_errors == 0 && broken == 0 {
+        output::ok(&localize!(
+            "validate-orphans-deleted",
+            count = orphans.len()
+        ));
+        return Ok(());
+    }
+    anyhow::bail!(localize!(
+        "validate-problems",
+        count = duplicates.len() + orphans.len() + naming_errors + format_errors + broken,
+        duplicates = duplicates.len(),
+        orphans = orphans.len(),
+        size = reclaimable
+    ));
+}
+
+/// Asset-path-looking string literals in the reference haystack: quoted,
+/// relative, and ending in an extension Bevy loads. The character class
+/// excludes `:` so URLs never match.
+fn referenced_paths(haystack: &str) -> std::collections::BTreeSet<String> {
+    let literal = regex::Regex::new(
+        r#""([A-Za-z0-9_\-./ ]+\.(?:png|jpe?g|ktx2|basis|ogg|wav|flac|mp3|gltf|glb|scn|ttf|otf|wgsl))(?:#[^"]*)?""#,
+    )
+    .expect("reference pattern compiles");
+    literal
+        .captures_iter(haystack)
+        .map(|captures| captures[1].to_string())
+        .filter(|path| !path.starts_with('/') && !path.contains(".."))
+        .collect()
+}
+
+/// Reads the `[assets.naming]` rules from `Bevy.toml`, when both exist.
+fn naming_rules(project: &Path) -> anyhow::Result<Option<super::NamingRules>> {
+    let manifest = project.join(crate::project::MANIFEST);
+    let Ok(contents) = std::fs::read_to_string(&manifest) else {
+        return Ok(None);
+    };
+    let config: super::ProjectConfig = toml::from_str(&contents)
+        .with_context(|| format!("failed to parse {}", manifest.display()))?;
+    Ok(config.assets.naming)
+}
+
+/// Checks every asset against the naming rules; each violation carries an
+/// autofix suggestion where one can be derived.
+fn naming_violations(
+    files: &[PathBuf],
+    assets: &Path,
+    rules: &super::NamingRules,
+) -> Vec<(PathBuf, String, Option<String>)> {
+    let mut violations = Vec::new();
+    for file in files {
+        let rel = file.strip_prefix(assets).unwrap_or(file);
+        let stem = file
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().into_owned())
+            .unwrap_or_default();
+
+        let styled = match rules.style.as_str() {
+            "snake_case" => restyle(&stem, '_'),
+            "kebab-case" => restyle(&stem, '-'),
+            _ => stem.clone(),
+        };
+        if styled != stem {
+            violations.push((
+                file.clone(),
+                format!("`{stem}` is not {}", rules.style),
+                Some(styled.clone()),
+            ));
+        }
+
+        if let Some(folder) = rel.components().next() {
+            let folder = folder.as_os_str().to_string_lossy();
+            if let Some(prefix) = rules.prefixes.get(folder.as_ref()) {
+                if !stem.starts_with(prefix.as_str()) {
+                    violations.push((
+                        file.clone(),
+                        format!("files under `{folder}/` must start with `{prefix}`"),
+                        Some(format!("{prefix}{styled}")),
+                    ));
+                }
+            }
+        }
+
+        if let Some(max) = rules.max_path_length {
+            let length = rel.to_string_lossy().len();
+            if length > max {
+                violations.push((
+                    file.clone(),
+                    format!("path is {length} characters, over the {max} limit"),
+                    None,
+                ));
+            }
+        }
+    }
+    violations
+}
+
+/// Lowercases and rewrites separators to `separator`, the shared part of
+/// snake_case and kebab-case.
+fn restyle(stem: &str, separator: char) -> String {
+    let mut restyled = String::new();
+    for c in stem.chars() {
+        if c.is_ascii_uppercase() {
+            if !restyled.is_empty() && !restyled.ends_with(separator) {
+                restyled.push(separator);
+            }
+            restyled.push(c.to_ascii_lowercase());
+        } else if c == '-' || c == '_' || c == ' ' {
+            if !restyled.ends_with(separator) {
+                restyled.push(separator);
+            }
+        } else {
+            restyled.push(c);
+        }
+    }
+    restyled.trim_matches(separator).to_string()
+}
+
+fn collect_files(root: &Path) -> anyhow::Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    let mut stack = vec![root.to_path_buf()];
+    while let Some(dir) = stack.pop() {
+        let mut entries: Vec<_> = std::fs::read_dir(&dir)?.flatten().collect();
+        entries.sort_by_key(|entry| entry.path());
+        for entry in entries {
+            let path = entry.path();
+            if path.is_dir() {
+                stack.push(path);
+            } else {
+                files.push(path);
+            }
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+/// Groups files with identical content, keyed by size and content hash so
+/// only same-size files are ever compared.
+fn find_duplicates(files: &[PathBuf]) -> anyhow::Result<Vec<Vec<PathBuf>>> {
+    use std::hash::{Hash, Hasher};
+    let mut groups: BTreeMap<(u64, u64), Vec<PathBuf>> = BTreeMap::new();
+    for file in files {
+        let bytes = std::fs::read(file)?;
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        bytes.hash(&mut hasher);
+        groups
+            .entry((bytes.len() as u64, hasher.finish()))
+            .or_default()
+            .push(file.clone());
+    }
+    Ok(groups
+        .into_values()
+        .filter(|group| group.len() > 1)
+        .collect())
+}
+
+/// Concatenates every text file that may reference assets: the project's
+/// Rust sources plus text assets (RON descriptors reference other assets).
+fn reference_haystack(project: &Path, assets: &Path) -> anyhow::Result<String> {
+    let mut haystack = String::new();
+    let mut roots = vec![project.join("src"), assets.to_path_buf()];
+    let crates = project.join("crates");
+    if crates.is_dir() {
+        roots.push(crates);
+    }
+    let mut stack: Vec<PathBuf> = roots.into_iter().filter(|root| root.is_dir()).collect();
+    while let Some(dir) = stack.pop() {
+        for entry in std::fs::read_dir(&dir)?.flatten() {
+            let path = entry.path();
+            if path.is_dir() {
+                stack.push(path);
+            } else if path
+                .extension()
+                .and_then(|extension| extension.to_str())
+                .is_some_and(|extension| REFERENCE_SOURCES.contains(&extension))
+            {
+                if let Ok(contents) = std::fs::read_to_string(&path) {
+                    haystack.push_str(&contents);
+                    haystack.push('\n');
+                }
+            }
+        }
+    }
+    Ok(haystack)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn duplicates_are_grouped_by_content() {
+        let dir = std::env::temp_dir().join("bevy_cli_validate_test");
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(dir.join("a")).unwrap();
+        std::fs::write(dir.join("a/one.png"), b"same").unwrap();
+        std::fs::write(dir.join("two.png"), b"same").unwrap();
+        std::fs::write(dir.join("three.png"), b"different").unwrap();
+
+        let files = collect_files(&dir).unwrap();
+        let duplicates = find_duplicates(&files).unwrap();
+        assert_eq!(duplicates.len(), 1);
+        assert_eq!(duplicates[0].len(), 2);
+    }
+
+    #[test]
+    fn naming_rules_flag_style_prefix_and_length() {
+        let rules = super::super::NamingRules {
+            style: "snake_case".to_string(),
+            max_path_length: Some(20),
+            severity: super::super::Severity::Error,
+            prefixes: [("sprites".to_string(), "spr_".to_string())].into(),
+        };
+        let assets = Path::new("assets");
+        let files = vec![
+            PathBuf::from("assets/sprites/spr_hero.png"),
+            PathBuf::from("assets/sprites/HeroIdle.png"),
+            PathBuf::from("assets/sounds/a_very_long_file_name.wav"),
+        ];
+        let violations = naming_violations(&files, assets, &rules);
+        assert_eq!(violations.len(), 3);
+        assert_eq!(violations[0].2.as_deref(), Some("hero_idle"));
+        assert_eq!(violations[1].2.as_deref(), Some("spr_hero_idle"));
+        assert!(violations[2].1.contains("over the 20 limit"));
+    }
+
+    #[test]
+    fn referenced_paths_match_asset_literals_only() {
+        let haystack = r#"
+            load("sprites/hero.png");
+            load("models/ship.gltf#Scene0");
+            fetch("https://example.com/remote.png");
+            load("../outside.png");
+        "#;
+        let paths = referenced_paths(haystack);
+        assert!(paths.contains("sprites/hero.png"));
+        assert!(paths.contains("models/ship.gltf"));
+        assert!(!paths.iter().any(|path| path.contains("example.com")));
+        assert!(!paths.iter().any(|path| path.contains("..")));
+    }
+
+    #[test]
+    fn orphans_are_files_no_source_references() {
+        let dir = std::env::temp_dir().join("bevy_cli_orphan_test");
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(dir.join("assets/sprites")).unwrap();
+        std::fs::create_dir_all(dir.join("src")).unwrap();
+        std::fs::write(dir.join("assets/sprites/hero.png"), b"used").unwrap();
+        std::fs::write(dir.join("assets/sprites/old.png"), b"unused").unwrap();
+        std::fs::write(
+            dir.join("src/main.rs"),
+            "fn main() { load(\"sprites/hero.png\"); }",
+        )
+        .unwrap();
+
+        let assets = dir.join("assets");
+        let haystack = reference_haystack(&dir, &assets).unwrap();
+        assert!(haystack.contains("sprites/hero.png"));
+        assert!(!haystack.contains("old.png"));
+    }
+}
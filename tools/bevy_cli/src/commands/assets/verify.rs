@@ -0,0 +1,119 @@
+//! Bundle integrity checking: re-hashes a built bundle's assets against the
+//! `manifest.ron` that `bevy bundle` wrote and reports anything modified,
+//! missing, or smuggled in after the fact.
+
+use std::collections::BTreeSet;
+use std::path::PathBuf;
+
+use anyhow::Context;
+use clap::Args;
+
+use crate::i18n::localize;
+use crate::{fs_util, output};
+
+use super::manifest;
+
+#[derive(Args)]
+pub struct VerifyArgs {
+    /// The bundle to check: a directory containing `assets/manifest.ron`
+    /// (e.g. `dist/default/my_game`), or the assets directory itself
+    #[arg(default_value = ".")]
+    pub bundle: PathBuf,
+
+    /// Instead of verifying, write the runtime verification module (e.g.
+    /// `src/asset_verify.rs`) so the shipped game can run the same check
+    #[arg(long, value_name = "FILE", conflicts_with = "bundle")]
+    pub emit_module: Option<PathBuf>,
+}
+
+pub fn run(args: VerifyArgs) -> anyhow::Result<()> {
+    if let Some(module) = &args.emit_module {
+        if let Some(parent) = module.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        fs_util::write_file(
+            module,
+            include_str!("../../../templates/scaffold/asset_verify.rs").as_bytes(),
+            false,
+        )?;
+        output::ok(&localize!("verify-module-written", file = module.display()));
+        return Ok(());
+    }
+
+    // Accept either the bundle root or the assets directory directly.
+    let assets = if args
+        .bundle
+        .join("assets")
+        .join(manifest::MANIFEST_FILE)
+        .is_file()
+    {
+        args.bundle.join("assets")
+    } else {
+        args.bundle.clone()
+    };
+    let manifest_path = assets.join(manifest::MANIFEST_FILE);
+    let contents = std::fs::read_to_string(&manifest_path).with_context(|| {
+        format!(
+            "failed to read {}; was the bundle built with `bevy bundle`?",
+            manifest_path.display()
+        )
+    })?;
+    let entries = manifest::parse(&contents)
+        .with_context(|| format!("failed to parse {}", manifest_path.display()))?;
+
+    let mut modified = 0usize;
+    let mut missing = 0usize;
+    let mut listed = BTreeSet::new();
+    for entry in &entries {
+        listed.insert(entry.path.clone());
+        let path = assets.join(&entry.path);
+        let actual = match std::fs::read(&path) {
+            Ok(actual) => actual,
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => {
+                output::warn(&localize!("verify-missing", file = entry.path));
+                missing += 1;
+                continue;
+            }
+            Err(error) => {
+                return Err(error).with_context(|| format!("failed to read {}", path.display()))
+            }
+        };
+        if actual.len() as u64 != entry.size || fs_util::fnv1a64(&actual) != entry.hash {
+            output::warn(&localize!("verify-modified", file = entry.path));
+            modified += 1;
+        }
+    }
+
+    // Files in the bundle that the manifest never listed are just as
+    // suspicious as modified ones.
+    let mut extra = 0usize;
+    for path in manifest::sorted_files(&assets)? {
+        if path == manifest_path {
+            continue;
+        }
+        let rel = path
+            .strip_prefix(&assets)?
+            .components()
+            .map(|component| component.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/");
+        if !listed.contains(&rel) {
+            output::warn(&localize!("verify-extra", file = rel));
+            extra += 1;
+        }
+    }
+
+    let problems = modified + missing + extra;
+    if problems == 0 {
+        output::ok(&localize!("verify-ok", count = entries.len()));
+        Ok(())
+    } else {
+        anyhow::bail!(localize!(
+            "verify-problems",
+            count = problems,
+            modified = modified,
+            missing = missing,
+            extra = extra
+        ))
+    }
+}
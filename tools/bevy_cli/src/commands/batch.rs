@@ -0,0 +1,190 @@
+use std::path::PathBuf;
+
+use anyhow::Context;
+use clap::{Args, Subcommand};
+use serde::Deserialize;
+
+use crate::commands::{install, new};
+use crate::i18n::localize;
+use crate::output;
+
+#[derive(Args)]
+pub struct BatchArgs {
+    #[command(subcommand)]
+    pub command: BatchCommand,
+}
+
+#[derive(Subcommand)]
+pub enum BatchCommand {
+    /// Execute a declarative plan file
+    Run {
+        /// Path to the plan, e.g. `plan.toml`
+        plan: PathBuf,
+    },
+}
+
+/// A declarative batch plan: templates to install, then projects to create.
+///
+/// ```toml
+/// [[install]]
+/// template = "pong"
+///
+/// [[project]]
+/// name = "game_a"
+/// template = "pong"
+/// vars = ["license=MIT"]
+/// with_examples = true
+/// ```
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct BatchPlan {
+    #[serde(default)]
+    pub install: Vec<InstallStep>,
+    #[serde(default)]
+    pub project: Vec<ProjectStep>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct InstallStep {
+    pub template: String,
+}
+
+/// One project to create; fields mirror the options of `bevy new`.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ProjectStep {
+    pub name: String,
+    #[serde(default)]
+    pub template: Option<PathBuf>,
+    #[serde(default)]
+    pub workspace: bool,
+    #[serde(default)]
+    pub kind: Option<new::ProjectKind>,
+    #[serde(default)]
+    pub vars: Vec<String>,
+    #[serde(default)]
+    pub with_examples: bool,
+    #[serde(default)]
+    pub with_benches: bool,
+    #[serde(default)]
+    pub bevy_version: Option<String>,
+    #[serde(default)]
+    pub bevy_features: Vec<String>,
+    #[serde(default)]
+    pub author: Option<String>,
+    #[serde(default)]
+    pub email: Option<String>,
+    #[serde(default)]
+    pub toolchain: Option<String>,
+    #[serde(default)]
+    pub fast_compiles: bool,
+    #[serde(default)]
+    pub layout: Option<crate::scaffold::Layout>,
+}
+
+impl ProjectStep {
+    fn to_new_args(&self) -> new::NewArgs {
+        new::NewArgs {
+            name: Some(self.name.clone()),
+            interactive: false,
+            replay: None,
+            i_know_what_im_doing: false,
+            template: self.template.clone(),
+            workspace: self.workspace,
+            kind: self.kind.unwrap_or(new::ProjectKind::Game),
+            vars: self.vars.clone(),
+            with_states: false,
+            with_assets: false,
+            with_examples: self.with_examples,
+            with_benches: self.with_benches,
+            bevy_version: self
+                .bevy_version
+                .clone()
+                .unwrap_or_else(|| "latest".to_string()),
+            bevy_features: self.bevy_features.clone(),
+            author: self.author.clone(),
+            email: self.email.clone(),
+            target_dir: None,
+            toolchain: self.toolchain.clone(),
+            fast_compiles: self.fast_compiles,
+            edition: "2021".to_string(),
+            msrv: None,
+            ci: false,
+            ci_provider: crate::scaffold::ContinuousIntegration::default(),
+            ci_options: crate::scaffold::CiOptions::default(),
+            vcs: new::Vcs::None,
+            xtask: false,
+            bins: Vec::new(),
+            target: None,
+            layout: self.layout.unwrap_or_default(),
+            with_editor_config: false,
+            gitignore: Vec::new(),
+            builtin_only: false,
+            extra_context: Vec::new(),
+        }
+    }
+}
+
+pub fn run(args: BatchArgs) -> anyhow::Result<()> {
+    match args.command {
+        BatchCommand::Run { plan } => run_plan(&plan),
+    }
+}
+
+/// Executes a plan. Installs come first so projects can reference the
+/// templates they bring in. Project creation is transactional: if any step
+/// fails, every project directory created by this run is removed again
+/// before the error is reported.
+fn run_plan(plan_path: &PathBuf) -> anyhow::Result<()> {
+    let contents = std::fs::read_to_string(plan_path)
+        .with_context(|| format!("failed to read {}", plan_path.display()))?;
+    let plan: BatchPlan = toml::from_str(&contents)
+        .with_context(|| format!("failed to parse {}", plan_path.display()))?;
+
+    let total = plan.install.len() + plan.project.len();
+    anyhow::ensure!(total > 0, "{} contains no steps", plan_path.display());
+    let mut done = 0usize;
+
+    for step in &plan.install {
+        output::progress(
+            (done * 100 / total) as u8,
+            &format!("install {}", step.template),
+        );
+        install::run(install::InstallArgs {
+            name: step.template.clone(),
+        })
+        .with_context(|| format!("install step `{}` failed", step.template))?;
+        done += 1;
+    }
+
+    let mut created: Vec<PathBuf> = Vec::new();
+    for step in &plan.project {
+        output::progress(
+            (done * 100 / total) as u8,
+            &format!("project {}", step.name),
+        );
+        let result = new::run(step.to_new_args())
+            .with_context(|| format!("project step `{}` failed", step.name));
+        match result {
+            Ok(()) => created.push(PathBuf::from(&step.name)),
+            Err(error) => {
+                for dir in &created {
+                    if let Err(cleanup_error) = std::fs::remove_dir_all(dir) {
+                        output::warn(&format!(
+                            "could not roll back {}: {cleanup_error}",
+                            dir.display()
+                        ));
+                    }
+                }
+                return Err(error);
+            }
+        }
+        done += 1;
+    }
+
+    output::progress(100, "done");
+    output::ok(&localize!("batch-installed", count = plan.install.len()));
+    output::ok(&localize!("batch-projects", count = plan.project.len()));
+    Ok(())
+}
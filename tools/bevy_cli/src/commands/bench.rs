@@ -0,0 +1,179 @@
+//! `bevy bench`: criterion benchmarks with comparable results.
+//!
+//! Benchmark numbers are only worth keeping when runs are comparable, so
+//! this warns when the CPU frequency governor is not pinned, stores every
+//! result under `target/bevy-bench/` (one criterion home across runs), and
+//! can benchmark a named git ref first — in a detached worktree, never
+//! touching the working copy — so the current tree reports against that
+//! baseline.
+
+use std::path::{Path, PathBuf};
+
+use clap::Args;
+
+use crate::i18n::localize;
+use crate::output;
+use crate::subprocess::Subprocess;
+
+#[derive(Args)]
+pub struct BenchArgs {
+    /// Project directory; defaults to the nearest project root
+    #[arg(long)]
+    pub project: Option<PathBuf>,
+
+    /// Git ref to benchmark first and compare the current tree against
+    #[arg(long)]
+    pub baseline: Option<String>,
+
+    /// Store this run as a named baseline for later comparisons
+    #[arg(long)]
+    pub save: Option<String>,
+
+    /// Only run benchmarks whose name matches this filter
+    pub filter: Option<String>,
+}
+
+/// Where criterion results accumulate, shared by every run and baseline.
+const BENCH_HOME: &str = "target/bevy-bench";
+
+/// Sysfs file exposing the frequency governor of the first CPU.
+const GOVERNOR_PATH: &str = "/sys/devices/system/cpu/cpu0/cpufreq/scaling_governor";
+
+pub fn run(args: BenchArgs) -> anyhow::Result<()> {
+    let project = args
+        .project
+        .clone()
+        .unwrap_or_else(|| crate::project::locate(Path::new(".")));
+    anyhow::ensure!(
+        project.join("Cargo.toml").is_file(),
+        "{} does not contain a Cargo.toml",
+        project.display()
+    );
+    if let Some(governor) = governor() {
+        if governor != "performance" {
+            output::warn(&localize!("bench-governor", governor = governor));
+        }
+    }
+    let home = project.join(BENCH_HOME);
+    std::fs::create_dir_all(&home)?;
+
+    let compare = if let Some(baseline_ref) = &args.baseline {
+        let name = baseline_name(baseline_ref);
+        bench_ref(&project, &home, baseline_ref, &name)?;
+        Some(name)
+    } else {
+        None
+    };
+
+    bench(
+        &project,
+        &home,
+        &bench_args(
+            args.filter.as_deref(),
+            args.save.as_deref(),
+            compare.as_deref(),
+        ),
+    )?;
+    output::ok(&localize!("bench-done", path = home.display()));
+    Ok(())
+}
+
+/// Benchmarks `baseline_ref` in a throwaway detached worktree and saves the
+/// results as a named criterion baseline in the shared home.
+fn bench_ref(project: &Path, home: &Path, baseline_ref: &str, name: &str) -> anyhow::Result<()> {
+    let worktree = home.join("worktrees").join(name);
+    if worktree.exists() {
+        std::fs::remove_dir_all(&worktree)?;
+    }
+    Subprocess::new("git")
+        .arg("-C")
+        .arg(project.to_string_lossy())
+        .args(["worktree", "add", "--force", "--detach"])
+        .arg(worktree.to_string_lossy())
+        .arg(baseline_ref)
+        .run()?;
+    println!("{}", localize!("bench-baseline", name = baseline_ref));
+    let result = bench(&worktree, home, &bench_args(None, Some(name), None));
+    let _ = Subprocess::new("git")
+        .arg("-C")
+        .arg(project.to_string_lossy())
+        .args(["worktree", "remove", "--force"])
+        .arg(worktree.to_string_lossy())
+        .run();
+    result
+}
+
+fn bench(dir: &Path, home: &Path, args: &[String]) -> anyhow::Result<()> {
+    Subprocess::new("cargo")
+        .args(args.iter().cloned())
+        .current_dir(dir)
+        // Criterion puts all output under its home; sharing one across the
+        // baseline worktree and the real tree is what makes them comparable.
+        .env("CRITERION_HOME", home.to_string_lossy())
+        .run()
+}
+
+/// The `cargo bench` invocation; separated for testing. Criterion flags go
+/// after `--` and reach every benchmark binary.
+fn bench_args(filter: Option<&str>, save: Option<&str>, baseline: Option<&str>) -> Vec<String> {
+    let mut cargo = vec!["bench".to_string()];
+    let mut criterion = Vec::new();
+    if let Some(filter) = filter {
+        criterion.push(filter.to_string());
+    }
+    if let Some(save) = save {
+        criterion.push("--save-baseline".to_string());
+        criterion.push(save.to_string());
+    }
+    if let Some(baseline) = baseline {
+        criterion.push("--baseline".to_string());
+        criterion.push(baseline.to_string());
+    }
+    if !criterion.is_empty() {
+        cargo.push("--".to_string());
+        cargo.extend(criterion);
+    }
+    cargo
+}
+
+/// A git ref as a criterion baseline and directory name: path separators
+/// would otherwise nest (`origin/main`) or escape (`../`).
+fn baseline_name(baseline_ref: &str) -> String {
+    baseline_ref
+        .chars()
+        .map(|character| {
+            if character.is_alphanumeric() || character == '.' {
+                character
+            } else {
+                '-'
+            }
+        })
+        .collect()
+}
+
+fn governor() -> Option<String> {
+    std::fs::read_to_string(GOVERNOR_PATH)
+        .ok()
+        .map(|governor| governor.trim().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn criterion_flags_land_after_the_separator() {
+        assert_eq!(
+            bench_args(Some("spawn"), None, Some("main")),
+            vec!["bench", "--", "spawn", "--baseline", "main"]
+        );
+        assert_eq!(bench_args(None, None, None), vec!["bench"]);
+    }
+
+    #[test]
+    fn refs_become_filesystem_safe_baseline_names() {
+        assert_eq!(baseline_name("origin/main"), "origin-main");
+        assert_eq!(baseline_name("v0.12.0"), "v0.12.0");
+        assert_eq!(baseline_name("../escape"), "..-escape");
+    }
+}
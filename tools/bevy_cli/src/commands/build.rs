@@ -0,0 +1,664 @@
+//! `bevy build`: cross-platform builds with release-appropriate profiles
+//! and a predictable `dist/` layout.
+//!
+//! `--platform` maps to a target triple (overridable per platform in
+//! `Bevy.toml`), release builds get thin LTO and stripped symbols through
+//! cargo's profile environment overrides — no Cargo.toml edits — and the
+//! built binary lands in `dist/<platform>/`. Building another OS picks the
+//! best cross tool installed — `cross`, then `cargo zigbuild`, then plain
+//! cargo — and adds the rustup target itself, so a Linux machine produces
+//! Windows builds without a hand-maintained docker setup.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+use clap::{Args, ValueEnum};
+use serde::Deserialize;
+
+use crate::i18n::localize;
+use crate::output;
+
+#[derive(Args)]
+pub struct BuildArgs {
+    /// Project directory; defaults to the nearest project root
+    #[arg(long)]
+    pub project: Option<PathBuf>,
+
+    /// Platform to build for; omitted builds for the host
+    #[arg(long, value_enum)]
+    pub platform: Option<Platform>,
+
+    /// Build with the release profile (thin LTO, stripped symbols)
+    #[arg(long)]
+    pub release: bool,
+
+    /// Cargo features to enable, comma-separated or repeated
+    #[arg(long, value_delimiter = ',')]
+    pub features: Vec<String>,
+
+    /// Skip the `wasm-opt` pass of release web builds
+    #[arg(long)]
+    pub no_wasm_opt: bool,
+}
+
+/// The platforms `bevy build` knows target triples for.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum Platform {
+    Windows,
+    Linux,
+    Macos,
+    Web,
+    Android,
+    Ios,
+}
+
+impl Platform {
+    /// The default target triple; `[build.targets]` in `Bevy.toml`
+    /// overrides it, e.g. to pick the gnu Windows toolchain.
+    fn default_target(self) -> &'static str {
+        match self {
+            Platform::Windows => "x86_64-pc-windows-msvc",
+            Platform::Linux => "x86_64-unknown-linux-gnu",
+            Platform::Macos => "aarch64-apple-darwin",
+            Platform::Web => "wasm32-unknown-unknown",
+            Platform::Android => "aarch64-linux-android",
+            Platform::Ios => "aarch64-apple-ios",
+        }
+    }
+
+    pub(crate) fn name(self) -> &'static str {
+        match self {
+            Platform::Windows => "windows",
+            Platform::Linux => "linux",
+            Platform::Macos => "macos",
+            Platform::Web => "web",
+            Platform::Android => "android",
+            Platform::Ios => "ios",
+        }
+    }
+
+    /// The file extension of the built binary on this platform.
+    pub(crate) fn binary_extension(self) -> &'static str {
+        match self {
+            Platform::Windows => ".exe",
+            Platform::Web => ".wasm",
+            Platform::Android => ".apk",
+            Platform::Linux | Platform::Macos | Platform::Ios => "",
+        }
+    }
+}
+
+/// The platform this CLI itself runs on.
+pub(crate) fn host_platform() -> Platform {
+    if cfg!(target_os = "windows") {
+        Platform::Windows
+    } else if cfg!(target_os = "macos") {
+        Platform::Macos
+    } else {
+        Platform::Linux
+    }
+}
+
+/// How a cross-OS build gets linked. Web is excluded: wasm needs no cross
+/// toolchain beyond the rustup target.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum BuildTool {
+    Cargo,
+    Cross,
+    Zigbuild,
+}
+
+impl BuildTool {
+    /// The cargo-level invocation prefix for this tool.
+    fn invocation(self) -> (&'static str, &'static str) {
+        match self {
+            BuildTool::Cargo => ("cargo", "build"),
+            BuildTool::Cross => ("cross", "build"),
+            BuildTool::Zigbuild => ("cargo", "zigbuild"),
+        }
+    }
+}
+
+/// The best installed way to build another OS: `cross` brings full docker
+/// toolchains, `cargo-zigbuild` links through zig's bundled cc, and plain
+/// cargo is the fallback — it works for the gnu Windows triple when mingw
+/// is installed, and fails with the real linker error otherwise.
+fn select_tool(cross_compiling: bool) -> BuildTool {
+    if !cross_compiling {
+        BuildTool::Cargo
+    } else if super::doctor::on_path("cross") {
+        BuildTool::Cross
+    } else if super::doctor::on_path("cargo-zigbuild") {
+        BuildTool::Zigbuild
+    } else {
+        output::warn(&localize!("build-no-cross-tool"));
+        BuildTool::Cargo
+    }
+}
+
+/// Installs `target` through rustup when it is missing; `cross` manages its
+/// own toolchains, so this only runs for the other tools.
+pub(crate) fn ensure_target(target: &str) -> anyhow::Result<()> {
+    let installed = crate::subprocess::Subprocess::new("rustup")
+        .args(["target", "list", "--installed"])
+        .capture()
+        .unwrap_or_default();
+    if super::doctor::has_target(&installed, target) {
+        return Ok(());
+    }
+    println!("{}", localize!("build-adding-target", target = target));
+    crate::subprocess::Subprocess::new("rustup")
+        .args(["target", "add", target])
+        .run()
+}
+
+/// The `Bevy.toml` sections the build command reads.
+#[derive(Debug, Default, Deserialize)]
+struct ProjectConfig {
+    #[serde(default)]
+    build: BuildSection,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct BuildSection {
+    /// Where artifacts land, relative to the project; `dist` by default.
+    #[serde(default)]
+    dist_dir: Option<PathBuf>,
+    /// Target-triple override per platform name.
+    #[serde(default)]
+    targets: std::collections::BTreeMap<String, String>,
+    /// Android packaging settings.
+    #[serde(default)]
+    android: AndroidSection,
+    /// iOS packaging and signing settings.
+    #[serde(default)]
+    ios: IosSection,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct IosSection {
+    /// Bundle identifier; `com.example.<crate>` by default.
+    #[serde(default)]
+    bundle_id: Option<String>,
+    /// Codesigning identity, e.g. `Apple Development: ...`; unset leaves
+    /// the bundle unsigned, which simulators accept.
+    #[serde(default)]
+    identity: Option<String>,
+    /// An `.xcassets` catalog to compile into the bundle with `actool`.
+    #[serde(default)]
+    asset_catalog: Option<PathBuf>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct AndroidSection {
+    /// Release keystore; unset signs with cargo-apk's debug keystore. The
+    /// password stays out of the config and comes from
+    /// `CARGO_APK_RELEASE_KEYSTORE_PASSWORD`.
+    #[serde(default)]
+    keystore: Option<PathBuf>,
+}
+
+pub fn run(args: BuildArgs) -> anyhow::Result<()> {
+    let project = args
+        .project
+        .clone()
+        .unwrap_or_else(|| crate::project::locate(Path::new(".")));
+    anyhow::ensure!(
+        project.join("Cargo.toml").is_file(),
+        "{} does not contain a Cargo.toml",
+        project.display()
+    );
+    let config = load_config(&project)?;
+    if args.platform == Some(Platform::Android) {
+        // Android goes through cargo-apk end to end: manifest, packaging
+        // and signing live there, not in the generic cargo path.
+        return build_android(&project, &config, &args);
+    }
+    if args.platform == Some(Platform::Ios) {
+        // iOS needs Xcode tooling for bundling and signing; also separate.
+        return build_ios(&project, &config, &args);
+    }
+    let target = args.platform.map(|platform| {
+        config
+            .build
+            .targets
+            .get(platform.name())
+            .cloned()
+            .unwrap_or_else(|| {
+                if platform == Platform::Windows && host_platform() != Platform::Windows {
+                    // msvc needs the Microsoft linker; gnu cross-links anywhere.
+                    "x86_64-pc-windows-gnu".to_string()
+                } else {
+                    platform.default_target().to_string()
+                }
+            })
+    });
+    let cross_compiling = args
+        .platform
+        .is_some_and(|platform| platform != Platform::Web && platform != host_platform());
+    let tool = select_tool(cross_compiling);
+    if let Some(target) = &target {
+        if tool != BuildTool::Cross {
+            ensure_target(target)?;
+        }
+    }
+
+    let (program, subcommand) = tool.invocation();
+    let mut command = std::process::Command::new(program);
+    command
+        .args(cargo_args(subcommand, &args, target.as_deref()))
+        .current_dir(&project);
+    if args.release {
+        // Profile overrides through the environment, so the project's
+        // Cargo.toml stays untouched.
+        command.env("CARGO_PROFILE_RELEASE_LTO", "thin");
+        command.env("CARGO_PROFILE_RELEASE_STRIP", "symbols");
+    }
+    let status = command.status().context("failed to run cargo")?;
+    anyhow::ensure!(status.success(), "cargo build failed");
+
+    let name = super::bundle::package_name(&project)?;
+    let built = artifact_path(&name, target.as_deref(), args.platform, args.release);
+    let dist = config
+        .build
+        .dist_dir
+        .unwrap_or_else(|| PathBuf::from("dist"))
+        .join(args.platform.map_or("host", Platform::name));
+    std::fs::create_dir_all(project.join(&dist))?;
+    if args.platform == Some(Platform::Web) {
+        // The wasm alone is not runnable; assemble the full page bundle.
+        assemble_web(
+            &project,
+            &name,
+            &built,
+            &dist,
+            args.release && !args.no_wasm_opt,
+        )?;
+        output::ok(&localize!("build-web-bundle", path = dist.display()));
+        return Ok(());
+    }
+    let out = project.join(&dist).join(
+        built
+            .file_name()
+            .expect("artifact paths end in a file name"),
+    );
+    std::fs::copy(project.join(&built), &out)
+        .with_context(|| format!("failed to copy {}", built.display()))?;
+    output::ok(&localize!(
+        "build-artifact",
+        file = dist.join(built.file_name().unwrap_or_default()).display()
+    ));
+    Ok(())
+}
+
+/// Builds, packages and signs an APK through `cargo apk`, then drops it in
+/// `dist/android/`. Signing uses cargo-apk's bundled debug keystore unless
+/// `[build.android] keystore` points at a release one.
+fn build_android(project: &Path, config: &ProjectConfig, args: &BuildArgs) -> anyhow::Result<()> {
+    anyhow::ensure!(
+        super::doctor::on_path("cargo-apk"),
+        "cargo-apk is not installed; install it with `cargo install cargo-apk`"
+    );
+    anyhow::ensure!(
+        std::env::var_os("ANDROID_NDK_ROOT").is_some() || std::env::var_os("NDK_HOME").is_some(),
+        "no Android NDK found; set ANDROID_NDK_ROOT (see `bevy doctor`)"
+    );
+    let target = config
+        .build
+        .targets
+        .get(Platform::Android.name())
+        .cloned()
+        .unwrap_or_else(|| Platform::Android.default_target().to_string());
+    ensure_target(&target)?;
+    let manifest = std::fs::read_to_string(project.join("Cargo.toml"))?;
+    if !manifest.contains("[package.metadata.android]") {
+        // Projects from templates with Android support carry the section;
+        // cargo-apk falls back to workable defaults without it.
+        output::warn(&localize!("build-android-defaults"));
+    }
+
+    let mut apk = crate::subprocess::Subprocess::new("cargo")
+        .args(["apk", "build", "--target"])
+        .arg(&target)
+        .current_dir(project);
+    if args.release {
+        apk = apk.arg("--release");
+    }
+    if !args.features.is_empty() {
+        apk = apk.arg("--features").arg(args.features.join(","));
+    }
+    if let Some(keystore) = &config.build.android.keystore {
+        apk = apk.env(
+            "CARGO_APK_RELEASE_KEYSTORE",
+            project.join(keystore).to_string_lossy(),
+        );
+    }
+    apk.run()?;
+
+    let profile = if args.release { "release" } else { "debug" };
+    let apk_dir = project.join("target").join(profile).join("apk");
+    let built = newest_apk(&apk_dir).with_context(|| {
+        format!(
+            "no APK under {}; did cargo-apk change layout?",
+            apk_dir.display()
+        )
+    })?;
+    let dist = config
+        .build
+        .dist_dir
+        .clone()
+        .unwrap_or_else(|| PathBuf::from("dist"))
+        .join(Platform::Android.name());
+    std::fs::create_dir_all(project.join(&dist))?;
+    let out = dist.join(built.file_name().expect("apk paths end in a file name"));
+    std::fs::copy(&built, project.join(&out))?;
+    output::ok(&localize!("build-artifact", file = out.display()));
+    Ok(())
+}
+
+/// Builds an iOS `.app` bundle: cargo compiles for the device or simulator
+/// triple, the bundle is assembled by hand like the macOS one in
+/// `bevy package`, the configured asset catalog is compiled with `actool`,
+/// and a configured identity signs the result. Simulator triples (pick one
+/// through `[build.targets] ios`) skip signing — the simulator runs
+/// unsigned bundles.
+fn build_ios(project: &Path, config: &ProjectConfig, args: &BuildArgs) -> anyhow::Result<()> {
+    anyhow::ensure!(
+        host_platform() == Platform::Macos,
+        "iOS builds need macOS and Xcode; this host cannot sign or bundle them"
+    );
+    let target = config
+        .build
+        .targets
+        .get(Platform::Ios.name())
+        .cloned()
+        .unwrap_or_else(|| Platform::Ios.default_target().to_string());
+    ensure_target(&target)?;
+    crate::subprocess::Subprocess::new("cargo")
+        .args(cargo_args("build", args, Some(&target)))
+        .current_dir(project)
+        .run()?;
+
+    let name = super::bundle::package_name(project)?;
+    let profile = if args.release { "release" } else { "debug" };
+    let built = project
+        .join("target")
+        .join(&target)
+        .join(profile)
+        .join(&name);
+    let dist = config
+        .build
+        .dist_dir
+        .clone()
+        .unwrap_or_else(|| PathBuf::from("dist"))
+        .join(Platform::Ios.name());
+    let app = project.join(&dist).join(format!("{name}.app"));
+    if app.exists() {
+        std::fs::remove_dir_all(&app)?;
+    }
+    std::fs::create_dir_all(&app)?;
+    std::fs::copy(&built, app.join(&name))
+        .with_context(|| format!("no built binary at {}", built.display()))?;
+    let bundle_id = config
+        .build
+        .ios
+        .bundle_id
+        .clone()
+        .unwrap_or_else(|| format!("com.example.{name}"));
+    crate::fs_util::write_file(
+        &app.join("Info.plist"),
+        ios_info_plist(&name, &bundle_id).as_bytes(),
+        false,
+    )?;
+    let assets = project.join("assets");
+    if assets.is_dir() {
+        crate::fs_util::copy_dir(&assets, &app.join("assets"))?;
+    }
+    if let Some(catalog) = &config.build.ios.asset_catalog {
+        crate::subprocess::Subprocess::new("xcrun")
+            .args(["actool", "--compile"])
+            .arg(app.to_string_lossy())
+            .arg(project.join(catalog).to_string_lossy())
+            .args([
+                "--platform",
+                "iphoneos",
+                "--minimum-deployment-target",
+                "12.0",
+            ])
+            .run()?;
+    }
+    if let Some(identity) = &config.build.ios.identity {
+        if is_simulator_target(&target) {
+            output::warn(&localize!("build-ios-sim-unsigned"));
+        } else {
+            crate::subprocess::Subprocess::new("codesign")
+                .args(["--force", "--sign", identity])
+                .arg(app.to_string_lossy())
+                .run()?;
+        }
+    }
+    output::ok(&localize!(
+        "build-artifact",
+        file = dist.join(format!("{name}.app")).display()
+    ));
+    Ok(())
+}
+
+/// Whether a target triple builds for the iOS simulator.
+fn is_simulator_target(target: &str) -> bool {
+    target.ends_with("-sim") || target.starts_with("x86_64-apple-ios")
+}
+
+/// The minimal `Info.plist` an installable iOS bundle needs.
+fn ios_info_plist(name: &str, bundle_id: &str) -> String {
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<!DOCTYPE plist PUBLIC "-//Apple//DTD PLIST 1.0//EN" "http://www.apple.com/DTDs/PropertyList-1.0.dtd">
+<plist version="1.0">
+<dict>
+    <key>CFBundleName</key>
+    <string>{name}</string>
+    <key>CFBundleExecutable</key>
+    <string>{name}</string>
+    <key>CFBundleIdentifier</key>
+    <string>{bundle_id}</string>
+    <key>CFBundlePackageType</key>
+    <string>APPL</string>
+    <key>CFBundleVersion</key>
+    <string>1</string>
+    <key>UILaunchStoryboardName</key>
+    <string></string>
+    <key>UIRequiresFullScreen</key>
+    <true/>
+</dict>
+</plist>
+"#
+    )
+}
+
+/// The most recently written `.apk` in a directory; cargo-apk names it
+/// after the package, which this command does not want to re-derive.
+fn newest_apk(dir: &Path) -> Option<PathBuf> {
+    std::fs::read_dir(dir)
+        .ok()?
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|extension| extension == "apk"))
+        .max_by_key(|path| {
+            path.metadata()
+                .and_then(|metadata| metadata.modified())
+                .ok()
+        })
+}
+
+/// Turns the built wasm into a loadable page bundle: `wasm-bindgen` emits
+/// the JS glue and bindgen'd wasm into `dist/web/`, `wasm-opt` shrinks the
+/// result when requested and installed, and an `index.html` plus the asset
+/// tree complete the bundle.
+fn assemble_web(
+    project: &Path,
+    name: &str,
+    wasm: &Path,
+    dist: &Path,
+    optimize: bool,
+) -> anyhow::Result<()> {
+    let out_dir = project.join(dist);
+    let status = std::process::Command::new("wasm-bindgen")
+        .args([
+            "--target",
+            "web",
+            "--no-typescript",
+            "--out-name",
+            name,
+            "--out-dir",
+        ])
+        .arg(&out_dir)
+        .arg(project.join(wasm))
+        .status()
+        .context("failed to run wasm-bindgen; install it with `cargo install wasm-bindgen-cli`")?;
+    anyhow::ensure!(status.success(), "wasm-bindgen failed");
+
+    if optimize {
+        let bindgen_wasm = out_dir.join(format!("{name}_bg.wasm"));
+        match std::process::Command::new("wasm-opt")
+            .arg("-Oz")
+            .arg("-o")
+            .arg(&bindgen_wasm)
+            .arg(&bindgen_wasm)
+            .status()
+        {
+            Ok(status) => anyhow::ensure!(status.success(), "wasm-opt failed"),
+            // Missing optimizer only costs size, not correctness.
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => {
+                output::warn(&localize!("build-wasm-opt-missing"));
+            }
+            Err(error) => return Err(error).context("failed to run wasm-opt"),
+        }
+    }
+
+    let mut context = tera::Context::new();
+    context.insert("project_name", name);
+    let index = crate::template::render::render_str(
+        include_str!("../../templates/scaffold/web/dist_index.html.tera"),
+        &context,
+    )?;
+    crate::fs_util::write_file(&out_dir.join("index.html"), index.as_bytes(), false)?;
+
+    let assets = project.join("assets");
+    if assets.is_dir() {
+        crate::fs_util::copy_dir(&assets, &out_dir.join("assets"))?;
+    }
+    Ok(())
+}
+
+fn load_config(project: &Path) -> anyhow::Result<ProjectConfig> {
+    let manifest_path = project.join(crate::project::MANIFEST);
+    if !manifest_path.is_file() {
+        return Ok(ProjectConfig::default());
+    }
+    toml::from_str(&std::fs::read_to_string(&manifest_path)?)
+        .with_context(|| format!("failed to parse {}", manifest_path.display()))
+}
+
+/// The cargo argument vector for this invocation, separated for testing.
+fn cargo_args(subcommand: &str, args: &BuildArgs, target: Option<&str>) -> Vec<String> {
+    let mut cargo = vec![subcommand.to_string()];
+    if args.release {
+        cargo.push("--release".to_string());
+    }
+    if let Some(target) = target {
+        cargo.push("--target".to_string());
+        cargo.push(target.to_string());
+    }
+    if !args.features.is_empty() {
+        cargo.push("--features".to_string());
+        cargo.push(args.features.join(","));
+    }
+    cargo
+}
+
+/// Where cargo put the binary, relative to the project.
+fn artifact_path(
+    name: &str,
+    target: Option<&str>,
+    platform: Option<Platform>,
+    release: bool,
+) -> PathBuf {
+    let mut path = PathBuf::from("target");
+    if let Some(target) = target {
+        path.push(target);
+    }
+    path.push(if release { "release" } else { "debug" });
+    // Without `--platform` the build targets the host, so the binary
+    // carries the host suffix — `.exe` on Windows, nothing elsewhere.
+    let extension = platform.map_or(std::env::consts::EXE_SUFFIX, Platform::binary_extension);
+    path.push(format!("{name}{extension}"));
+    path
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn release_builds_target_the_selected_platform() {
+        let args = BuildArgs {
+            project: None,
+            platform: Some(Platform::Web),
+            release: true,
+            features: vec!["webgl2".to_string()],
+            no_wasm_opt: false,
+        };
+        assert_eq!(
+            cargo_args("build", &args, Some("wasm32-unknown-unknown")),
+            vec![
+                "build",
+                "--release",
+                "--target",
+                "wasm32-unknown-unknown",
+                "--features",
+                "webgl2"
+            ]
+        );
+        assert_eq!(
+            cargo_args("zigbuild", &args, Some("x86_64-pc-windows-gnu"))[0],
+            "zigbuild"
+        );
+    }
+
+    #[test]
+    fn simulator_triples_are_recognized_for_signing() {
+        assert!(is_simulator_target("aarch64-apple-ios-sim"));
+        assert!(is_simulator_target("x86_64-apple-ios"));
+        assert!(!is_simulator_target("aarch64-apple-ios"));
+        assert!(ios_info_plist("my_game", "com.example.my_game")
+            .contains("<string>com.example.my_game</string>"));
+    }
+
+    #[test]
+    fn artifact_paths_follow_cargo_layout() {
+        assert_eq!(
+            artifact_path(
+                "my_game",
+                Some("wasm32-unknown-unknown"),
+                Some(Platform::Web),
+                true
+            ),
+            PathBuf::from("target/wasm32-unknown-unknown/release/my_game.wasm")
+        );
+        assert_eq!(
+            artifact_path("my_game", None, None, false),
+            PathBuf::from(format!(
+                "target/debug/my_game{}",
+                std::env::consts::EXE_SUFFIX
+            ))
+        );
+        // Host builds pick up the host executable suffix, so the copy
+        // after `cargo build` finds `<name>.exe` on Windows.
+        #[cfg(windows)]
+        assert_eq!(
+            artifact_path("my_game", None, None, false),
+            PathBuf::from("target/debug/my_game.exe")
+        );
+    }
+}
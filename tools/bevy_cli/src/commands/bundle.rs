@@ -0,0 +1,469 @@
+use std::collections::BTreeMap;
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+use clap::Args;
+use serde::Deserialize;
+
+use crate::i18n::localize;
+use crate::template::render;
+use crate::{fs_util, output};
+
+#[derive(Args)]
+pub struct BundleArgs {
+    /// Project directory to bundle; defaults to the current directory
+    #[arg(long)]
+    pub project: Option<PathBuf>,
+
+    /// Bundle profile from the project's Bevy.toml, e.g. `itch-web`;
+    /// defaults to the `default` profile (or built-in settings)
+    #[arg(long, value_name = "NAME")]
+    pub profile: Option<String>,
+
+    /// Re-run a step even when its cached inputs are unchanged (repeatable)
+    #[arg(long = "force-step", value_name = "NAME")]
+    pub force_steps: Vec<String>,
+}
+
+/// The `Bevy.toml` sections the bundler reads.
+#[derive(Debug, Default, Deserialize)]
+struct ProjectConfig {
+    #[serde(default)]
+    bundle: BundleSection,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct BundleSection {
+    /// Named profiles like `itch-web` or `steam-windows`, selected with
+    /// `bevy bundle --profile`.
+    #[serde(default)]
+    profiles: BTreeMap<String, BundleProfile>,
+}
+
+/// One named bundle configuration.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct BundleProfile {
+    /// Target triples to build; empty builds for the host.
+    #[serde(default)]
+    pub targets: Vec<String>,
+    /// Cargo features enabled for the build step.
+    #[serde(default)]
+    pub features: Vec<String>,
+    /// Globs (relative to `assets/`) of assets to ship; empty ships
+    /// everything.
+    #[serde(default)]
+    pub include: Vec<String>,
+    /// Globs (relative to `assets/`) excluded from the bundle: debug
+    /// scenes, raw sources (PSD/Blend), test fixtures.
+    #[serde(default)]
+    pub exclude: Vec<String>,
+    /// Whether the sign step runs; stores that re-sign uploads can turn
+    /// this off.
+    #[serde(default = "default_sign")]
+    pub sign: bool,
+}
+
+impl Default for BundleProfile {
+    fn default() -> Self {
+        Self {
+            targets: Vec::new(),
+            features: Vec::new(),
+            include: Vec::new(),
+            exclude: Vec::new(),
+            sign: true,
+        }
+    }
+}
+
+fn default_sign() -> bool {
+    true
+}
+
+/// Loads the selected profile. Asking for a named profile that is not
+/// declared is an error; without `--profile` the `default` profile applies
+/// when declared and built-in settings otherwise.
+fn load_profile(project: &Path, selected: Option<&str>) -> anyhow::Result<BundleProfile> {
+    let manifest_path = project.join(crate::project::MANIFEST);
+    let config: ProjectConfig = if manifest_path.is_file() {
+        toml::from_str(&std::fs::read_to_string(&manifest_path)?)
+            .with_context(|| format!("failed to parse {}", manifest_path.display()))?
+    } else {
+        ProjectConfig::default()
+    };
+    let name = selected.unwrap_or("default");
+    match config.bundle.profiles.get(name) {
+        Some(profile) => Ok(profile.clone()),
+        None if selected.is_none() => Ok(BundleProfile::default()),
+        None => {
+            let available: Vec<&str> = config.bundle.profiles.keys().map(String::as_str).collect();
+            anyhow::bail!(
+                "Bevy.toml declares no bundle profile `{name}`; available: {}",
+                if available.is_empty() {
+                    "none".to_string()
+                } else {
+                    available.join(", ")
+                }
+            )
+        }
+    }
+}
+
+/// The pipeline steps, in execution order.
+const STEPS: &[&str] = &["build", "assets", "icons", "sign", "package"];
+
+/// Where a profile's step results are staged before packaging.
+fn stage_dir(project: &Path, profile_name: &str) -> PathBuf {
+    project
+        .join("target/bundle")
+        .join(profile_name)
+        .join("stage")
+}
+
+/// Cache of input hashes per completed step, relative to the project. The
+/// file is rewritten after every step, so an interrupted run resumes from
+/// the first step whose inputs no longer match.
+const CACHE_FILE: &str = ".bevy/bundle-cache.json";
+
+/// Runs the bundle pipeline: build, assets, icons, sign, package. Each step
+/// caches the hash of its inputs; a re-run skips steps whose inputs are
+/// unchanged (unless forced with `--force-step`), so a failure — or a
+/// signing-only change — does not repeat the expensive early steps.
+pub fn run(args: BundleArgs) -> anyhow::Result<()> {
+    let project = args
+        .project
+        .clone()
+        .unwrap_or_else(|| crate::project::locate(Path::new(".")));
+    anyhow::ensure!(
+        project.join("Cargo.toml").is_file(),
+        "{} does not contain a Cargo.toml",
+        project.display()
+    );
+    if let Some(unknown) = args
+        .force_steps
+        .iter()
+        .find(|step| !STEPS.contains(&step.as_str()))
+    {
+        anyhow::bail!(
+            "unknown step `{unknown}`; the pipeline steps are {}",
+            STEPS.join(", ")
+        );
+    }
+
+    let profile_name = args
+        .profile
+        .clone()
+        .unwrap_or_else(|| "default".to_string());
+    let profile = load_profile(&project, args.profile.as_deref())?;
+
+    let cache_path = project.join(CACHE_FILE);
+    let mut cache = load_cache(&cache_path);
+    let mut skipped = 0usize;
+    for &step in STEPS {
+        if step == "sign" && !profile.sign {
+            skipped += 1;
+            println!("{}", localize!("bundle-step-disabled", step = step));
+            continue;
+        }
+        let hash = input_hash(&project, &profile_name, &profile, step)?;
+        // Profiles cache independently, so switching profiles never reuses
+        // another profile's outputs.
+        let cache_key = format!("{profile_name}/{step}");
+        let forced = args.force_steps.iter().any(|name| name == step);
+        if !forced && cache.get(&cache_key) == Some(&hash) {
+            skipped += 1;
+            println!("{}", localize!("bundle-step-skipped", step = step));
+            continue;
+        }
+        run_step(&project, &profile_name, &profile, step)?;
+        // Record completion immediately: a cancel after this point resumes
+        // behind this step, never before it.
+        cache.insert(cache_key, hash);
+        save_cache(&cache_path, &cache)?;
+        output::ok(&localize!("bundle-step-done", step = step));
+    }
+    println!(
+        "{}",
+        localize!(
+            "bundle-complete",
+            count = STEPS.len() - skipped,
+            skipped = skipped
+        )
+    );
+    Ok(())
+}
+
+fn run_step(
+    project: &Path,
+    profile_name: &str,
+    profile: &BundleProfile,
+    step: &str,
+) -> anyhow::Result<()> {
+    let stage = stage_dir(project, profile_name);
+    std::fs::create_dir_all(&stage)?;
+    match step {
+        "build" => {
+            let name = package_name(project)?;
+            if profile.targets.is_empty() {
+                build_one(project, profile, None, &name, &stage)?;
+            } else {
+                for target in &profile.targets {
+                    build_one(project, profile, Some(target), &name, &stage)?;
+                }
+            }
+        }
+        "assets" => {
+            let assets = project.join("assets");
+            if assets.is_dir() {
+                let staged = stage.join("assets");
+                if staged.exists() {
+                    std::fs::remove_dir_all(&staged)?;
+                }
+                stage_assets(&assets, &staged, profile)?;
+                // The integrity manifest ships inside the bundle; `bevy
+                // assets verify` and the generated runtime module check
+                // against it.
+                let recorded = crate::commands::assets::manifest::write(&staged)?;
+                println!("{}", localize!("bundle-manifest-written", count = recorded));
+            }
+        }
+        "icons" => {
+            let icon = project.join("icon.png");
+            if icon.is_file() {
+                std::fs::copy(&icon, stage.join("icon.png"))?;
+            }
+        }
+        "sign" => {
+            // A content manifest stands in for real signing; per-file hashes
+            // let distribution channels verify the payload.
+            let mut manifest = String::from("# Content hashes of the staged bundle\n");
+            for path in sorted_files(&stage)? {
+                if path
+                    .file_name()
+                    .is_some_and(|name| name == "SIGNATURES.toml")
+                {
+                    continue;
+                }
+                let rel = path.strip_prefix(&stage)?.to_string_lossy().into_owned();
+                let contents = std::fs::read(&path)?;
+                manifest.push_str(&format!("\"{rel}\" = \"{:016x}\"\n", hash_bytes(&contents)));
+            }
+            fs_util::write_file(&stage.join("SIGNATURES.toml"), manifest.as_bytes(), false)?;
+        }
+        "package" => {
+            let dist = project
+                .join("dist")
+                .join(profile_name)
+                .join(package_name(project)?);
+            if dist.exists() {
+                std::fs::remove_dir_all(&dist)?;
+            }
+            fs_util::copy_dir(&stage, &dist)?;
+        }
+        _ => unreachable!("unknown pipeline step"),
+    }
+    Ok(())
+}
+
+/// Copies the asset tree into the stage, honoring the profile's include and
+/// exclude globs, and reports what stayed behind and how much smaller the
+/// bundle got for it.
+fn stage_assets(assets: &Path, staged: &Path, profile: &BundleProfile) -> anyhow::Result<()> {
+    let include = render::compile_globs(&profile.include)
+        .context("invalid `include` pattern in bundle profile")?;
+    let exclude = render::compile_globs(&profile.exclude)
+        .context("invalid `exclude` pattern in bundle profile")?;
+    let mut excluded = 0usize;
+    let mut saved = 0u64;
+    for path in sorted_files(assets)? {
+        let rel = path.strip_prefix(assets)?;
+        let shipped = (include.is_empty() || render::matches_any(&include, rel))
+            && !render::matches_any(&exclude, rel);
+        if !shipped {
+            excluded += 1;
+            saved += std::fs::metadata(&path)?.len();
+            println!(
+                "{}",
+                localize!("bundle-asset-excluded", file = rel.display())
+            );
+            continue;
+        }
+        let out = staged.join(rel);
+        if let Some(parent) = out.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::copy(&path, &out)?;
+    }
+    if excluded > 0 {
+        println!(
+            "{}",
+            localize!("bundle-assets-excluded", count = excluded, size = saved)
+        );
+    }
+    Ok(())
+}
+
+/// Builds one target of the build step and stages the resulting binary.
+fn build_one(
+    project: &Path,
+    profile: &BundleProfile,
+    target: Option<&str>,
+    name: &str,
+    stage: &Path,
+) -> anyhow::Result<()> {
+    let mut command = std::process::Command::new("cargo");
+    command.args(["build", "--release"]).current_dir(project);
+    if !profile.features.is_empty() {
+        command.arg("--features").arg(profile.features.join(","));
+    }
+    if let Some(triple) = target {
+        command.arg("--target").arg(triple);
+    }
+    let status = command.status().context("failed to run cargo")?;
+    anyhow::ensure!(status.success(), "cargo build failed");
+    let release = match target {
+        Some(triple) => project.join("target").join(triple).join("release"),
+        None => project.join("target/release"),
+    };
+    let binary = release.join(name);
+    if binary.is_file() {
+        let staged = match target {
+            Some(triple) => format!("{name}-{triple}"),
+            None => name.to_string(),
+        };
+        std::fs::copy(&binary, stage.join(staged))?;
+    }
+    Ok(())
+}
+
+/// Hashes everything a step reads, so any input edit invalidates its cache
+/// entry. Later steps consume the stage directory, which earlier steps keep
+/// appending to, so their hashes chain naturally. The profile settings hash
+/// into every step, so editing a profile invalidates its cached runs.
+fn input_hash(
+    project: &Path,
+    profile_name: &str,
+    profile: &BundleProfile,
+    step: &str,
+) -> anyhow::Result<String> {
+    let stage = stage_dir(project, profile_name);
+    let inputs: Vec<PathBuf> = match step {
+        "build" => vec![project.join("Cargo.toml"), project.join("src")],
+        "assets" => vec![project.join("assets")],
+        "icons" => vec![project.join("icon.png")],
+        "sign" | "package" => vec![stage.clone()],
+        _ => unreachable!("unknown pipeline step"),
+    };
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    profile.targets.hash(&mut hasher);
+    profile.features.hash(&mut hasher);
+    profile.include.hash(&mut hasher);
+    profile.exclude.hash(&mut hasher);
+    profile.sign.hash(&mut hasher);
+    for input in inputs {
+        if input.is_file() {
+            input.to_string_lossy().hash(&mut hasher);
+            std::fs::read(&input)?.hash(&mut hasher);
+        } else if input.is_dir() {
+            for path in sorted_files(&input)? {
+                // The signing step must not see its own output, or it would
+                // invalidate itself on every run.
+                if step == "sign"
+                    && path
+                        .file_name()
+                        .is_some_and(|name| name == "SIGNATURES.toml")
+                {
+                    continue;
+                }
+                path.to_string_lossy().hash(&mut hasher);
+                std::fs::read(&path)?.hash(&mut hasher);
+            }
+        }
+    }
+    Ok(format!("{:016x}", hasher.finish()))
+}
+
+fn hash_bytes(bytes: &[u8]) -> u64 {
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    bytes.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Every file under `dir`, recursively, in a deterministic order.
+fn sorted_files(dir: &Path) -> anyhow::Result<Vec<PathBuf>> {
+    fn visit(dir: &Path, files: &mut Vec<PathBuf>) -> anyhow::Result<()> {
+        for entry in std::fs::read_dir(dir)? {
+            let path = entry?.path();
+            if path.is_dir() {
+                visit(&path, files)?;
+            } else {
+                files.push(path);
+            }
+        }
+        Ok(())
+    }
+    let mut files = Vec::new();
+    if dir.is_dir() {
+        visit(dir, &mut files)?;
+    }
+    files.sort();
+    Ok(files)
+}
+
+fn load_cache(path: &Path) -> BTreeMap<String, String> {
+    std::fs::read_to_string(path)
+        .ok()
+        .and_then(|contents| serde_json::from_str(&contents).ok())
+        .unwrap_or_default()
+}
+
+fn save_cache(path: &Path, cache: &BTreeMap<String, String>) -> anyhow::Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    fs_util::write_file(path, serde_json::to_string_pretty(cache)?.as_bytes(), false)
+}
+
+/// Reads the package name from the project's Cargo.toml.
+pub(crate) fn package_name(project: &Path) -> anyhow::Result<String> {
+    let manifest = crate::scaffold::read_manifest(&project.join("Cargo.toml"))?;
+    manifest["package"]["name"]
+        .as_str()
+        .map(str::to_string)
+        .context("Cargo.toml has no `package.name`")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn input_hashes_track_content_changes() {
+        let dir = std::env::temp_dir().join("bevy_cli_bundle_test");
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(dir.join("src")).unwrap();
+        std::fs::write(dir.join("Cargo.toml"), "[package]\nname = \"demo\"\n").unwrap();
+        std::fs::write(dir.join("src/main.rs"), "fn main() {}\n").unwrap();
+        let profile = BundleProfile::default();
+        let before = input_hash(&dir, "default", &profile, "build").unwrap();
+        assert_eq!(
+            before,
+            input_hash(&dir, "default", &profile, "build").unwrap()
+        );
+        std::fs::write(dir.join("src/main.rs"), "fn main() { /* edited */ }\n").unwrap();
+        assert_ne!(
+            before,
+            input_hash(&dir, "default", &profile, "build").unwrap()
+        );
+        let demo = BundleProfile {
+            features: vec!["demo".to_string()],
+            ..BundleProfile::default()
+        };
+        assert_ne!(
+            input_hash(&dir, "default", &profile, "build").unwrap(),
+            input_hash(&dir, "demo", &demo, "build").unwrap()
+        );
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,391 @@
+//! `bevy check`: cargo's own diagnostics plus Bevy-aware ones.
+//!
+//! After `cargo check` (or clippy) passes, the project is scanned for the
+//! mistakes the compiler cannot see: systems that are never added to an
+//! `App`, `Plugin` impls that are never registered, and dependency pairs
+//! known to be incompatible with the Bevy version in use. The scans are
+//! heuristic and only ever warn — a clean build stays a clean exit.
+//!
+//! `--matrix` instead checks every feature-set x target combination
+//! `[check.matrix]` in `Bevy.toml` declares (by default: default features
+//! and no features, natively and for wasm) and reports the failing cells —
+//! the honest-feature-gates check plugin authors otherwise script by hand.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+use clap::Args;
+use serde::Deserialize;
+
+use crate::i18n::localize;
+use crate::output;
+
+#[derive(Args)]
+pub struct CheckArgs {
+    /// Project directory; defaults to the nearest project root
+    #[arg(long)]
+    pub project: Option<PathBuf>,
+
+    /// Run `cargo clippy` instead of `cargo check`
+    #[arg(long)]
+    pub clippy: bool,
+
+    /// Check the configured feature-set and target matrix instead
+    #[arg(long)]
+    pub matrix: bool,
+}
+
+/// The `[check.matrix]` section of `Bevy.toml`.
+#[derive(Debug, Deserialize)]
+struct MatrixSection {
+    /// Feature sets to check: `"default"`, `"none"`, or a comma-separated
+    /// feature list.
+    #[serde(default = "default_feature_sets")]
+    features: Vec<String>,
+    /// Target triples to check each set on; `""` is the host.
+    #[serde(default = "default_matrix_targets")]
+    targets: Vec<String>,
+}
+
+fn default_feature_sets() -> Vec<String> {
+    vec!["default".to_string(), "none".to_string()]
+}
+
+fn default_matrix_targets() -> Vec<String> {
+    vec![String::new(), "wasm32-unknown-unknown".to_string()]
+}
+
+impl Default for MatrixSection {
+    fn default() -> Self {
+        Self {
+            features: default_feature_sets(),
+            targets: default_matrix_targets(),
+        }
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct CheckSection {
+    #[serde(default)]
+    matrix: MatrixSection,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct ProjectConfig {
+    #[serde(default)]
+    check: CheckSection,
+}
+
+/// Dependency pairs that are known not to work together: the Bevy version
+/// prefix, the offending crate, its broken version prefix, and what to do.
+const INCOMPATIBLE_PAIRS: &[(&str, &str, &str, &str)] = &[
+    (
+        "0.12",
+        "bevy_rapier2d",
+        "0.22",
+        "bevy_rapier2d 0.23 is the first release for Bevy 0.12",
+    ),
+    (
+        "0.12",
+        "bevy_rapier3d",
+        "0.22",
+        "bevy_rapier3d 0.23 is the first release for Bevy 0.12",
+    ),
+    (
+        "0.12",
+        "bevy_egui",
+        "0.22",
+        "bevy_egui 0.23 is the first release for Bevy 0.12",
+    ),
+    (
+        "0.12",
+        "leafwing-input-manager",
+        "0.10",
+        "0.11 is the first release for Bevy 0.12",
+    ),
+    (
+        "0.11",
+        "bevy_rapier2d",
+        "0.21",
+        "bevy_rapier2d 0.22 is the first release for Bevy 0.11",
+    ),
+    (
+        "0.11",
+        "bevy_rapier3d",
+        "0.21",
+        "bevy_rapier3d 0.22 is the first release for Bevy 0.11",
+    ),
+];
+
+pub fn run(args: CheckArgs) -> anyhow::Result<()> {
+    let project = args
+        .project
+        .clone()
+        .unwrap_or_else(|| crate::project::locate(Path::new(".")));
+    anyhow::ensure!(
+        project.join("Cargo.toml").is_file(),
+        "{} does not contain a Cargo.toml",
+        project.display()
+    );
+    if args.matrix {
+        return run_matrix(&project, args.clippy);
+    }
+    crate::subprocess::Subprocess::new("cargo")
+        .arg(if args.clippy { "clippy" } else { "check" })
+        .current_dir(&project)
+        .run()?;
+
+    let source = project_sources(&project)?;
+    let mut findings = 0usize;
+    for system in unreferenced_systems(&source) {
+        output::warn(&localize!("check-unused-system", name = system));
+        findings += 1;
+    }
+    for plugin in unregistered_plugins(&source) {
+        output::warn(&localize!("check-unused-plugin", name = plugin));
+        findings += 1;
+    }
+    for note in incompatible_dependencies(&project)? {
+        output::warn(&note);
+        findings += 1;
+    }
+
+    if findings == 0 {
+        output::ok(&localize!("check-clean"));
+    } else {
+        println!("{}", localize!("check-findings", count = findings));
+    }
+    Ok(())
+}
+
+/// Checks every cell of the configured matrix, keeps going past failures,
+/// and fails at the end when any cell did — one run tells a plugin author
+/// every combination that needs fixing.
+fn run_matrix(project: &Path, clippy: bool) -> anyhow::Result<()> {
+    let config = load_config(project)?;
+    let matrix = &config.check.matrix;
+    for target in &matrix.targets {
+        if !target.is_empty() {
+            super::build::ensure_target(target)?;
+        }
+    }
+    let mut failed = Vec::new();
+    for target in &matrix.targets {
+        for set in &matrix.features {
+            let label = cell_label(set, target);
+            println!("{}", localize!("check-matrix-cell", cell = label));
+            let result = crate::subprocess::Subprocess::new("cargo")
+                .args(cell_args(clippy, set, target))
+                .current_dir(project)
+                .run();
+            if result.is_err() {
+                failed.push(label);
+            }
+        }
+    }
+    if failed.is_empty() {
+        output::ok(&localize!("check-matrix-clean"));
+        return Ok(());
+    }
+    for cell in &failed {
+        output::error(&localize!("check-matrix-failed", cell = cell));
+    }
+    anyhow::bail!(localize!("check-matrix-summary", count = failed.len()));
+}
+
+/// The cargo invocation for one matrix cell; separated for testing.
+fn cell_args(clippy: bool, set: &str, target: &str) -> Vec<String> {
+    let mut cargo = vec![if clippy { "clippy" } else { "check" }.to_string()];
+    match set {
+        "default" => {}
+        "none" => cargo.push("--no-default-features".to_string()),
+        features => {
+            cargo.push("--no-default-features".to_string());
+            cargo.push("--features".to_string());
+            cargo.push(features.to_string());
+        }
+    }
+    if !target.is_empty() {
+        cargo.push("--target".to_string());
+        cargo.push(target.to_string());
+    }
+    cargo
+}
+
+/// A human name for a matrix cell, e.g. `serialize on wasm32-unknown-unknown`.
+fn cell_label(set: &str, target: &str) -> String {
+    let target = if target.is_empty() { "host" } else { target };
+    format!("{set} on {target}")
+}
+
+fn load_config(project: &Path) -> anyhow::Result<ProjectConfig> {
+    let manifest_path = project.join(crate::project::MANIFEST);
+    if !manifest_path.is_file() {
+        return Ok(ProjectConfig::default());
+    }
+    toml::from_str(&std::fs::read_to_string(&manifest_path)?)
+        .with_context(|| format!("failed to parse {}", manifest_path.display()))
+}
+
+/// All Rust sources of the project (including workspace crates under
+/// `crates/`), concatenated — the heuristics only need a haystack.
+fn project_sources(project: &Path) -> anyhow::Result<String> {
+    let mut source = String::new();
+    let mut stack = vec![project.join("src")];
+    if let Ok(entries) = std::fs::read_dir(project.join("crates")) {
+        stack.extend(entries.flatten().map(|entry| entry.path().join("src")));
+    }
+    while let Some(dir) = stack.pop() {
+        let Ok(entries) = std::fs::read_dir(&dir) else {
+            continue;
+        };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.is_dir() {
+                stack.push(path);
+            } else if path.extension().is_some_and(|extension| extension == "rs") {
+                source.push_str(&std::fs::read_to_string(&path)?);
+                source.push('\n');
+            }
+        }
+    }
+    Ok(source)
+}
+
+fn occurrences(source: &str, name: &str) -> usize {
+    regex::Regex::new(&format!(r"\b{}\b", regex::escape(name)))
+        .expect("escaped name compiles")
+        .find_iter(source)
+        .count()
+}
+
+/// Private functions that look like systems (they take ECS parameters) but
+/// are never referenced outside their own definition — usually a system
+/// someone forgot to pass to `add_systems`.
+fn unreferenced_systems(source: &str) -> Vec<String> {
+    let system =
+        regex::Regex::new(r"(?m)^\s*fn\s+(\w+)\s*\(([^)]*)\)").expect("system pattern compiles");
+    let ecs_param =
+        regex::Regex::new(r"\b(Query|Res|ResMut|Commands|EventReader|EventWriter)\s*<?")
+            .expect("param pattern compiles");
+    let mut unused = Vec::new();
+    for captures in system.captures_iter(source) {
+        let name = &captures[1];
+        if !ecs_param.is_match(&captures[2]) {
+            continue;
+        }
+        // The definition itself is the one allowed occurrence.
+        if occurrences(source, name) <= 1 {
+            unused.push(name.to_string());
+        }
+    }
+    unused
+}
+
+/// Private `Plugin` impls whose type never appears beyond its declaration
+/// and the impl — a plugin nobody passed to `add_plugins`.
+fn unregistered_plugins(source: &str) -> Vec<String> {
+    let plugin =
+        regex::Regex::new(r"impl\s+Plugin\s+for\s+(\w+)").expect("plugin pattern compiles");
+    let mut unregistered = Vec::new();
+    for captures in plugin.captures_iter(source) {
+        let name = &captures[1];
+        if source.contains(&format!("pub struct {name}")) {
+            // Exported plugins are registered by downstream crates.
+            continue;
+        }
+        // Declaration plus impl are the two allowed occurrences.
+        if occurrences(source, name) <= 2 {
+            unregistered.push(name.to_string());
+        }
+    }
+    unregistered
+}
+
+/// Checks the dependency graph for version pairs known to be incompatible
+/// with the Bevy version in use, via `cargo metadata`.
+fn incompatible_dependencies(project: &Path) -> anyhow::Result<Vec<String>> {
+    let Ok(stdout) = crate::subprocess::Subprocess::new("cargo")
+        .args(["metadata", "--format-version", "1", "--no-deps"])
+        .current_dir(project)
+        .capture()
+    else {
+        return Ok(Vec::new());
+    };
+    let metadata: serde_json::Value = serde_json::from_str(&stdout)?;
+    let mut versions: BTreeMap<String, String> = BTreeMap::new();
+    for package in metadata["packages"].as_array().into_iter().flatten() {
+        for dependency in package["dependencies"].as_array().into_iter().flatten() {
+            if let (Some(name), Some(req)) =
+                (dependency["name"].as_str(), dependency["req"].as_str())
+            {
+                versions.insert(name.to_string(), req.trim_start_matches('^').to_string());
+            }
+        }
+    }
+    let Some(bevy) = versions.get("bevy").cloned() else {
+        return Ok(Vec::new());
+    };
+    let mut notes = Vec::new();
+    for (bevy_prefix, crate_name, bad_prefix, note) in INCOMPATIBLE_PAIRS {
+        if !bevy.starts_with(bevy_prefix) {
+            continue;
+        }
+        if let Some(version) = versions.get(*crate_name) {
+            if version.starts_with(bad_prefix) {
+                notes.push(format!(
+                    "{crate_name} {version} is incompatible with bevy {bevy}: {note}"
+                ));
+            }
+        }
+    }
+    Ok(notes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn systems_missing_from_any_schedule_are_reported() {
+        let source = "fn orphan(query: Query<&Transform>) {}\n\
+                      fn movement(query: Query<&mut Transform>) {}\n\
+                      fn plain_helper(value: u32) {}\n\
+                      fn main() { App::new().add_systems(Update, movement).run(); }\n";
+        assert_eq!(unreferenced_systems(source), vec!["orphan"]);
+    }
+
+    #[test]
+    fn matrix_cells_translate_feature_set_shorthand() {
+        assert_eq!(cell_args(false, "default", ""), vec!["check"]);
+        assert_eq!(
+            cell_args(false, "none", "wasm32-unknown-unknown"),
+            vec![
+                "check",
+                "--no-default-features",
+                "--target",
+                "wasm32-unknown-unknown"
+            ]
+        );
+        assert_eq!(
+            cell_args(true, "serialize,webgl2", ""),
+            vec![
+                "clippy",
+                "--no-default-features",
+                "--features",
+                "serialize,webgl2"
+            ]
+        );
+        assert_eq!(cell_label("none", ""), "none on host");
+    }
+
+    #[test]
+    fn unregistered_private_plugins_are_reported() {
+        let source = "struct ForgottenPlugin;\n\
+                      impl Plugin for ForgottenPlugin { fn build(&self, _: &mut App) {} }\n\
+                      pub struct ExportedPlugin;\n\
+                      impl Plugin for ExportedPlugin { fn build(&self, _: &mut App) {} }\n";
+        assert_eq!(unregistered_plugins(source), vec!["ForgottenPlugin"]);
+    }
+}
@@ -0,0 +1,255 @@
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use anyhow::Context;
+use clap::Args;
+use serde::Deserialize;
+
+use crate::commands::new;
+use crate::i18n::localize;
+use crate::output;
+
+/// Name of the resume file kept in the output directory; lists the projects
+/// already generated so an interrupted run picks up where it stopped.
+const RESUME_FILE: &str = ".classroom-resume";
+
+#[derive(Args)]
+pub struct ClassroomArgs {
+    /// Student roster: a `.toml` file with `[[students]]` entries, or a CSV
+    /// with `name[,email[,project]]` rows
+    pub roster: PathBuf,
+
+    /// Template for the generated projects (path or installed name)
+    #[arg(long)]
+    pub template: Option<PathBuf>,
+
+    /// Directory the per-student projects are created in
+    #[arg(long, default_value = ".")]
+    pub output: PathBuf,
+
+    /// Pause between students, for rate-limited remotes
+    #[arg(long, default_value_t = 0)]
+    pub delay_ms: u64,
+
+    /// Initialize a git repo per project and add an origin remote of
+    /// `<prefix><project>.git`, e.g. `--remote-prefix git@github.com:course/`
+    #[arg(long)]
+    pub remote_prefix: Option<String>,
+
+    /// Bevy version for the generated projects
+    #[arg(long, default_value = "latest")]
+    pub bevy_version: String,
+}
+
+/// One student from the roster.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Student {
+    pub name: String,
+    #[serde(default)]
+    pub email: Option<String>,
+    /// Project name override; defaults to a slug of the student name.
+    #[serde(default)]
+    pub project: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Roster {
+    students: Vec<Student>,
+}
+
+impl Student {
+    pub fn project_name(&self) -> String {
+        self.project.clone().unwrap_or_else(|| slugify(&self.name))
+    }
+}
+
+/// `Ada Lovelace` -> `ada_lovelace`, a valid crate/directory name.
+pub fn slugify(name: &str) -> String {
+    let mut slug = String::new();
+    for c in name.trim().chars() {
+        if c.is_alphanumeric() {
+            slug.extend(c.to_lowercase());
+        } else if !slug.ends_with('_') && !slug.is_empty() {
+            slug.push('_');
+        }
+    }
+    slug.trim_end_matches('_').to_string()
+}
+
+/// Parses a roster file, TOML or CSV depending on the extension.
+pub fn parse_roster(path: &Path, contents: &str) -> anyhow::Result<Vec<Student>> {
+    if path.extension().is_some_and(|ext| ext == "toml") {
+        let roster: Roster = toml::from_str(contents)?;
+        return Ok(roster.students);
+    }
+    let mut students = Vec::new();
+    for (index, line) in contents.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || (index == 0 && line.to_lowercase().starts_with("name")) {
+            continue;
+        }
+        let mut fields = line.split(',').map(str::trim);
+        let name = fields.next().unwrap_or_default();
+        anyhow::ensure!(!name.is_empty(), "row {}: empty student name", index + 1);
+        students.push(Student {
+            name: name.to_string(),
+            email: fields.next().filter(|f| !f.is_empty()).map(str::to_string),
+            project: fields.next().filter(|f| !f.is_empty()).map(str::to_string),
+        });
+    }
+    Ok(students)
+}
+
+pub fn run(args: ClassroomArgs) -> anyhow::Result<()> {
+    let contents = std::fs::read_to_string(&args.roster)
+        .with_context(|| format!("failed to read {}", args.roster.display()))?;
+    let students = parse_roster(&args.roster, &contents)?;
+    anyhow::ensure!(!students.is_empty(), "the roster lists no students");
+    std::fs::create_dir_all(&args.output)?;
+
+    let resume_path = args.output.join(RESUME_FILE);
+    let done: BTreeSet<String> = std::fs::read_to_string(&resume_path)
+        .map(|contents| contents.lines().map(str::to_string).collect())
+        .unwrap_or_default();
+
+    let total = students.len();
+    let mut failed: Vec<String> = Vec::new();
+    for (index, student) in students.iter().enumerate() {
+        let project = student.project_name();
+        output::progress((index * 100 / total) as u8, &project);
+        if done.contains(&project) {
+            println!("{}", localize!("classroom-skipping", project = project));
+            continue;
+        }
+        match generate_for(student, &args) {
+            Ok(()) => {
+                let mut line = project.clone();
+                line.push('\n');
+                append(&resume_path, &line)?;
+            }
+            Err(error) => {
+                output::warn(&format!("{project}: {error:#}"));
+                failed.push(project);
+            }
+        }
+        if args.delay_ms > 0 && index + 1 < total {
+            std::thread::sleep(Duration::from_millis(args.delay_ms));
+        }
+    }
+    output::progress(100, "done");
+
+    if failed.is_empty() {
+        // A finished classroom run does not need resuming again.
+        let _ = std::fs::remove_file(&resume_path);
+        output::ok(&localize!("classroom-complete", count = total));
+        Ok(())
+    } else {
+        anyhow::bail!(localize!(
+            "classroom-failed",
+            count = failed.len(),
+            projects = failed.join(", ")
+        ));
+    }
+}
+
+fn generate_for(student: &Student, args: &ClassroomArgs) -> anyhow::Result<()> {
+    let project = student.project_name();
+    let target = args.output.join(&project);
+    new::run(new::NewArgs {
+        name: Some(project.clone()),
+        interactive: false,
+        replay: None,
+        i_know_what_im_doing: false,
+        template: args.template.clone(),
+        workspace: false,
+        kind: new::ProjectKind::Game,
+        vars: Vec::new(),
+        with_states: false,
+        with_assets: false,
+        with_examples: false,
+        with_benches: false,
+        bevy_version: args.bevy_version.clone(),
+        bevy_features: Vec::new(),
+        author: Some(student.name.clone()),
+        email: student.email.clone(),
+        target_dir: Some(target.clone()),
+        toolchain: None,
+        fast_compiles: false,
+        edition: "2021".to_string(),
+        msrv: None,
+        ci: false,
+        ci_provider: crate::scaffold::ContinuousIntegration::default(),
+        ci_options: crate::scaffold::CiOptions::default(),
+        vcs: new::Vcs::None,
+        xtask: false,
+        bins: Vec::new(),
+        target: None,
+        layout: crate::scaffold::Layout::Flat,
+        with_editor_config: false,
+        gitignore: Vec::new(),
+        builtin_only: false,
+        extra_context: vec![
+            ("student_name".to_string(), student.name.clone()),
+            (
+                "student_email".to_string(),
+                student.email.clone().unwrap_or_default(),
+            ),
+        ],
+    })?;
+    if let Some(prefix) = &args.remote_prefix {
+        init_repo_with_remote(&target, &format!("{prefix}{project}.git"))?;
+    }
+    Ok(())
+}
+
+fn init_repo_with_remote(dir: &Path, remote: &str) -> anyhow::Result<()> {
+    use crate::vcs::Vcs;
+    crate::vcs::ShellGit::default().init(dir)?;
+    crate::vcs::ShellGit::default().add_remote(dir, "origin", remote)
+}
+
+fn append(path: &Path, line: &str) -> anyhow::Result<()> {
+    use std::io::Write;
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)?;
+    file.write_all(line.as_bytes())?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn slugify_produces_crate_names() {
+        assert_eq!(slugify("Ada Lovelace"), "ada_lovelace");
+        assert_eq!(slugify("  Éva  N.-K.  "), "éva_n_k");
+    }
+
+    #[test]
+    fn csv_rosters_skip_headers_and_blank_lines() {
+        let students = parse_roster(
+            Path::new("roster.csv"),
+            "name,email\nAda Lovelace,ada@example.com\n\nGrace Hopper,,navy_game\n",
+        )
+        .unwrap();
+        assert_eq!(students.len(), 2);
+        assert_eq!(students[0].email.as_deref(), Some("ada@example.com"));
+        assert_eq!(students[1].project_name(), "navy_game");
+    }
+
+    #[test]
+    fn toml_rosters_use_students_tables() {
+        let students = parse_roster(
+            Path::new("roster.toml"),
+            "[[students]]\nname = \"Ada Lovelace\"\n",
+        )
+        .unwrap();
+        assert_eq!(students[0].project_name(), "ada_lovelace");
+    }
+}
@@ -0,0 +1,81 @@
+//! `bevy clean`: remove build output and the caches around it.
+//!
+//! By default everything project-local goes: cargo's `target/`, the staged
+//! `dist/` artifacts, and the `.bevy/` processed-asset caches. The CLI's own
+//! per-user cache of registries and installed templates is shared between
+//! projects and only removed on request. Per-category flags narrow the run
+//! to just the named categories.
+
+use std::path::{Path, PathBuf};
+
+use clap::Args;
+
+use crate::i18n::localize;
+use crate::{fs_util, output};
+
+#[derive(Args)]
+pub struct CleanArgs {
+    /// Project directory; defaults to the nearest project root
+    #[arg(long)]
+    pub project: Option<PathBuf>,
+
+    /// Remove only cargo's `target/` directory
+    #[arg(long)]
+    pub target: bool,
+
+    /// Remove only the `dist/` output directory
+    #[arg(long)]
+    pub dist: bool,
+
+    /// Remove only the processed-asset caches under `.bevy/`
+    #[arg(long)]
+    pub assets: bool,
+
+    /// Also remove the CLI's per-user registry and template caches
+    #[arg(long)]
+    pub caches: bool,
+}
+
+pub fn run(args: CleanArgs) -> anyhow::Result<()> {
+    let project = args
+        .project
+        .clone()
+        .unwrap_or_else(|| crate::project::locate(Path::new(".")));
+    anyhow::ensure!(
+        project.join("Cargo.toml").is_file(),
+        "{} does not contain a Cargo.toml",
+        project.display()
+    );
+    // No category flag means the project-local default set; any flag means
+    // exactly the named categories.
+    let everything = !args.target && !args.dist && !args.assets && !args.caches;
+    let mut freed = 0u64;
+    if everything || args.target {
+        freed += remove(&project.join("target"))?;
+    }
+    if everything || args.dist {
+        freed += remove(&project.join("dist"))?;
+    }
+    if everything || args.assets {
+        freed += remove(&project.join(".bevy"))?;
+    }
+    if args.caches {
+        freed += remove(&crate::registry::cache_dir()?)?;
+    }
+    output::ok(&localize!("clean-done", freed = fs_util::human_size(freed)));
+    Ok(())
+}
+
+/// Removes a directory if it exists and reports the bytes it held.
+fn remove(dir: &Path) -> anyhow::Result<u64> {
+    if !dir.is_dir() {
+        return Ok(0);
+    }
+    let size = fs_util::dir_size(dir);
+    std::fs::remove_dir_all(dir)?;
+    println!(
+        "{}",
+        localize!("clean-removed", path = dir.display().to_string())
+    );
+    Ok(size)
+}
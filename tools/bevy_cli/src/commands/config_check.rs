@@ -0,0 +1,49 @@
+use std::path::PathBuf;
+
+use clap::Args;
+
+use crate::envfile;
+use crate::i18n::localize;
+use crate::output;
+
+#[derive(Args)]
+pub struct ConfigCheckArgs {
+    /// Project directory containing `.env` and `.env.example`
+    #[arg(long, default_value = ".")]
+    pub path: PathBuf,
+}
+
+/// Validates a local `.env` against the committed `.env.example`: every key
+/// the example declares must be present, and keys the example does not know
+/// about are reported as likely typos.
+pub fn run(args: ConfigCheckArgs) -> anyhow::Result<()> {
+    let example = envfile::load(&args.path.join(".env.example"))?;
+    let local = envfile::load(&args.path.join(".env"))?;
+
+    let mut missing: Vec<&str> = example
+        .iter()
+        .map(|(key, _)| key.as_str())
+        .filter(|key| !local.iter().any(|(local_key, _)| local_key == key))
+        .collect();
+    let mut unexpected: Vec<&str> = local
+        .iter()
+        .map(|(key, _)| key.as_str())
+        .filter(|key| !example.iter().any(|(example_key, _)| example_key == key))
+        .collect();
+
+    output::sort_localized(&mut missing, |key| key);
+    output::sort_localized(&mut unexpected, |key| key);
+    for key in &unexpected {
+        output::warn(&localize!("env-undeclared", key = key));
+    }
+    if missing.is_empty() {
+        output::ok(&localize!("env-in-sync"));
+        Ok(())
+    } else {
+        anyhow::bail!(localize!(
+            "env-missing-keys",
+            count = missing.len(),
+            keys = missing.join(", ")
+        ));
+    }
+}
@@ -0,0 +1,387 @@
+//! `bevy deploy`: push packaged builds to distribution services.
+//!
+//! `deploy itch` drives itch.io's official `butler` tool: every platform
+//! staged by `bevy package` is pushed to the channel `Bevy.toml` maps it
+//! to. Credentials stay out of the config — butler reads `BUTLER_API_KEY`
+//! from the environment, which is also how its own CI documentation
+//! recommends authenticating. `deploy pages` publishes the `dist/web`
+//! bundle as a single-commit `gh-pages` branch through [`crate::vcs`].
+
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+use clap::{Args, Subcommand};
+use serde::Deserialize;
+
+use crate::i18n::localize;
+use crate::output;
+use crate::subprocess::Subprocess;
+use crate::vcs::Vcs as _;
+
+#[derive(Args)]
+pub struct DeployArgs {
+    #[command(subcommand)]
+    pub command: DeployCommand,
+}
+
+#[derive(Subcommand)]
+pub enum DeployCommand {
+    /// Push packaged builds to itch.io channels using butler
+    Itch(ItchArgs),
+    /// Publish the web build to GitHub Pages
+    Pages(PagesArgs),
+}
+
+#[derive(Args)]
+pub struct ItchArgs {
+    /// Project directory; defaults to the nearest project root
+    #[arg(long)]
+    pub project: Option<PathBuf>,
+
+    /// Print the butler invocations without running them
+    #[arg(long)]
+    pub dry_run: bool,
+}
+
+#[derive(Args)]
+pub struct PagesArgs {
+    /// Project directory; defaults to the nearest project root
+    #[arg(long)]
+    pub project: Option<PathBuf>,
+
+    /// Remote to push the pages branch to
+    #[arg(long, default_value = "origin")]
+    pub remote: String,
+}
+
+/// The `[deploy.itch]` section of `Bevy.toml`.
+#[derive(Debug, Default, Deserialize)]
+struct ItchSection {
+    /// The itch.io target, `user/game`.
+    #[serde(default)]
+    target: Option<String>,
+    /// Channel name per platform; the platform name itself by default.
+    #[serde(default)]
+    channels: std::collections::BTreeMap<String, String>,
+}
+
+/// The `[deploy.pages]` section of `Bevy.toml`.
+#[derive(Debug, Default, Deserialize)]
+struct PagesSection {
+    /// Branch GitHub Pages serves from; `gh-pages` by default.
+    #[serde(default)]
+    branch: Option<String>,
+    /// Custom domain to write into a `CNAME` file.
+    #[serde(default)]
+    cname: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct DeploySection {
+    #[serde(default)]
+    itch: ItchSection,
+    #[serde(default)]
+    pages: PagesSection,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct ProjectConfig {
+    #[serde(default)]
+    deploy: DeploySection,
+}
+
+pub fn run(args: DeployArgs) -> anyhow::Result<()> {
+    match args.command {
+        DeployCommand::Itch(args) => itch(args),
+        DeployCommand::Pages(args) => pages(args),
+    }
+}
+
+fn itch(args: ItchArgs) -> anyhow::Result<()> {
+    let project = args
+        .project
+        .clone()
+        .unwrap_or_else(|| crate::project::locate(Path::new(".")));
+    anyhow::ensure!(
+        project.join("Cargo.toml").is_file(),
+        "{} does not contain a Cargo.toml",
+        project.display()
+    );
+    let config: ProjectConfig = load_config(&project)?;
+    let target = config.deploy.itch.target.clone().with_context(|| {
+        format!(
+            "no itch.io target configured; add `target = \"user/game\"` under [deploy.itch] in {}",
+            crate::project::MANIFEST
+        )
+    })?;
+    let crate_name = super::bundle::package_name(&project)?;
+    let staged = staged_builds(&project, &crate_name);
+    anyhow::ensure!(
+        !staged.is_empty(),
+        "nothing to deploy; run `bevy package` first"
+    );
+    if !args.dry_run && std::env::var_os("BUTLER_API_KEY").is_none() {
+        output::warn(&localize!("deploy-no-key"));
+    }
+
+    let version = package_version(&project);
+    for (platform, stage) in staged {
+        let channel = config
+            .deploy
+            .itch
+            .channels
+            .get(&platform)
+            .cloned()
+            .unwrap_or_else(|| platform.clone());
+        let butler_args = push_args(&stage, &target, &channel, version.as_deref());
+        if args.dry_run {
+            println!("butler {}", butler_args.join(" "));
+            continue;
+        }
+        Subprocess::new("butler")
+            .args(butler_args.iter().cloned())
+            .run()?;
+        println!(
+            "{}",
+            localize!(
+                "deploy-pushed",
+                platform = platform,
+                target = target,
+                channel = channel
+            )
+        );
+    }
+    Ok(())
+}
+
+fn pages(args: PagesArgs) -> anyhow::Result<()> {
+    let project = args
+        .project
+        .clone()
+        .unwrap_or_else(|| crate::project::locate(Path::new(".")));
+    anyhow::ensure!(
+        project.join("Cargo.toml").is_file(),
+        "{} does not contain a Cargo.toml",
+        project.display()
+    );
+    let web = project.join("dist").join("web");
+    anyhow::ensure!(
+        web.join("index.html").is_file(),
+        "no web build; run `bevy build --platform web` first"
+    );
+    let config: ProjectConfig = load_config(&project)?;
+    let branch = config
+        .deploy
+        .pages
+        .branch
+        .clone()
+        .unwrap_or_else(|| "gh-pages".to_string());
+    let url = crate::vcs::ShellGit::default()
+        .remote_url(&project, &args.remote)
+        .with_context(|| {
+            format!(
+                "no `{}` remote; is this project a git repository?",
+                args.remote
+            )
+        })?;
+
+    let stage = project.join("dist").join("pages");
+    publish_pages(
+        &crate::vcs::ShellGit::default(),
+        &web,
+        &stage,
+        &url,
+        &branch,
+        config.deploy.pages.cname.as_deref(),
+    )?;
+    output::ok(&localize!("deploy-pages-done", branch = branch, url = url));
+    Ok(())
+}
+
+/// Copies the web bundle into a throwaway single-commit repository and
+/// force-pushes it as the pages branch, so published history never grows.
+fn publish_pages(
+    vcs: &dyn crate::vcs::Vcs,
+    web: &Path,
+    stage: &Path,
+    url: &str,
+    branch: &str,
+    cname: Option<&str>,
+) -> anyhow::Result<()> {
+    if stage.exists() {
+        std::fs::remove_dir_all(stage)?;
+    }
+    crate::fs_util::copy_dir(web, stage)?;
+    // GitHub Pages runs Jekyll by default, which drops underscore-prefixed
+    // files such as wasm-bindgen's snippets directory.
+    std::fs::write(stage.join(".nojekyll"), "")?;
+    if let Some(domain) = cname {
+        std::fs::write(stage.join("CNAME"), format!("{domain}\n"))?;
+    }
+    vcs.init(stage)?;
+    vcs.commit_all(stage, "deploy web build")?;
+    vcs.push(stage, url, &format!("HEAD:refs/heads/{branch}"))
+}
+
+/// The staged package directories under `dist/package/`, keyed by the
+/// platform suffix of their `<crate>-<platform>` name.
+fn staged_builds(project: &Path, crate_name: &str) -> Vec<(String, PathBuf)> {
+    let Ok(entries) = std::fs::read_dir(project.join("dist").join("package")) else {
+        return Vec::new();
+    };
+    let prefix = format!("{crate_name}-");
+    let mut staged: Vec<(String, PathBuf)> = entries
+        .flatten()
+        .filter(|entry| entry.path().is_dir())
+        .filter_map(|entry| {
+            let name = entry.file_name().to_string_lossy().into_owned();
+            name.strip_prefix(&prefix)
+                .map(|platform| (platform.to_string(), entry.path()))
+        })
+        .collect();
+    staged.sort();
+    staged
+}
+
+/// The butler invocation for one push; separated for testing.
+fn push_args(stage: &Path, target: &str, channel: &str, version: Option<&str>) -> Vec<String> {
+    let mut butler = vec![
+        "push".to_string(),
+        stage.to_string_lossy().into_owned(),
+        format!("{target}:{channel}"),
+    ];
+    if let Some(version) = version {
+        butler.push("--userversion".to_string());
+        butler.push(version.to_string());
+    }
+    butler
+}
+
+/// The crate's `package.version`, for itch.io's version display.
+fn package_version(project: &Path) -> Option<String> {
+    let manifest = std::fs::read_to_string(project.join("Cargo.toml")).ok()?;
+    let table: toml::Table = manifest.parse().ok()?;
+    table
+        .get("package")?
+        .get("version")?
+        .as_str()
+        .map(str::to_string)
+}
+
+fn load_config(project: &Path) -> anyhow::Result<ProjectConfig> {
+    let manifest_path = project.join(crate::project::MANIFEST);
+    if !manifest_path.is_file() {
+        return Ok(ProjectConfig::default());
+    }
+    toml::from_str(&std::fs::read_to_string(&manifest_path)?)
+        .with_context(|| format!("failed to parse {}", manifest_path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn push_invocations_name_target_channel_and_version() {
+        assert_eq!(
+            push_args(
+                Path::new("dist/package/game-linux"),
+                "me/game",
+                "linux",
+                Some("1.2.0")
+            ),
+            vec![
+                "push",
+                "dist/package/game-linux",
+                "me/game:linux",
+                "--userversion",
+                "1.2.0"
+            ]
+        );
+    }
+
+    #[test]
+    fn pages_publishing_stages_nojekyll_cname_and_one_forced_push() {
+        use std::cell::RefCell;
+
+        struct RecordingVcs {
+            calls: RefCell<Vec<String>>,
+        }
+        impl crate::vcs::Vcs for RecordingVcs {
+            fn clone_repo(&self, _: &str, _: Option<&str>, _: &Path) -> anyhow::Result<()> {
+                unreachable!()
+            }
+            fn update(&self, _: &Path) -> anyhow::Result<()> {
+                unreachable!()
+            }
+            fn checkout(&self, _: &Path, _: &str) -> anyhow::Result<()> {
+                unreachable!()
+            }
+            fn init(&self, _: &Path) -> anyhow::Result<()> {
+                self.calls.borrow_mut().push("init".to_string());
+                Ok(())
+            }
+            fn add_remote(&self, _: &Path, _: &str, _: &str) -> anyhow::Result<()> {
+                unreachable!()
+            }
+            fn commit_all(&self, _: &Path, message: &str) -> anyhow::Result<()> {
+                self.calls.borrow_mut().push(format!("commit {message}"));
+                Ok(())
+            }
+            fn remote_url(&self, _: &Path, _: &str) -> anyhow::Result<String> {
+                unreachable!()
+            }
+            fn push(&self, _: &Path, url: &str, refspec: &str) -> anyhow::Result<()> {
+                self.calls
+                    .borrow_mut()
+                    .push(format!("push {url} {refspec}"));
+                Ok(())
+            }
+            fn files(&self, _: &Path) -> anyhow::Result<Vec<String>> {
+                Ok(Vec::new())
+            }
+        }
+
+        let root = std::env::temp_dir().join(format!("bevy_cli_pages_{}", std::process::id()));
+        let web = root.join("web");
+        std::fs::create_dir_all(&web).unwrap();
+        std::fs::write(web.join("index.html"), "<html></html>").unwrap();
+        let vcs = RecordingVcs {
+            calls: RefCell::new(Vec::new()),
+        };
+        let stage = root.join("pages");
+        publish_pages(
+            &vcs,
+            &web,
+            &stage,
+            "https://example.com/g.git",
+            "gh-pages",
+            Some("play.example.com"),
+        )
+        .unwrap();
+        assert!(stage.join(".nojekyll").is_file());
+        assert_eq!(
+            std::fs::read_to_string(stage.join("CNAME")).unwrap(),
+            "play.example.com\n"
+        );
+        assert_eq!(
+            *vcs.calls.borrow(),
+            vec![
+                "init",
+                "commit deploy web build",
+                "push https://example.com/g.git HEAD:refs/heads/gh-pages"
+            ]
+        );
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn itch_sections_parse_with_channel_overrides() {
+        let config: ProjectConfig = toml::from_str(
+            "[deploy.itch]\ntarget = \"me/game\"\n[deploy.itch.channels]\nweb = \"html5\"\n",
+        )
+        .unwrap();
+        assert_eq!(config.deploy.itch.target.as_deref(), Some("me/game"));
+        assert_eq!(config.deploy.itch.channels["web"], "html5");
+    }
+}
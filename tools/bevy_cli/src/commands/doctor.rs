@@ -0,0 +1,139 @@
+//! `bevy doctor`: diagnose the build environment.
+//!
+//! Checks for the things that most commonly break Bevy builds — missing
+//! Linux system libraries, no fast linker, an absent wasm target, no
+//! Vulkan loader, no Android NDK — and prints an actionable fix for each.
+//! Everything is a warning; `doctor` never fails the shell, it informs.
+
+use clap::Args;
+
+use crate::i18n::localize;
+use crate::output;
+use crate::subprocess::Subprocess;
+
+#[derive(Args)]
+pub struct DoctorArgs {}
+
+/// Linux system libraries Bevy links against, as pkg-config names.
+const LINUX_LIBS: &[(&str, &str)] = &[
+    ("alsa", "libasound2-dev / alsa-lib-devel"),
+    ("libudev", "libudev-dev / systemd-devel"),
+];
+
+/// Linkers that keep incremental Bevy builds fast, in preference order.
+const FAST_LINKERS: &[&str] = &["mold", "lld", "ld.lld"];
+
+pub fn run(_args: DoctorArgs) -> anyhow::Result<()> {
+    let mut problems = 0usize;
+
+    if cfg!(target_os = "linux") {
+        for (lib, packages) in LINUX_LIBS {
+            match pkg_config_has(lib) {
+                Some(true) => output::ok(&localize!("doctor-lib-ok", name = *lib)),
+                Some(false) => {
+                    output::warn(&localize!(
+                        "doctor-missing-lib",
+                        name = *lib,
+                        packages = *packages
+                    ));
+                    problems += 1;
+                }
+                // No pkg-config; nothing meaningful to report.
+                None => {}
+            }
+        }
+        if !has_vulkan_loader() {
+            output::warn(&localize!("doctor-no-vulkan"));
+            problems += 1;
+        }
+    }
+
+    match FAST_LINKERS.iter().find(|linker| on_path(linker)) {
+        Some(linker) => output::ok(&localize!("doctor-linker-ok", name = *linker)),
+        None => {
+            output::warn(&localize!("doctor-no-linker"));
+            problems += 1;
+        }
+    }
+
+    if let Ok(installed) = Subprocess::new("rustup")
+        .args(["target", "list", "--installed"])
+        .capture()
+    {
+        if has_target(&installed, "wasm32-unknown-unknown") {
+            output::ok(&localize!("doctor-wasm-ok"));
+        } else {
+            output::warn(&localize!("doctor-no-wasm-target"));
+            problems += 1;
+        }
+    }
+
+    if std::env::var_os("ANDROID_NDK_ROOT").is_none()
+        && std::env::var_os("ANDROID_NDK_HOME").is_none()
+    {
+        // Only relevant for Android work; informative, not counted.
+        println!("{}", localize!("doctor-no-ndk"));
+    }
+
+    if problems == 0 {
+        output::ok(&localize!("doctor-clean"));
+    } else {
+        println!("{}", localize!("doctor-problems", count = problems));
+    }
+    Ok(())
+}
+
+/// Whether pkg-config knows `lib`; `None` when pkg-config itself is absent.
+fn pkg_config_has(lib: &str) -> Option<bool> {
+    if !on_path("pkg-config") {
+        return None;
+    }
+    Some(
+        Subprocess::new("pkg-config")
+            .arg("--exists")
+            .arg(lib)
+            .run()
+            .is_ok(),
+    )
+}
+
+/// Whether the dynamic linker knows a Vulkan loader.
+fn has_vulkan_loader() -> bool {
+    Subprocess::new("ldconfig")
+        .arg("-p")
+        .capture()
+        .map(|libraries| libraries.contains("libvulkan.so"))
+        .unwrap_or(true)
+}
+
+/// Whether `program` resolves through `PATH`.
+pub(crate) fn on_path(program: &str) -> bool {
+    let Some(path) = std::env::var_os("PATH") else {
+        return false;
+    };
+    std::env::split_paths(&path).any(|dir| dir.join(program).is_file())
+}
+
+/// Whether `rustup target list --installed` output includes `target`.
+pub(crate) fn has_target(installed: &str, target: &str) -> bool {
+    installed.lines().any(|line| line.trim() == target)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn installed_target_lists_match_whole_lines() {
+        let installed = "wasm32-unknown-unknown\nx86_64-unknown-linux-gnu\n";
+        assert!(has_target(installed, "wasm32-unknown-unknown"));
+        assert!(!has_target(installed, "wasm32-unknown"));
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn path_lookup_finds_the_shell() {
+        assert!(on_path("sh"));
+        assert!(!on_path("definitely-not-a-real-program"));
+    }
+}
@@ -0,0 +1,99 @@
+use std::path::{Path, PathBuf};
+
+use clap::{Args, Subcommand};
+
+use crate::fs_util;
+use crate::i18n::localize;
+
+#[derive(Args)]
+pub struct EnvArgs {
+    #[command(subcommand)]
+    pub command: EnvCommand,
+}
+
+#[derive(Subcommand)]
+pub enum EnvCommand {
+    /// Scaffold `.env.example`, a `.env` stub, and a typed config module
+    File {
+        /// Project directory to scaffold into
+        #[arg(long, default_value = ".")]
+        path: PathBuf,
+    },
+}
+
+pub fn run(args: EnvArgs) -> anyhow::Result<()> {
+    match args.command {
+        EnvCommand::File { path } => scaffold_env(&path),
+    }
+}
+
+/// Writes the runtime-configuration scaffold into an existing project:
+/// a committed `.env.example`, a private `.env` stub copied from it, the
+/// figment-based `src/runtime_config.rs` loader, and `.gitignore` entries
+/// keeping `.env` out of version control.
+fn scaffold_env(project: &Path) -> anyhow::Result<()> {
+    anyhow::ensure!(
+        project.join("Cargo.toml").exists(),
+        "{} does not look like a project (no Cargo.toml)",
+        project.display()
+    );
+    let example = include_str!("../../templates/scaffold/env.example");
+    write_if_absent(&project.join(".env.example"), example, false)?;
+    write_if_absent(&project.join(".env"), example, true)?;
+    write_if_absent(
+        &project.join("src/runtime_config.rs"),
+        include_str!("../../templates/scaffold/runtime_config.rs"),
+        false,
+    )?;
+    ensure_gitignore_entry(&project.join(".gitignore"), ".env")?;
+    println!(
+        "Scaffolded runtime config in {}; add the dependencies listed in src/runtime_config.rs",
+        project.display()
+    );
+    Ok(())
+}
+
+fn write_if_absent(path: &Path, contents: &str, sensitive: bool) -> anyhow::Result<()> {
+    if path.exists() {
+        println!("{}", localize!("keeping-existing", path = path.display()));
+        return Ok(());
+    }
+    fs_util::write_file(path, contents.as_bytes(), sensitive)
+}
+
+/// Appends `entry` to a .gitignore, creating the file if needed; no-op when
+/// an identical line is already present.
+pub fn ensure_gitignore_entry(gitignore: &Path, entry: &str) -> anyhow::Result<()> {
+    let existing = match std::fs::read_to_string(gitignore) {
+        Ok(contents) => contents,
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => String::new(),
+        Err(error) => return Err(error.into()),
+    };
+    if existing.lines().any(|line| line.trim() == entry) {
+        return Ok(());
+    }
+    let mut updated = existing;
+    if !updated.is_empty() && !updated.ends_with('\n') {
+        updated.push('\n');
+    }
+    updated.push_str(entry);
+    updated.push('\n');
+    fs_util::write_file(gitignore, updated.as_bytes(), false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gitignore_entry_is_added_once() {
+        let dir = std::env::temp_dir().join("bevy_cli_env_test");
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join(".gitignore");
+        let _ = std::fs::remove_file(&path);
+        ensure_gitignore_entry(&path, ".env").unwrap();
+        ensure_gitignore_entry(&path, ".env").unwrap();
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), ".env\n");
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,316 @@
+//! Code and asset generators that do not create whole projects.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+use clap::{Args, Subcommand};
+
+use crate::fs_util;
+use crate::i18n::localize;
+use crate::output;
+
+#[derive(Args)]
+pub struct GenerateArgs {
+    #[command(subcommand)]
+    pub command: GenerateCommand,
+}
+
+#[derive(Subcommand)]
+pub enum GenerateCommand {
+    /// Turn a base palette into a UI theme: a Rust constants module and a
+    /// RON theme asset, with contrast-ratio warnings
+    Theme {
+        /// The palette as a comma-separated hex list, e.g.
+        /// `--palette "#1a1c2c,#5d275d,#ef7d57,#f4f4f4"`
+        #[arg(
+            long,
+            value_delimiter = ',',
+            value_name = "HEX",
+            conflicts_with = "image"
+        )]
+        palette: Vec<String>,
+
+        /// Extract the palette from an image instead (most frequent colors
+        /// of a PNG)
+        #[arg(long, value_name = "PNG")]
+        image: Option<PathBuf>,
+
+        /// Where the Rust constants module is written
+        #[arg(long, default_value = "src/theme.rs")]
+        rust_out: PathBuf,
+
+        /// Where the RON theme asset is written
+        #[arg(long, default_value = "assets/theme.ron")]
+        ron_out: PathBuf,
+    },
+}
+
+/// An sRGB color with 8-bit channels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+/// Role names assigned to the palette, ordered dark to light: the darkest
+/// color becomes the background, the lightest the text color.
+const ROLES: &[&str] = &[
+    "background",
+    "surface",
+    "primary",
+    "secondary",
+    "accent",
+    "text",
+];
+
+/// The most colors an image-derived palette keeps.
+const MAX_PALETTE: usize = 8;
+
+pub fn run(args: GenerateArgs) -> anyhow::Result<()> {
+    match args.command {
+        GenerateCommand::Theme {
+            palette,
+            image,
+            rust_out,
+            ron_out,
+        } => theme(&palette, image.as_deref(), &rust_out, &ron_out),
+    }
+}
+
+fn theme(
+    palette: &[String],
+    image: Option<&Path>,
+    rust_out: &Path,
+    ron_out: &Path,
+) -> anyhow::Result<()> {
+    let mut colors = match image {
+        Some(image) => image_palette(image)?,
+        None => palette
+            .iter()
+            .map(|hex| parse_hex(hex))
+            .collect::<anyhow::Result<Vec<_>>>()?,
+    };
+    anyhow::ensure!(
+        colors.len() >= 2,
+        "a theme needs at least two colors (got {})",
+        colors.len()
+    );
+    // Dark to light, so the role assignment below is stable regardless of
+    // the order the palette was given in.
+    colors.sort_by(|a, b| luminance(*a).total_cmp(&luminance(*b)));
+
+    let roles: Vec<(&str, Rgb)> = assign_roles(&colors);
+    check_contrast(&roles);
+
+    write_rust_module(rust_out, &roles, &colors)?;
+    write_ron_asset(ron_out, &roles, &colors)?;
+    println!(
+        "{}",
+        localize!(
+            "theme-written",
+            rust = rust_out.display(),
+            ron = ron_out.display()
+        )
+    );
+    Ok(())
+}
+
+/// Pairs each color with a role name. With fewer colors than roles the
+/// extremes keep their meaning (background stays darkest, text lightest) and
+/// the middle roles share the middle colors.
+fn assign_roles(colors: &[Rgb]) -> Vec<(&'static str, Rgb)> {
+    ROLES
+        .iter()
+        .enumerate()
+        .map(|(index, role)| {
+            let color = colors[index * (colors.len() - 1) / (ROLES.len() - 1)];
+            (*role, color)
+        })
+        .collect()
+}
+
+/// Warns about role pairs below the WCAG thresholds: 4.5 for text on
+/// background, 3.0 for interactive colors on background.
+fn check_contrast(roles: &[(&str, Rgb)]) {
+    let background = roles[0].1;
+    for (role, color) in &roles[1..] {
+        let ratio = contrast_ratio(background, *color);
+        let minimum = if *role == "text" { 4.5 } else { 3.0 };
+        if ratio < minimum {
+            output::warn(&localize!(
+                "theme-low-contrast",
+                role = role,
+                ratio = format!("{ratio:.1}"),
+                minimum = minimum
+            ));
+        }
+    }
+}
+
+fn write_rust_module(path: &Path, roles: &[(&str, Rgb)], colors: &[Rgb]) -> anyhow::Result<()> {
+    let mut module = String::from(
+        "//! UI theme generated by `bevy generate theme`.\n\nuse bevy::prelude::Color;\n\n",
+    );
+    for (role, color) in roles {
+        module.push_str(&format!(
+            "pub const {}: Color = {};\n",
+            role.to_uppercase(),
+            rust_color(*color)
+        ));
+    }
+    module.push_str(&format!(
+        "\n/// The full palette, dark to light.\npub const PALETTE: [Color; {}] = [\n",
+        colors.len()
+    ));
+    for color in colors {
+        module.push_str(&format!("    {},\n", rust_color(*color)));
+    }
+    module.push_str("];\n");
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    fs_util::write_file(path, module.as_bytes(), false)
+}
+
+fn write_ron_asset(path: &Path, roles: &[(&str, Rgb)], colors: &[Rgb]) -> anyhow::Result<()> {
+    let mut ron = String::from("(\n");
+    for (role, color) in roles {
+        ron.push_str(&format!("    {role}: \"{}\",\n", hex(*color)));
+    }
+    ron.push_str("    palette: [\n");
+    for color in colors {
+        ron.push_str(&format!("        \"{}\",\n", hex(*color)));
+    }
+    ron.push_str("    ],\n)\n");
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    fs_util::write_file(path, ron.as_bytes(), false)
+}
+
+/// Parses `#rgb`, `#rrggbb`, or the same without the `#`.
+pub fn parse_hex(hex: &str) -> anyhow::Result<Rgb> {
+    let digits = hex.trim().trim_start_matches('#');
+    let channel = |digits: &str| u8::from_str_radix(digits, 16);
+    match digits.len() {
+        3 => {
+            let mut channels = digits.chars().map(|c| channel(&format!("{c}{c}")));
+            Ok(Rgb(
+                channels.next().unwrap()?,
+                channels.next().unwrap()?,
+                channels.next().unwrap()?,
+            ))
+        }
+        6 => Ok(Rgb(
+            channel(&digits[0..2])?,
+            channel(&digits[2..4])?,
+            channel(&digits[4..6])?,
+        )),
+        _ => anyhow::bail!("`{hex}` is not a #rgb or #rrggbb color"),
+    }
+}
+
+/// Extracts the most frequent opaque colors of a PNG.
+fn image_palette(path: &Path) -> anyhow::Result<Vec<Rgb>> {
+    let decoder = png::Decoder::new(
+        std::fs::File::open(path).with_context(|| format!("failed to open {}", path.display()))?,
+    );
+    let mut reader = decoder.read_info()?;
+    let mut buffer = vec![0; reader.output_buffer_size()];
+    let info = reader.next_frame(&mut buffer)?;
+    let bytes = &buffer[..info.buffer_size()];
+
+    let mut counts: HashMap<Rgb, usize> = HashMap::new();
+    let stride = match info.color_type {
+        png::ColorType::Rgb => 3,
+        png::ColorType::Rgba => 4,
+        other => anyhow::bail!(
+            "{} has color type {other:?}; export it as RGB or RGBA",
+            path.display()
+        ),
+    };
+    for pixel in bytes.chunks_exact(stride) {
+        if stride == 4 && pixel[3] < 128 {
+            continue;
+        }
+        *counts.entry(Rgb(pixel[0], pixel[1], pixel[2])).or_default() += 1;
+    }
+    anyhow::ensure!(
+        !counts.is_empty(),
+        "{} has no opaque pixels",
+        path.display()
+    );
+    let mut colors: Vec<(Rgb, usize)> = counts.into_iter().collect();
+    colors.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| hex(a.0).cmp(&hex(b.0))));
+    Ok(colors
+        .into_iter()
+        .take(MAX_PALETTE)
+        .map(|(color, _)| color)
+        .collect())
+}
+
+fn hex(color: Rgb) -> String {
+    format!("#{:02x}{:02x}{:02x}", color.0, color.1, color.2)
+}
+
+fn rust_color(color: Rgb) -> String {
+    format!(
+        "Color::rgb({:.3}, {:.3}, {:.3})",
+        color.0 as f32 / 255.0,
+        color.1 as f32 / 255.0,
+        color.2 as f32 / 255.0
+    )
+}
+
+/// WCAG relative luminance of an sRGB color.
+fn luminance(color: Rgb) -> f64 {
+    let linear = |channel: u8| {
+        let channel = channel as f64 / 255.0;
+        if channel <= 0.03928 {
+            channel / 12.92
+        } else {
+            ((channel + 0.055) / 1.055).powf(2.4)
+        }
+    };
+    0.2126 * linear(color.0) + 0.7152 * linear(color.1) + 0.0722 * linear(color.2)
+}
+
+/// WCAG contrast ratio, 1.0 (identical) to 21.0 (black on white).
+pub fn contrast_ratio(a: Rgb, b: Rgb) -> f64 {
+    let (darker, lighter) = {
+        let (a, b) = (luminance(a), luminance(b));
+        if a < b {
+            (a, b)
+        } else {
+            (b, a)
+        }
+    };
+    (lighter + 0.05) / (darker + 0.05)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hex_colors_parse_in_short_and_long_form() {
+        assert_eq!(parse_hex("#1a2b3c").unwrap(), Rgb(0x1a, 0x2b, 0x3c));
+        assert_eq!(parse_hex("fff").unwrap(), Rgb(255, 255, 255));
+        assert!(parse_hex("#12345").is_err());
+    }
+
+    #[test]
+    fn contrast_ratio_matches_wcag_reference_values() {
+        let ratio = contrast_ratio(Rgb(0, 0, 0), Rgb(255, 255, 255));
+        assert!((ratio - 21.0).abs() < 0.01);
+        assert!(
+            (contrast_ratio(Rgb(128, 128, 128), Rgb(128, 128, 128)) - 1.0).abs() < f64::EPSILON
+        );
+    }
+
+    #[test]
+    fn roles_cover_the_palette_extremes() {
+        let colors = [Rgb(0, 0, 0), Rgb(100, 100, 100), Rgb(255, 255, 255)];
+        let roles = assign_roles(&colors);
+        assert_eq!(roles.first().unwrap(), &("background", Rgb(0, 0, 0)));
+        assert_eq!(roles.last().unwrap(), &("text", Rgb(255, 255, 255)));
+    }
+}
@@ -0,0 +1,73 @@
+use std::io::Write;
+
+use clap::Args;
+
+use crate::history;
+use crate::i18n::localize;
+
+#[derive(Args)]
+pub struct HistoryArgs {}
+
+/// Lists recorded invocations, most recent first, numbered for `bevy redo`.
+pub fn run(_args: HistoryArgs) -> anyhow::Result<()> {
+    let entries = history::load()?;
+    if entries.is_empty() {
+        println!("{}", localize!("history-empty"));
+        return Ok(());
+    }
+    for (index, entry) in entries.iter().enumerate() {
+        println!("{:>3}  {}", index + 1, entry.command_line());
+    }
+    Ok(())
+}
+
+#[derive(Args)]
+pub struct LastArgs {}
+
+/// Shows the most recent recorded invocation.
+pub fn run_last(_args: LastArgs) -> anyhow::Result<()> {
+    match history::load()?.first() {
+        Some(entry) => println!("{}", entry.command_line()),
+        None => println!("{}", localize!("history-empty")),
+    }
+    Ok(())
+}
+
+#[derive(Args)]
+pub struct RedoArgs {
+    /// Which history entry to re-run, as numbered by `bevy history`
+    #[arg(default_value_t = 1)]
+    pub index: usize,
+
+    /// Re-run without asking for confirmation
+    #[arg(long)]
+    pub yes: bool,
+}
+
+/// Re-executes a recorded invocation after confirmation.
+pub fn run_redo(args: RedoArgs) -> anyhow::Result<()> {
+    let entries = history::load()?;
+    let entry = entries
+        .get(args.index.saturating_sub(1))
+        .ok_or_else(|| anyhow::anyhow!(localize!("redo-no-entry", index = args.index)))?;
+    println!("{}", entry.command_line());
+    if !args.yes && !confirm(&localize!("redo-prompt"))? {
+        println!("{}", localize!("redo-cancelled"));
+        return Ok(());
+    }
+    let status = std::process::Command::new(std::env::current_exe()?)
+        .args(&entry.args)
+        .status()?;
+    anyhow::ensure!(status.success(), "re-run exited with {status}");
+    Ok(())
+}
+
+/// Asks a yes/no question on stdin; anything but `y`/`yes` is a no.
+fn confirm(prompt: &str) -> anyhow::Result<bool> {
+    print!("{prompt} [y/N] ");
+    std::io::stdout().flush()?;
+    let mut answer = String::new();
+    std::io::stdin().read_line(&mut answer)?;
+    let answer = answer.trim().to_lowercase();
+    Ok(answer == "y" || answer == "yes")
+}
@@ -0,0 +1,194 @@
+//! `bevy info`: a one-screen report of what the project is.
+//!
+//! Collects the Bevy version and enabled Bevy features from `Cargo.toml`,
+//! the third-party plugin crates in the dependency list, the `[project]`
+//! provenance and targets recorded in `Bevy.toml`, and the workspace
+//! member crates. `--json` emits the same report as a single object for
+//! tooling.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+use clap::Args;
+use serde::Serialize;
+
+use crate::i18n::localize;
+
+#[derive(Args)]
+pub struct InfoArgs {
+    /// Project directory; defaults to the nearest project root
+    #[arg(long)]
+    pub project: Option<PathBuf>,
+
+    /// Emit the report as JSON instead of human-readable lines
+    #[arg(long)]
+    pub json: bool,
+}
+
+/// Everything `bevy info` reports; the JSON schema for `--json`.
+#[derive(Serialize)]
+struct Report {
+    bevy_version: Option<String>,
+    bevy_features: Vec<String>,
+    plugin_crates: Vec<String>,
+    template: Option<String>,
+    targets: Vec<String>,
+    workspace_members: Vec<String>,
+}
+
+pub fn run(args: InfoArgs) -> anyhow::Result<()> {
+    let project = args
+        .project
+        .clone()
+        .unwrap_or_else(|| crate::project::locate(Path::new(".")));
+    anyhow::ensure!(
+        project.join("Cargo.toml").is_file(),
+        "{} does not contain a Cargo.toml",
+        project.display()
+    );
+    let manifest = std::fs::read_to_string(project.join("Cargo.toml"))?;
+    let section = crate::project::load(&project)?;
+    let report = Report {
+        bevy_version: super::migrate::detected_bevy_minor(&project),
+        bevy_features: bevy_features(&manifest),
+        plugin_crates: plugin_crates(&manifest),
+        template: section
+            .as_ref()
+            .and_then(|section| section.template.clone()),
+        targets: section.map(|section| section.targets).unwrap_or_default(),
+        workspace_members: workspace_members(&project),
+    };
+    if args.json {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&report).context("report serializes")?
+        );
+        return Ok(());
+    }
+    println!(
+        "{}",
+        localize!(
+            "info-bevy",
+            version = report.bevy_version.as_deref().unwrap_or("?"),
+            features = join_or_dash(&report.bevy_features)
+        )
+    );
+    println!(
+        "{}",
+        localize!(
+            "info-plugins",
+            plugins = join_or_dash(&report.plugin_crates)
+        )
+    );
+    if let Some(template) = &report.template {
+        println!("{}", localize!("info-template", template = template));
+    }
+    if !report.targets.is_empty() {
+        println!(
+            "{}",
+            localize!("info-targets", targets = report.targets.join(", "))
+        );
+    }
+    if !report.workspace_members.is_empty() {
+        println!(
+            "{}",
+            localize!(
+                "info-workspace",
+                members = report.workspace_members.join(", ")
+            )
+        );
+    }
+    Ok(())
+}
+
+fn join_or_dash(values: &[String]) -> String {
+    if values.is_empty() {
+        "-".to_string()
+    } else {
+        values.join(", ")
+    }
+}
+
+/// The feature list of the `bevy` dependency, when spelled out.
+fn bevy_features(manifest: &str) -> Vec<String> {
+    let Ok(table) = manifest.parse::<toml::Table>() else {
+        return Vec::new();
+    };
+    table
+        .get("dependencies")
+        .and_then(|dependencies| dependencies.get("bevy"))
+        .and_then(|bevy| bevy.get("features"))
+        .and_then(|features| features.as_array())
+        .map(|features| {
+            features
+                .iter()
+                .filter_map(|feature| feature.as_str().map(str::to_string))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Dependencies that look like Bevy plugins: ecosystem crates following the
+/// `bevy_*` convention, plus everything in the known pin table.
+fn plugin_crates(manifest: &str) -> Vec<String> {
+    let Ok(table) = manifest.parse::<toml::Table>() else {
+        return Vec::new();
+    };
+    let known: Vec<&str> = super::upgrade::ECOSYSTEM
+        .iter()
+        .flat_map(|(_, pins)| pins.iter().map(|(name, _)| *name))
+        .collect();
+    table
+        .get("dependencies")
+        .and_then(|dependencies| dependencies.as_table())
+        .map(|dependencies| {
+            dependencies
+                .keys()
+                .filter(|name| {
+                    *name != "bevy" && (name.starts_with("bevy_") || known.contains(&name.as_str()))
+                })
+                .cloned()
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// The crates under `crates/`, for workspace-layout projects.
+fn workspace_members(project: &Path) -> Vec<String> {
+    let Ok(entries) = std::fs::read_dir(project.join("crates")) else {
+        return Vec::new();
+    };
+    let mut members: Vec<String> = entries
+        .flatten()
+        .filter(|entry| entry.path().join("Cargo.toml").is_file())
+        .map(|entry| entry.file_name().to_string_lossy().into_owned())
+        .collect();
+    members.sort();
+    members
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bevy_features_come_from_the_dependency_table() {
+        let manifest = "[dependencies]\n\
+                        bevy = { version = \"0.12\", features = [\"wayland\", \"mp3\"] }\n";
+        assert_eq!(bevy_features(manifest), vec!["wayland", "mp3"]);
+        assert!(bevy_features("[dependencies]\nbevy = \"0.12\"\n").is_empty());
+    }
+
+    #[test]
+    fn plugin_crates_follow_the_naming_convention_and_pin_table() {
+        let manifest = "[dependencies]\n\
+                        bevy = \"0.12\"\n\
+                        bevy_egui = \"0.23\"\n\
+                        leafwing-input-manager = \"0.11\"\n\
+                        serde = \"1\"\n";
+        assert_eq!(
+            plugin_crates(manifest),
+            vec!["bevy_egui", "leafwing-input-manager"]
+        );
+    }
+}
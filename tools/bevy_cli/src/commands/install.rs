@@ -0,0 +1,58 @@
+use clap::Args;
+
+use crate::config::CliConfig;
+use crate::i18n::localize;
+use crate::{fs_util, output, registry};
+
+#[derive(Args)]
+pub struct InstallArgs {
+    /// Name of the template to install, as shown by `bevy search`
+    pub name: String,
+}
+
+pub fn run(args: InstallArgs) -> anyhow::Result<()> {
+    let config = CliConfig::load()?;
+    output::progress_event(&output::ProgressEvent {
+        phase: "install",
+        percent: 0,
+        file: None,
+        bytes: None,
+    });
+    let found = registry::find(&config.registries, &args.name)?;
+    output::progress_event(&output::ProgressEvent {
+        phase: "install",
+        percent: 50,
+        file: Some(&args.name),
+        bytes: None,
+    });
+    anyhow::ensure!(
+        found.dir.is_dir(),
+        "registry `{}` lists `{}` but {} does not exist",
+        found.registry,
+        args.name,
+        found.dir.display()
+    );
+    let target = registry::installed_templates_dir()?.join(&args.name);
+    // Preflight the copy before removing anything, so a full disk cannot
+    // cost an existing installation.
+    fs_util::ensure_space(&target, fs_util::dir_size(&found.dir))?;
+    if target.exists() {
+        std::fs::remove_dir_all(&target)?;
+    }
+    fs_util::copy_dir(&found.dir, &target)?;
+    output::progress_event(&output::ProgressEvent {
+        phase: "install",
+        percent: 100,
+        file: None,
+        bytes: None,
+    });
+    println!(
+        "{}",
+        localize!(
+            "installed-template",
+            name = args.name,
+            registry = found.registry
+        )
+    );
+    Ok(())
+}
@@ -0,0 +1,237 @@
+//! `bevy migrate`: apply per-release code migration rules.
+//!
+//! Where `upgrade` bumps version requirements, `migrate` rewrites the
+//! project's source for the renames and signature changes each Bevy
+//! release brings. Runs as a diff preview by default; `--apply` writes the
+//! changes. The rules are textual and deliberately conservative — anything
+//! a regex cannot do safely stays a printed note instead of an edit.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+use clap::Args;
+
+use crate::i18n::localize;
+use crate::output;
+
+#[derive(Args)]
+pub struct MigrateArgs {
+    /// Project directory; defaults to the nearest project root
+    #[arg(long)]
+    pub project: Option<PathBuf>,
+
+    /// Bevy minor migrated from; detected from Cargo.toml when omitted
+    #[arg(long)]
+    pub from: Option<String>,
+
+    /// Bevy minor migrated to
+    #[arg(long, default_value = crate::versions::FALLBACK_BEVY_VERSION)]
+    pub to: String,
+
+    /// Write the changes instead of previewing the diff
+    #[arg(long)]
+    pub apply: bool,
+}
+
+/// One textual rewrite: a regex, its replacement, and the change it covers.
+struct Rule {
+    pattern: &'static str,
+    replacement: &'static str,
+    note: &'static str,
+}
+
+/// Migration rules per release step, oldest step first.
+const STEPS: &[(&str, &[Rule])] = &[
+    (
+        "0.10-to-0.11",
+        &[
+            Rule {
+                pattern: r"\.add_system\(",
+                replacement: ".add_systems(Update, ",
+                note: "add_system was replaced by add_systems with an explicit schedule",
+            },
+            Rule {
+                pattern: r"\.add_startup_system\(",
+                replacement: ".add_systems(Startup, ",
+                note: "add_startup_system was replaced by add_systems(Startup, ..)",
+            },
+        ],
+    ),
+    (
+        "0.11-to-0.12",
+        &[
+            Rule {
+                pattern: r"\.add_asset::<",
+                replacement: ".init_asset::<",
+                note: "assets v2 renamed add_asset to init_asset",
+            },
+            Rule {
+                pattern: r"AssetEvent::Created",
+                replacement: "AssetEvent::Added",
+                note: "asset events now carry ids; Created became Added { id }",
+            },
+        ],
+    ),
+];
+
+pub fn run(args: MigrateArgs) -> anyhow::Result<()> {
+    let project = args
+        .project
+        .clone()
+        .unwrap_or_else(|| crate::project::locate(Path::new(".")));
+    anyhow::ensure!(
+        project.join("Cargo.toml").is_file(),
+        "{} does not contain a Cargo.toml",
+        project.display()
+    );
+    let from = match &args.from {
+        Some(from) => from.clone(),
+        None => detected_bevy_minor(&project)
+            .context("could not detect the bevy version; pass --from")?,
+    };
+    let steps = steps_between(&from, &args.to);
+    anyhow::ensure!(
+        !steps.is_empty(),
+        "no migration rules cover {from} -> {}",
+        args.to
+    );
+
+    let mut changed_files = 0usize;
+    for file in rust_sources(&project) {
+        let contents = std::fs::read_to_string(&file)?;
+        let (migrated, notes) = apply_steps(&contents, &steps);
+        if migrated == contents {
+            continue;
+        }
+        changed_files += 1;
+        let shown = file.strip_prefix(&project).unwrap_or(&file);
+        println!("{}", localize!("migrate-file", file = shown.display()));
+        for note in notes {
+            println!("  {note}");
+        }
+        for line in super::templates::line_diff(&contents, &migrated) {
+            println!("  {line}");
+        }
+        if args.apply {
+            std::fs::write(&file, migrated)
+                .with_context(|| format!("failed to write {}", file.display()))?;
+        }
+    }
+    if changed_files == 0 {
+        output::ok(&localize!("migrate-clean", from = from, to = args.to));
+    } else if args.apply {
+        output::ok(&localize!("migrate-applied", count = changed_files));
+    } else {
+        println!("{}", localize!("migrate-preview", count = changed_files));
+    }
+    Ok(())
+}
+
+/// The release steps covering a `from` -> `to` jump, in order.
+fn steps_between(from: &str, to: &str) -> Vec<&'static (&'static str, &'static [Rule])> {
+    STEPS
+        .iter()
+        .filter(|(step, _)| {
+            let (old, new) = step.split_once("-to-").expect("step names are old-to-new");
+            // A step applies when the jump starts at or before its old
+            // release and ends at or after its new one.
+            minor_at_least(old, from).unwrap_or(false) && minor_at_least(to, new).unwrap_or(false)
+        })
+        .collect()
+}
+
+/// Whether `version`'s minor is at least `than` (both `major.minor`).
+fn minor_at_least(version: &str, than: &str) -> Option<bool> {
+    let parse = |minor: &str| {
+        let mut parts = minor.trim_start_matches(['^', '=', '~']).split('.');
+        let major: u32 = parts.next()?.parse().ok()?;
+        let minor: u32 = parts.next().unwrap_or("0").parse().ok()?;
+        Some((major, minor))
+    };
+    Some(parse(version)? >= parse(than)?)
+}
+
+/// Applies every rule of the given steps, collecting the notes of the rules
+/// that actually matched.
+fn apply_steps(contents: &str, steps: &[&(&str, &[Rule])]) -> (String, Vec<String>) {
+    let mut migrated = contents.to_string();
+    let mut notes = Vec::new();
+    for (_, rules) in steps {
+        for rule in *rules {
+            let regex = regex::Regex::new(rule.pattern).expect("migration patterns compile");
+            if regex.is_match(&migrated) {
+                migrated = regex.replace_all(&migrated, rule.replacement).into_owned();
+                notes.push(rule.note.to_string());
+            }
+        }
+    }
+    (migrated, notes)
+}
+
+/// The `major.minor` of the project's `bevy` requirement, if declared.
+pub(crate) fn detected_bevy_minor(project: &Path) -> Option<String> {
+    let manifest = std::fs::read_to_string(project.join("Cargo.toml")).ok()?;
+    let table: toml::Table = manifest.parse().ok()?;
+    let bevy = table.get("dependencies")?.get("bevy")?;
+    let requirement = match bevy {
+        toml::Value::String(version) => version.clone(),
+        other => other.get("version")?.as_str()?.to_string(),
+    };
+    Some(
+        requirement
+            .trim_start_matches(['^', '=', '~'])
+            .split('.')
+            .take(2)
+            .collect::<Vec<_>>()
+            .join("."),
+    )
+}
+
+/// Every Rust source of the project, including workspace crates.
+fn rust_sources(project: &Path) -> Vec<PathBuf> {
+    let mut sources = Vec::new();
+    let mut stack = vec![project.join("src")];
+    if let Ok(entries) = std::fs::read_dir(project.join("crates")) {
+        stack.extend(entries.flatten().map(|entry| entry.path().join("src")));
+    }
+    while let Some(dir) = stack.pop() {
+        let Ok(entries) = std::fs::read_dir(&dir) else {
+            continue;
+        };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.is_dir() {
+                stack.push(path);
+            } else if path.extension().is_some_and(|extension| extension == "rs") {
+                sources.push(path);
+            }
+        }
+    }
+    sources.sort();
+    sources
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn each_release_step_rewrites_its_renames() {
+        let steps = steps_between("0.10", "0.12");
+        assert_eq!(steps.len(), 2);
+        let source = "app.add_startup_system(setup).add_system(movement);\n\
+                      app.add_asset::<Level>();\n";
+        let (migrated, notes) = apply_steps(source, &steps);
+        assert!(migrated.contains("add_systems(Startup, setup)"));
+        assert!(migrated.contains("add_systems(Update, movement)"));
+        assert!(migrated.contains("init_asset::<Level>()"));
+        assert_eq!(notes.len(), 3);
+    }
+
+    #[test]
+    fn steps_outside_the_jump_are_skipped() {
+        assert_eq!(steps_between("0.11", "0.12").len(), 1);
+        assert!(steps_between("0.12", "0.12").is_empty());
+        assert!(steps_between("0.12", "0.11").is_empty());
+    }
+}
@@ -0,0 +1,30 @@
+pub mod add;
+pub mod assets;
+pub mod batch;
+pub mod bench;
+pub mod build;
+pub mod bundle;
+pub mod check;
+pub mod classroom;
+pub mod clean;
+pub mod config_check;
+pub mod deploy;
+pub mod doctor;
+pub mod env;
+pub mod generate;
+pub mod history;
+pub mod info;
+pub mod install;
+pub mod migrate;
+pub mod new;
+pub mod package;
+pub mod profile;
+pub mod remove;
+pub mod run;
+pub mod search;
+pub mod serve;
+pub mod size;
+pub mod templates;
+pub mod test;
+pub mod upgrade;
+pub mod watch;
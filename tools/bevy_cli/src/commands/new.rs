@@ -0,0 +1,658 @@
+use std::path::PathBuf;
+
+use anyhow::Context;
+use clap::{Args, ValueEnum};
+use serde::Deserialize;
+
+use crate::i18n::localize;
+
+use crate::template::manifest::{BinSpec, TemplateManifest};
+use crate::template::render;
+use crate::template::source::{
+    TemplateSource, EMBEDDED_DEFAULT, EMBEDDED_PLUGIN, EMBEDDED_WORKSPACE,
+};
+use crate::template::vars::{self, VarValue};
+
+#[derive(Args)]
+pub struct NewArgs {
+    /// Name of the project to create; also used as the target directory.
+    /// Omitting it on a terminal launches the interactive wizard
+    pub name: Option<String>,
+
+    /// Walk through the project options with prompts instead of flags; the
+    /// default when no name is given on a terminal
+    #[arg(long)]
+    pub interactive: bool,
+
+    /// Re-apply recorded wizard answers from a replay file; values given
+    /// on the command line still win over recorded ones
+    #[arg(long, value_name = "FILE", conflicts_with = "interactive")]
+    pub replay: Option<PathBuf>,
+
+    /// Generate into a protected location (home directory, filesystem
+    /// root, system directories) anyway
+    #[arg(long = "i-know-what-im-doing")]
+    pub i_know_what_im_doing: bool,
+
+    /// Path to a template directory, or the name of an installed template;
+    /// defaults to the built-in template
+    #[arg(long, conflicts_with = "workspace")]
+    pub template: Option<PathBuf>,
+
+    /// Generate a Cargo workspace (game binary crate plus gameplay library
+    /// crate) instead of a single-crate project
+    #[arg(long)]
+    pub workspace: bool,
+
+    /// What kind of project to generate
+    #[arg(long, value_enum, default_value_t = ProjectKind::Game)]
+    pub kind: ProjectKind,
+
+    /// Bootstrap an `assets/` directory with placeholder files and code
+    /// that loads them
+    #[arg(long, conflicts_with = "with_states")]
+    pub with_assets: bool,
+
+    /// Generate a `GameState` enum (Loading/Menu/InGame) with state-scoped
+    /// plugins and transition systems instead of a bare `main.rs`
+    #[arg(long)]
+    pub with_states: bool,
+
+    /// Also scaffold a runnable minimal example under `examples/`
+    #[arg(long)]
+    pub with_examples: bool,
+
+    /// Also scaffold a criterion benchmark harness under `benches/`
+    #[arg(long)]
+    pub with_benches: bool,
+
+    /// Bevy version to generate against: an explicit version requirement, or
+    /// `latest` to resolve the newest release from crates.io
+    #[arg(long, default_value = "latest")]
+    pub bevy_version: String,
+
+    /// Extra Bevy cargo features for the generated project, e.g.
+    /// `--bevy-features wayland,mp3,dynamic_linking`
+    #[arg(long, value_delimiter = ',', value_name = "FEATURE")]
+    pub bevy_features: Vec<String>,
+
+    /// Author name for generated manifests; defaults to git's user.name
+    #[arg(long)]
+    pub author: Option<String>,
+
+    /// Author email for generated manifests; defaults to git's user.email
+    #[arg(long)]
+    pub email: Option<String>,
+
+    /// Directory to create the project in; defaults to `./<name>`
+    #[arg(long)]
+    pub target_dir: Option<PathBuf>,
+
+    /// Pin the Rust toolchain by writing a rust-toolchain.toml, e.g.
+    /// `--toolchain stable` or `--toolchain 1.74.0`
+    #[arg(long, value_name = "CHANNEL")]
+    pub toolchain: Option<String>,
+
+    /// Write a .cargo/config.toml with fast linkers, optimized dependencies,
+    /// and a dynamic-linking dev alias
+    #[arg(long)]
+    pub fast_compiles: bool,
+
+    /// Rust edition for the generated manifests
+    #[arg(long, default_value = "2021")]
+    pub edition: String,
+
+    /// Minimum supported Rust version, written as `rust-version` and tested
+    /// in the generated CI matrix
+    #[arg(long, value_name = "VERSION")]
+    pub msrv: Option<String>,
+
+    /// Also write a basic CI pipeline (format, clippy, test)
+    #[arg(long)]
+    pub ci: bool,
+
+    /// CI provider to generate for; only meaningful with --ci
+    #[arg(long, value_enum, default_value_t, value_name = "PROVIDER")]
+    pub ci_provider: crate::scaffold::ContinuousIntegration,
+
+    #[command(flatten)]
+    pub ci_options: crate::scaffold::CiOptions,
+
+    /// Version control system to initialize in the generated project
+    #[arg(long, value_enum, default_value_t = Vcs::None)]
+    pub vcs: Vcs,
+
+    /// Also generate an `xtask` automation crate with starter dist, assets,
+    /// and ci tasks, runnable as `cargo xtask <task>`
+    #[arg(long)]
+    pub xtask: bool,
+
+    /// Generate extra `[[bin]]` targets sharing a library crate, e.g.
+    /// `--bins game,editor:devtools,server`; features a binary requires
+    /// follow a colon, separated by `+`
+    #[arg(long, value_delimiter = ',', value_name = "NAME[:FEATURES]")]
+    pub bins: Vec<String>,
+
+    /// Prepare the project for an additional deployment target, e.g.
+    /// `--target web` for browser builds via trunk
+    #[arg(long, value_enum)]
+    pub target: Option<TargetPlatform>,
+
+    /// How the generated source tree is organized
+    #[arg(
+        long,
+        value_enum,
+        default_value_t = crate::scaffold::Layout::Flat,
+        conflicts_with_all = ["with_states", "with_assets"]
+    )]
+    pub layout: crate::scaffold::Layout,
+
+    /// Also emit `.editorconfig`, `rustfmt.toml`, and `clippy.toml` with
+    /// Bevy-community defaults
+    #[arg(long)]
+    pub with_editor_config: bool,
+
+    /// Compose the `.gitignore` from sections instead of the template's own
+    /// file, e.g. `--gitignore rust,vscode,os,assets`
+    #[arg(long, value_delimiter = ',', value_enum, value_name = "SECTION")]
+    pub gitignore: Vec<crate::scaffold::GitignoreSection>,
+
+    /// Use the template embedded in this binary even when a refreshed
+    /// official default is available for the chosen Bevy release
+    #[arg(long)]
+    pub builtin_only: bool,
+
+    /// Additional context values injected by wrapping commands (classroom,
+    /// batch); not settable from the command line.
+    #[arg(skip)]
+    pub extra_context: Vec<(String, String)>,
+
+    /// Set a template variable, e.g. `--var use_physics=true` (repeatable)
+    #[arg(long = "var", value_name = "KEY=VALUE")]
+    pub vars: Vec<String>,
+}
+
+pub fn run(mut args: NewArgs) -> anyhow::Result<()> {
+    use std::io::IsTerminal;
+    if let Some(replay) = args.replay.clone() {
+        crate::wizard::apply_replay(&mut args, &replay)?;
+    }
+    let mut record_answers = false;
+    if args.interactive
+        || (args.name.is_none() && args.replay.is_none() && std::io::stdin().is_terminal())
+    {
+        crate::wizard::fill(&mut args)?;
+        record_answers = true;
+    }
+    let name = args
+        .name
+        .clone()
+        .context("a project name is required (or run `bevy new --interactive`)")?;
+    validate_project_name(&name)?;
+    anyhow::ensure!(
+        !(args.workspace && args.kind == ProjectKind::Plugin),
+        "--workspace is not supported for --kind plugin"
+    );
+    anyhow::ensure!(
+        !(args.with_states && args.kind == ProjectKind::Plugin),
+        "--with-states only applies to game projects"
+    );
+    let bevy_version = crate::versions::resolve(&args.bevy_version)?;
+    let source = match &args.template {
+        Some(path) if path.exists() => TemplateSource::Dir(path.clone()),
+        Some(name) => {
+            let installed = crate::registry::installed_templates_dir()?.join(name);
+            anyhow::ensure!(
+                installed.is_dir(),
+                "`{}` is neither a directory nor an installed template (try `bevy install`)",
+                name.display()
+            );
+            TemplateSource::Dir(installed)
+        }
+        None if args.workspace => TemplateSource::Embedded(EMBEDDED_WORKSPACE),
+        None if args.kind == ProjectKind::Plugin => TemplateSource::Embedded(EMBEDDED_PLUGIN),
+        // The default template can be refreshed from the release channel, so
+        // starter-project fixes do not have to wait for a new CLI; the
+        // embedded copy remains the offline (and `--builtin-only`) fallback.
+        None if !args.builtin_only => match crate::registry::official_default(&bevy_version)? {
+            Some(dir) => TemplateSource::Dir(dir),
+            None => TemplateSource::Embedded(EMBEDDED_DEFAULT),
+        },
+        None => TemplateSource::Embedded(EMBEDDED_DEFAULT),
+    };
+    let manifest = match source.manifest_contents()? {
+        Some(contents) => TemplateManifest::parse(&contents)?,
+        None => TemplateManifest::default(),
+    };
+
+    // Validate every variable before any file is written.
+    let mut values = vars::resolve(&manifest.vars, &args.vars)?;
+    values.insert("project_name".to_string(), VarValue::String(name.clone()));
+    values.insert(
+        "plugin_struct".to_string(),
+        VarValue::String(format!("{}Plugin", pascal_case(&name))),
+    );
+    values.insert(
+        "bevy_version".to_string(),
+        VarValue::String(bevy_version.clone()),
+    );
+    let mut features = args.bevy_features.clone();
+    features.dedup();
+    values.insert("bevy_features".to_string(), VarValue::List(features));
+    let author = args.author.clone().or_else(|| git_config("user.name"));
+    let email = args.email.clone().or_else(|| git_config("user.email"));
+    values.insert(
+        "author".to_string(),
+        VarValue::String(author.clone().unwrap_or_default()),
+    );
+    values.insert(
+        "email".to_string(),
+        VarValue::String(email.clone().unwrap_or_default()),
+    );
+    values.insert(
+        "authors".to_string(),
+        VarValue::String(match (author, email) {
+            (Some(author), Some(email)) => format!("{author} <{email}>"),
+            (Some(author), None) => author,
+            (None, _) => String::new(),
+        }),
+    );
+
+    for (key, value) in &args.extra_context {
+        values.insert(key.clone(), VarValue::String(value.clone()));
+    }
+
+    let target_dir = args
+        .target_dir
+        .clone()
+        .unwrap_or_else(|| PathBuf::from(&name));
+    if let Some(template_name) = &manifest.name {
+        match &manifest.description {
+            Some(description) => println!(
+                "{}",
+                localize!(
+                    "using-template-described",
+                    name = template_name,
+                    description = description
+                )
+            ),
+            None => println!("{}", localize!("using-template", name = template_name)),
+        }
+    }
+    anyhow::ensure!(
+        !target_dir.exists(),
+        "target directory {} already exists",
+        target_dir.display()
+    );
+    // Guard rail against generating over half the machine: protected
+    // locations need an explicit, unmistakable override.
+    if let Some(reason) = crate::fs_util::protected_path(&target_dir) {
+        if args.i_know_what_im_doing {
+            crate::output::warn(&format!(
+                "generating into {} although {reason}",
+                target_dir.display()
+            ));
+        } else {
+            anyhow::bail!(
+                "refusing to generate into {}: {reason} (pass --i-know-what-im-doing to override)",
+                target_dir.display()
+            );
+        }
+    }
+    // Disk-space preflight: the manifest's own estimate when the author
+    // declared one, otherwise twice the template's size to cover rendering
+    // plus scaffolds. Failing here beats a half-written project.
+    let required_space = match manifest.required_space {
+        Some(bytes) => bytes,
+        None => {
+            source
+                .entries()?
+                .iter()
+                .map(|entry| entry.contents.len() as u64)
+                .sum::<u64>()
+                * 2
+        }
+    };
+    let space_probe = target_dir
+        .parent()
+        .filter(|parent| !parent.as_os_str().is_empty())
+        .map_or_else(|| PathBuf::from("."), std::path::Path::to_path_buf);
+    crate::fs_util::ensure_space(&space_probe, required_space)?;
+    // External drives are often FAT/exFAT; warn about what that filesystem
+    // cannot hold instead of failing later with raw OS errors.
+    if let Some(capabilities) = crate::fs_util::probe_capabilities(&space_probe) {
+        if capabilities.case_insensitive && manifest.requires_case_sensitive {
+            crate::output::warn(&localize!("fs-case-insensitive"));
+        }
+        if !capabilities.unix_permissions && !manifest.sensitive.is_empty() {
+            crate::output::warn(&localize!("fs-no-permissions"));
+        }
+    }
+    // Generate into a staging directory next to the target (same filesystem,
+    // so the final move is an atomic rename) and clean it up on any failure,
+    // so a bad template or I/O error never leaves a half-written project.
+    let staging = staging_dir(&target_dir);
+    if staging.exists() {
+        std::fs::remove_dir_all(&staging)?;
+    }
+    let generated = generate(&args, &source, &manifest, &values, &staging);
+    match generated {
+        Ok(()) => std::fs::rename(&staging, &target_dir)?,
+        Err(error) => {
+            let _ = std::fs::remove_dir_all(&staging);
+            return Err(error);
+        }
+    }
+    if record_answers {
+        let replay_path = target_dir.join(crate::wizard::REPLAY_FILE);
+        crate::wizard::write_replay(&args, &replay_path)?;
+        println!(
+            "{}",
+            localize!("replay-recorded", file = replay_path.display())
+        );
+    }
+    if args.vcs == Vcs::Git {
+        use crate::vcs::Vcs as _;
+        crate::vcs::ShellGit::default().init(&target_dir)?;
+    }
+    println!(
+        "{}",
+        localize!("created-project", name = name, path = target_dir.display())
+    );
+    Ok(())
+}
+
+/// The kinds of project `bevy new` can generate.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ProjectKind {
+    /// A game binary crate
+    Game,
+    /// A Bevy plugin library crate, ready to publish
+    Plugin,
+}
+
+/// Version control systems `bevy new` can initialize.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default, ValueEnum, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Vcs {
+    /// Leave the project without version control
+    #[default]
+    None,
+    /// Run `git init` in the generated project
+    Git,
+}
+
+/// Extra deployment targets a generated project can be prepared for, beyond
+/// the host platform.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TargetPlatform {
+    /// Browser builds via wasm-bindgen and trunk
+    Web,
+    /// Android builds via cargo-apk
+    Android,
+    /// iOS builds via xcodegen
+    Ios,
+}
+
+/// Reads a value from the user's git config, treating a missing git
+/// installation like an unset key.
+fn git_config(key: &str) -> Option<String> {
+    let output = std::process::Command::new("git")
+        .args(["config", "--get", key])
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let value = String::from_utf8(output.stdout).ok()?.trim().to_string();
+    (!value.is_empty()).then_some(value)
+}
+
+/// Renders the template and applies every requested scaffold into
+/// `project_dir` (the staging directory during normal operation).
+fn generate(
+    args: &NewArgs,
+    source: &TemplateSource,
+    manifest: &TemplateManifest,
+    values: &std::collections::BTreeMap<String, VarValue>,
+    project_dir: &std::path::Path,
+) -> anyhow::Result<()> {
+    let name = args.name.as_deref().unwrap_or_default();
+    render::render(source, manifest, project_dir, values)?;
+    // In workspace mode the optional pieces belong to the game binary crate.
+    let scaffold_dir = if args.workspace {
+        project_dir.join("crates").join(name)
+    } else {
+        project_dir.to_path_buf()
+    };
+    if let Some(channel) = &args.toolchain {
+        crate::scaffold::add_toolchain(project_dir, channel)?;
+    }
+    if args.fast_compiles {
+        crate::scaffold::add_fast_compiles(project_dir)?;
+    }
+    crate::scaffold::set_rust_versions(
+        &project_dir.join("Cargo.toml"),
+        &args.edition,
+        args.msrv.as_deref(),
+    )?;
+    if args.with_editor_config {
+        crate::scaffold::add_editor_config(project_dir)?;
+    }
+    if args.xtask {
+        crate::scaffold::add_xtask(project_dir)?;
+    }
+    if let Some(target) = args.target {
+        match target {
+            TargetPlatform::Web => crate::scaffold::add_web_target(project_dir, name)?,
+            TargetPlatform::Android => {
+                crate::scaffold::add_android_target(project_dir, &scaffold_dir, name)?
+            }
+            TargetPlatform::Ios => crate::scaffold::add_ios_target(project_dir, name)?,
+        }
+    }
+    // After the target scaffolding, so the CI generators can see whether
+    // the project builds for the web.
+    if args.ci {
+        crate::scaffold::add_ci(
+            project_dir,
+            args.msrv.as_deref(),
+            args.ci_provider,
+            &args.ci_options,
+        )?;
+    }
+    // The flag wins over the manifest's `bins` declaration when both exist.
+    let bins: Vec<BinSpec> = if args.bins.is_empty() {
+        manifest.bins.clone()
+    } else {
+        args.bins.iter().map(|spec| parse_bin_flag(spec)).collect()
+    };
+    if !bins.is_empty() {
+        crate::scaffold::add_bins(project_dir, &scaffold_dir, name, &bins)?;
+    }
+    crate::scaffold::apply_layout(&scaffold_dir, args.layout)?;
+    if args.with_states {
+        crate::scaffold::add_states(&scaffold_dir)?;
+    }
+    if args.with_assets {
+        crate::scaffold::add_assets(&scaffold_dir)?;
+    }
+    if args.with_examples {
+        crate::scaffold::add_examples(&scaffold_dir)?;
+    }
+    if args.with_benches {
+        crate::scaffold::add_benches(&scaffold_dir)?;
+    }
+    if let Some(VarValue::String(license)) = values.get("license") {
+        crate::scaffold::add_license(project_dir, license, values)?;
+    }
+    crate::scaffold::add_readme(
+        project_dir,
+        values,
+        args.target == Some(TargetPlatform::Web),
+    )?;
+    crate::scaffold::write_gitignore(project_dir, &args.gitignore)?;
+    // Record what was generated, so later subcommands can read the
+    // project's shape from Bevy.toml instead of guessing.
+    crate::project::write(project_dir, &project_section(args, values))?;
+    Ok(())
+}
+
+/// The `[project]` record of this generation, written into `Bevy.toml`.
+fn project_section(
+    args: &NewArgs,
+    values: &std::collections::BTreeMap<String, VarValue>,
+) -> crate::project::ProjectSection {
+    fn enum_name(value: &impl ValueEnum) -> String {
+        value
+            .to_possible_value()
+            .expect("no skipped variants")
+            .get_name()
+            .to_string()
+    }
+    let bevy_version = match values.get("bevy_version") {
+        Some(VarValue::String(version)) => version.clone(),
+        _ => args.bevy_version.clone(),
+    };
+    crate::project::ProjectSection {
+        name: args.name.clone().unwrap_or_default(),
+        template: args
+            .template
+            .as_ref()
+            .map(|template| template.to_string_lossy().into_owned()),
+        kind: enum_name(&args.kind),
+        bevy_version,
+        edition: args.edition.clone(),
+        targets: args.target.iter().map(enum_name).collect(),
+        layout: enum_name(&args.layout),
+        bevy_features: args.bevy_features.clone(),
+        workspace: args.workspace,
+        with_assets: args.with_assets,
+        with_states: args.with_states,
+        xtask: args.xtask,
+    }
+}
+
+/// Sibling of the target directory used for staged generation, hidden so an
+/// interrupted run is recognizably not a real project.
+fn staging_dir(target_dir: &std::path::Path) -> PathBuf {
+    let name = target_dir
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_else(|| "project".to_string());
+    match target_dir.parent() {
+        Some(parent) => parent.join(format!(".{name}.partial")),
+        None => PathBuf::from(format!(".{name}.partial")),
+    }
+}
+
+/// Rust keywords cargo refuses as crate names.
+const RESERVED_NAMES: &[&str] = &[
+    "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum", "extern",
+    "false", "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub",
+    "ref", "return", "self", "Self", "static", "struct", "super", "trait", "true", "type",
+    "unsafe", "use", "where", "while",
+];
+
+/// Checks the project name against cargo's crate-name rules before anything
+/// is written, so an invalid name fails here instead of producing a broken
+/// Cargo.toml. The error suggests a sanitized alternative.
+pub fn validate_project_name(name: &str) -> anyhow::Result<()> {
+    let valid = !name.is_empty()
+        && !name.starts_with(|c: char| c.is_ascii_digit())
+        && !RESERVED_NAMES.contains(&name)
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+    anyhow::ensure!(
+        valid,
+        "`{name}` is not a valid crate name; try `{}`",
+        sanitize_project_name(name)
+    );
+    Ok(())
+}
+
+/// Derives a valid crate name from an arbitrary string: invalid characters
+/// become hyphens, a leading digit gets a prefix, and keywords a suffix.
+pub fn sanitize_project_name(name: &str) -> String {
+    let mut sanitized: String = name
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '_' || c == '-' {
+                c
+            } else {
+                '-'
+            }
+        })
+        .collect();
+    if sanitized.starts_with(|c: char| c.is_ascii_digit()) {
+        sanitized = format!("bevy-{sanitized}");
+    }
+    if sanitized.is_empty() || RESERVED_NAMES.contains(&sanitized.as_str()) {
+        sanitized.push_str("-game");
+    }
+    sanitized
+}
+
+/// `editor:devtools+tracing` -> a [`BinSpec`] named `editor` requiring the
+/// `devtools` and `tracing` features.
+fn parse_bin_flag(spec: &str) -> BinSpec {
+    match spec.split_once(':') {
+        Some((name, features)) => BinSpec {
+            name: name.to_string(),
+            features: features.split('+').map(str::to_string).collect(),
+        },
+        None => BinSpec {
+            name: spec.to_string(),
+            features: Vec::new(),
+        },
+    }
+}
+
+/// `my_cool-crate` -> `MyCoolCrate`, for deriving type names from crate
+/// names.
+pub(crate) fn pascal_case(name: &str) -> String {
+    name.split(['_', '-'])
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect::<String>(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pascal_case_handles_separators() {
+        assert_eq!(pascal_case("my_cool-plugin"), "MyCoolPlugin");
+        assert_eq!(pascal_case("game"), "Game");
+    }
+
+    #[test]
+    fn invalid_names_are_rejected_with_a_suggestion() {
+        assert!(validate_project_name("my_game").is_ok());
+        assert!(validate_project_name("my game!").is_err());
+        assert!(validate_project_name("2048").is_err());
+        assert!(validate_project_name("match").is_err());
+        assert_eq!(sanitize_project_name("my game!"), "my-game-");
+        assert_eq!(sanitize_project_name("2048"), "bevy-2048");
+        assert_eq!(sanitize_project_name("match"), "match-game");
+    }
+
+    #[test]
+    fn bin_flags_carry_optional_features() {
+        let plain = parse_bin_flag("server");
+        assert_eq!(plain.name, "server");
+        assert!(plain.features.is_empty());
+        let with_features = parse_bin_flag("editor:devtools+tracing");
+        assert_eq!(with_features.name, "editor");
+        assert_eq!(with_features.features, vec!["devtools", "tracing"]);
+    }
+}
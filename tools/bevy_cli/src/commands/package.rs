@@ -0,0 +1,245 @@
+//! `bevy package`: turn a build into a distributable.
+//!
+//! Runs `bevy build` for the chosen platform, stages the binary together
+//! with assets, licenses, an icon, and any extra files `[package]` in
+//! `Bevy.toml` lists, then archives the stage: zip for Windows and web,
+//! tar for Linux, and a zipped `.app` bundle for macOS.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+use clap::Args;
+use serde::Deserialize;
+
+use super::build::{self, host_platform, Platform};
+use crate::i18n::localize;
+use crate::{archive, fs_util, output};
+
+#[derive(Args)]
+pub struct PackageArgs {
+    /// Project directory; defaults to the nearest project root
+    #[arg(long)]
+    pub project: Option<PathBuf>,
+
+    /// Platform to package for; omitted packages for the host
+    #[arg(long, value_enum)]
+    pub platform: Option<Platform>,
+
+    /// Package a debug build instead of the release profile
+    #[arg(long)]
+    pub debug: bool,
+}
+
+/// The `[package]` section of `Bevy.toml`.
+#[derive(Debug, Default, Deserialize)]
+struct PackageSection {
+    /// Display name for the distributable; the crate name by default.
+    #[serde(default)]
+    name: Option<String>,
+    /// Extra files or globs to ship, relative to the project root.
+    #[serde(default)]
+    include: Vec<String>,
+    /// Icon file to ship alongside the binary.
+    #[serde(default)]
+    icon: Option<PathBuf>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct ProjectConfig {
+    #[serde(default)]
+    package: PackageSection,
+}
+
+pub fn run(args: PackageArgs) -> anyhow::Result<()> {
+    let project = args
+        .project
+        .clone()
+        .unwrap_or_else(|| crate::project::locate(Path::new(".")));
+    anyhow::ensure!(
+        project.join("Cargo.toml").is_file(),
+        "{} does not contain a Cargo.toml",
+        project.display()
+    );
+    let platform = args.platform.unwrap_or_else(host_platform);
+    // Building the host platform without an explicit --target keeps working
+    // on machines that never ran `rustup target add`.
+    let build_platform = args
+        .platform
+        .filter(|&platform| platform == Platform::Web || platform != host_platform());
+    build::run(build::BuildArgs {
+        project: Some(project.clone()),
+        platform: build_platform,
+        release: !args.debug,
+        features: Vec::new(),
+        no_wasm_opt: false,
+    })?;
+
+    let config = load_config(&project)?;
+    let crate_name = super::bundle::package_name(&project)?;
+    let display_name = config
+        .package
+        .name
+        .clone()
+        .unwrap_or_else(|| crate_name.clone());
+    let built_dir = project
+        .join("dist")
+        .join(build_platform.map_or("host", Platform::name));
+
+    let stage = project
+        .join("dist")
+        .join("package")
+        .join(format!("{crate_name}-{}", platform.name()));
+    if stage.exists() {
+        std::fs::remove_dir_all(&stage)?;
+    }
+
+    if platform == Platform::Web {
+        fs_util::copy_dir(&built_dir, &stage)?;
+    } else {
+        let binary = format!("{crate_name}{}", platform.binary_extension());
+        let payload = if platform == Platform::Macos {
+            // Game.app/Contents/{Info.plist, MacOS/<binary>, Resources/}
+            let contents = stage.join(format!("{display_name}.app")).join("Contents");
+            std::fs::create_dir_all(contents.join("MacOS"))?;
+            fs_util::write_file(
+                &contents.join("Info.plist"),
+                info_plist(&display_name, &crate_name).as_bytes(),
+                false,
+            )?;
+            std::fs::copy(
+                built_dir.join(&binary),
+                contents.join("MacOS").join(&binary),
+            )
+            .context("no built binary; did the build step fail?")?;
+            contents.join("Resources")
+        } else {
+            std::fs::create_dir_all(&stage)?;
+            std::fs::copy(built_dir.join(&binary), stage.join(&binary))
+                .context("no built binary; did the build step fail?")?;
+            stage.clone()
+        };
+        std::fs::create_dir_all(&payload)?;
+        let assets = project.join("assets");
+        if assets.is_dir() {
+            fs_util::copy_dir(&assets, &payload.join("assets"))?;
+        }
+    }
+
+    // Embed the asset manifest so `bevy assets verify` works on the
+    // package and web deployments get stable hashes for cache busting.
+    let staged_assets = if platform == Platform::Macos {
+        stage
+            .join(format!("{display_name}.app"))
+            .join("Contents")
+            .join("Resources")
+            .join("assets")
+    } else {
+        stage.join("assets")
+    };
+    if staged_assets.is_dir() {
+        crate::commands::assets::manifest::write(&staged_assets)?;
+    }
+
+    copy_extras(&project, &stage, &config.package)?;
+
+    let archive_path = match platform {
+        Platform::Linux => {
+            let path = stage.with_extension("tar");
+            archive::tar_dir(&stage, &path)?;
+            path
+        }
+        _ => {
+            let path = stage.with_extension("zip");
+            archive::zip_dir(&stage, &path)?;
+            path
+        }
+    };
+    output::ok(&localize!(
+        "package-created",
+        path = archive_path
+            .strip_prefix(&project)
+            .unwrap_or(&archive_path)
+            .display()
+    ));
+    Ok(())
+}
+
+/// Licenses, the icon, and the `[package] include` globs.
+fn copy_extras(project: &Path, stage: &Path, section: &PackageSection) -> anyhow::Result<()> {
+    let mut patterns = vec!["LICENSE*".to_string()];
+    patterns.extend(section.include.iter().cloned());
+    if let Some(icon) = &section.icon {
+        patterns.push(icon.to_string_lossy().into_owned());
+    }
+    for pattern in patterns {
+        let full = project.join(&pattern);
+        for path in glob::glob(&full.to_string_lossy())
+            .with_context(|| format!("bad include pattern `{pattern}`"))?
+            .flatten()
+        {
+            if path.is_file() {
+                let relative = path.strip_prefix(project).unwrap_or(&path);
+                if let Some(parent) = stage.join(relative).parent() {
+                    std::fs::create_dir_all(parent)?;
+                }
+                std::fs::copy(&path, stage.join(relative))?;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn load_config(project: &Path) -> anyhow::Result<ProjectConfig> {
+    let manifest_path = project.join(crate::project::MANIFEST);
+    if !manifest_path.is_file() {
+        return Ok(ProjectConfig::default());
+    }
+    toml::from_str(&std::fs::read_to_string(&manifest_path)?)
+        .with_context(|| format!("failed to parse {}", manifest_path.display()))
+}
+
+/// The minimal `Info.plist` a runnable `.app` bundle needs.
+fn info_plist(display_name: &str, binary: &str) -> String {
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<!DOCTYPE plist PUBLIC "-//Apple//DTD PLIST 1.0//EN" "http://www.apple.com/DTDs/PropertyList-1.0.dtd">
+<plist version="1.0">
+<dict>
+    <key>CFBundleName</key>
+    <string>{display_name}</string>
+    <key>CFBundleExecutable</key>
+    <string>{binary}</string>
+    <key>CFBundleIdentifier</key>
+    <string>com.example.{binary}</string>
+    <key>CFBundlePackageType</key>
+    <string>APPL</string>
+    <key>NSHighResolutionCapable</key>
+    <true/>
+</dict>
+</plist>
+"#
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn the_plist_names_bundle_and_executable() {
+        let plist = info_plist("My Game", "my_game");
+        assert!(plist.contains("<string>My Game</string>"));
+        assert!(plist.contains("<string>my_game</string>"));
+        assert!(plist.contains("CFBundlePackageType"));
+    }
+
+    #[test]
+    fn package_sections_parse_from_project_config() {
+        let config: ProjectConfig = toml::from_str(
+            "[package]\nname = \"My Game\"\ninclude = [\"README.md\"]\nicon = \"icon.png\"\n",
+        )
+        .unwrap();
+        assert_eq!(config.package.name.as_deref(), Some("My Game"));
+        assert_eq!(config.package.include, vec!["README.md"]);
+    }
+}
@@ -0,0 +1,178 @@
+//! `bevy profile`: run the game with tracing turned on.
+//!
+//! Bevy's profiling story is a pile of incantations — the right `trace_*`
+//! feature combination, release mode so spans measure real work, and
+//! knowing where the output lands. This wraps the three usual backends:
+//! `chrome` writes a `trace-*.json` for Perfetto, `tracy` streams live to a
+//! connected Tracy profiler, and `flamegraph` drives the external
+//! `cargo flamegraph` sampler.
+
+use std::path::{Path, PathBuf};
+
+use clap::Args;
+
+use crate::i18n::localize;
+use crate::output;
+use crate::subprocess::Subprocess;
+
+#[derive(Args)]
+pub struct ProfileArgs {
+    /// Project directory; defaults to the nearest project root
+    #[arg(long)]
+    pub project: Option<PathBuf>,
+
+    /// Profiling backend to use
+    #[arg(long, value_enum, default_value_t = Backend::Chrome)]
+    pub backend: Backend,
+
+    /// Profile the dev profile; span timings will include debug overhead
+    #[arg(long)]
+    pub debug: bool,
+
+    /// Arguments passed through to the game binary
+    #[arg(last = true)]
+    pub args: Vec<String>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum Backend {
+    /// Write a Chrome trace file; open it at ui.perfetto.dev
+    Chrome,
+    /// Stream spans live to a running Tracy profiler
+    Tracy,
+    /// Sample a CPU flamegraph through `cargo flamegraph`
+    Flamegraph,
+}
+
+pub fn run(args: ProfileArgs) -> anyhow::Result<()> {
+    let project = args
+        .project
+        .clone()
+        .unwrap_or_else(|| crate::project::locate(Path::new(".")));
+    anyhow::ensure!(
+        project.join("Cargo.toml").is_file(),
+        "{} does not contain a Cargo.toml",
+        project.display()
+    );
+
+    if args.backend == Backend::Tracy {
+        // Say so up front: without a connected profiler the run looks idle.
+        println!("{}", localize!("profile-tracy-hint"));
+    }
+    // The chrome backend names its file after the process id; remember what
+    // was there before so this run's trace is identifiable afterwards.
+    let before = trace_files(&project);
+    Subprocess::new("cargo")
+        .args(cargo_args(args.backend, args.debug, &args.args))
+        .current_dir(&project)
+        .run()?;
+
+    match args.backend {
+        Backend::Chrome => match new_trace(&before, &trace_files(&project)) {
+            Some(trace) => {
+                output::ok(&localize!("profile-trace-ready", path = trace.display()));
+            }
+            None => output::warn(&localize!("profile-no-trace")),
+        },
+        Backend::Tracy => {}
+        Backend::Flamegraph => {
+            let svg = project.join("flamegraph.svg");
+            if svg.is_file() {
+                output::ok(&localize!("profile-trace-ready", path = svg.display()));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// The cargo invocation per backend; separated for testing. All backends
+/// default to release builds — dev-profile spans mostly measure the
+/// compiler's missed optimizations.
+fn cargo_args(backend: Backend, debug: bool, game_args: &[String]) -> Vec<String> {
+    let mut cargo = match backend {
+        Backend::Chrome => vec![
+            "run".to_string(),
+            "--features".to_string(),
+            "bevy/trace,bevy/trace_chrome".to_string(),
+        ],
+        Backend::Tracy => vec![
+            "run".to_string(),
+            "--features".to_string(),
+            "bevy/trace,bevy/trace_tracy".to_string(),
+        ],
+        Backend::Flamegraph => vec!["flamegraph".to_string()],
+    };
+    if !debug && backend != Backend::Flamegraph {
+        cargo.insert(1, "--release".to_string());
+    }
+    if backend == Backend::Flamegraph && debug {
+        cargo.push("--dev".to_string());
+    }
+    if !game_args.is_empty() {
+        cargo.push("--".to_string());
+        cargo.extend(game_args.iter().cloned());
+    }
+    cargo
+}
+
+/// The `trace-*.json` files Bevy's chrome backend writes into the run
+/// directory, so the one produced by this run can be singled out.
+fn trace_files(project: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = std::fs::read_dir(project) else {
+        return Vec::new();
+    };
+    let mut traces: Vec<PathBuf> = entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name.starts_with("trace-") && name.ends_with(".json"))
+        })
+        .collect();
+    traces.sort();
+    traces
+}
+
+/// The trace that appeared during the run, if any.
+fn new_trace(before: &[PathBuf], after: &[PathBuf]) -> Option<PathBuf> {
+    after.iter().find(|path| !before.contains(path)).cloned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn chrome_profiles_run_release_with_the_trace_features() {
+        assert_eq!(
+            cargo_args(Backend::Chrome, false, &["--level".to_string()]),
+            vec![
+                "run",
+                "--release",
+                "--features",
+                "bevy/trace,bevy/trace_chrome",
+                "--",
+                "--level"
+            ]
+        );
+        assert_eq!(
+            cargo_args(Backend::Flamegraph, false, &[]),
+            vec!["flamegraph"]
+        );
+    }
+
+    #[test]
+    fn the_trace_from_this_run_is_the_one_not_seen_before() {
+        let before = vec![PathBuf::from("trace-100.json")];
+        let after = vec![
+            PathBuf::from("trace-100.json"),
+            PathBuf::from("trace-200.json"),
+        ];
+        assert_eq!(
+            new_trace(&before, &after),
+            Some(PathBuf::from("trace-200.json"))
+        );
+        assert_eq!(new_trace(&after, &after), None);
+    }
+}
@@ -0,0 +1,129 @@
+//! `bevy remove`: the inverse of `bevy add`.
+//!
+//! Drops the dependency from every dependency table in `Cargo.toml` and
+//! strips the matching `add_plugins(..)` call plus the now-dead `use`
+//! lines from `main.rs`. `--dry-run` previews the source edit as a diff
+//! instead of writing it.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+use clap::Args;
+
+use crate::i18n::localize;
+use crate::output;
+
+#[derive(Args)]
+pub struct RemoveArgs {
+    /// Name of the crate to remove
+    pub name: String,
+
+    /// Project directory; defaults to the nearest project root
+    #[arg(long)]
+    pub project: Option<PathBuf>,
+
+    /// Show the edits as a diff without writing anything
+    #[arg(long)]
+    pub dry_run: bool,
+}
+
+pub fn run(args: RemoveArgs) -> anyhow::Result<()> {
+    let project = args
+        .project
+        .clone()
+        .unwrap_or_else(|| crate::project::locate(Path::new(".")));
+    let manifest_path = project.join("Cargo.toml");
+    anyhow::ensure!(
+        manifest_path.is_file(),
+        "{} does not contain a Cargo.toml",
+        project.display()
+    );
+
+    let contents = std::fs::read_to_string(&manifest_path)?;
+    let mut doc: toml_edit::Document = contents.parse().context("invalid Cargo.toml")?;
+    let mut removed = false;
+    for table_name in ["dependencies", "dev-dependencies", "build-dependencies"] {
+        if let Some(table) = doc
+            .get_mut(table_name)
+            .and_then(|item| item.as_table_like_mut())
+        {
+            removed |= table.remove(&args.name).is_some();
+        }
+    }
+    anyhow::ensure!(removed, "`{}` is not a dependency", args.name);
+    if args.dry_run {
+        for line in super::templates::line_diff(&contents, &doc.to_string()) {
+            println!("{line}");
+        }
+    } else {
+        std::fs::write(&manifest_path, doc.to_string())?;
+        println!("{}", localize!("remove-dependency", name = args.name));
+    }
+
+    let main = project.join("src/main.rs");
+    let Ok(source) = std::fs::read_to_string(&main) else {
+        return Ok(());
+    };
+    let stripped = strip_crate(&source, &args.name);
+    if stripped == source {
+        return Ok(());
+    }
+    if args.dry_run {
+        for line in super::templates::line_diff(&source, &stripped) {
+            println!("{line}");
+        }
+        println!("{}", localize!("remove-dry-run"));
+    } else {
+        std::fs::write(&main, stripped)?;
+        output::ok(&localize!("remove-plugin-stripped", name = args.name));
+    }
+    Ok(())
+}
+
+/// Strips everything obviously belonging to `name` from a source file: its
+/// `use` lines and — for crates with a known snippet — the chained
+/// `.add_plugins(..)` call inserted by `bevy add`.
+fn strip_crate(source: &str, name: &str) -> String {
+    let ident = name.replace('-', "_");
+    let mut stripped: String = source
+        .lines()
+        .filter(|line| {
+            let trimmed = line.trim_start();
+            !(trimmed.starts_with("use ")
+                && trimmed["use ".len()..].trim_start().starts_with(&ident))
+        })
+        .map(|line| format!("{line}\n"))
+        .collect();
+    if !source.ends_with('\n') {
+        stripped.pop();
+    }
+    if let Some((_, _, plugin)) = super::add::PLUGIN_SNIPPETS
+        .iter()
+        .find(|(snippet_name, _, _)| *snippet_name == name)
+    {
+        stripped = stripped.replace(&format!(".add_plugins({plugin})"), "");
+    }
+    stripped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn plugin_call_and_imports_are_stripped() {
+        let source = "use bevy::prelude::*;\nuse bevy_egui::EguiPlugin;\n\n\
+                      fn main() {\n    App::new().add_plugins(EguiPlugin).run();\n}\n";
+        let stripped = strip_crate(source, "bevy_egui");
+        assert!(!stripped.contains("bevy_egui"));
+        assert!(!stripped.contains("EguiPlugin"));
+        assert!(stripped.contains("App::new().run();"));
+        assert!(stripped.contains("use bevy::prelude::*;"));
+    }
+
+    #[test]
+    fn unrelated_sources_are_untouched() {
+        let source = "use bevy::prelude::*;\nfn main() { App::new().run(); }\n";
+        assert_eq!(strip_crate(source, "bevy_rapier2d"), source);
+    }
+}
@@ -0,0 +1,158 @@
+//! `bevy run`: `cargo run` with Bevy-appropriate defaults.
+//!
+//! Dev runs enable `bevy/dynamic_linking` automatically (when the project
+//! depends on Bevy), backtraces and engine log filtering get sensible
+//! defaults unless the environment already set them, and the linker flags a
+//! generated `.cargo/config.toml` declares are picked up by cargo as usual.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+use clap::Args;
+
+#[derive(Args)]
+pub struct RunArgs {
+    /// Project directory; defaults to the nearest project root
+    #[arg(long)]
+    pub project: Option<PathBuf>,
+
+    /// Build with the release profile (disables dynamic linking)
+    #[arg(long)]
+    pub release: bool,
+
+    /// Cargo features to enable, comma-separated or repeated
+    #[arg(long, value_delimiter = ',')]
+    pub features: Vec<String>,
+
+    /// Binary target to run, for projects with several `[[bin]]`s
+    #[arg(long)]
+    pub bin: Option<String>,
+
+    /// Skip the automatic `bevy/dynamic_linking` dev feature, e.g. when a
+    /// crate in the tree does not build against the dynamic libraries
+    #[arg(long)]
+    pub no_dynamic: bool,
+
+    /// Arguments passed through to the game binary
+    #[arg(last = true)]
+    pub args: Vec<String>,
+}
+
+/// The log filter dev runs get when `RUST_LOG` is unset: engine info
+/// without the wgpu and naga noise.
+const DEFAULT_LOG_FILTER: &str = "info,wgpu_core=warn,wgpu_hal=warn,naga=warn";
+
+pub fn run(args: RunArgs) -> anyhow::Result<()> {
+    let project = args
+        .project
+        .clone()
+        .unwrap_or_else(|| crate::project::locate(Path::new(".")));
+    anyhow::ensure!(
+        project.join("Cargo.toml").is_file(),
+        "{} does not contain a Cargo.toml",
+        project.display()
+    );
+    let dynamic = !args.release && !args.no_dynamic && depends_on_bevy(&project);
+    let mut command = std::process::Command::new("cargo");
+    command
+        .args(cargo_args(&args, dynamic))
+        .current_dir(&project);
+    // Defaults only; an explicit environment always wins.
+    if std::env::var_os("RUST_BACKTRACE").is_none() {
+        command.env("RUST_BACKTRACE", "1");
+    }
+    if std::env::var_os("RUST_LOG").is_none() {
+        command.env("RUST_LOG", DEFAULT_LOG_FILTER);
+    }
+    let status = command.status().context("failed to run cargo")?;
+    anyhow::ensure!(status.success(), "cargo run failed");
+    Ok(())
+}
+
+/// The cargo argument vector for this invocation; separated from [`run`] so
+/// the flag plumbing is testable without spawning cargo.
+fn cargo_args(args: &RunArgs, dynamic: bool) -> Vec<String> {
+    let mut cargo = vec!["run".to_string()];
+    if args.release {
+        cargo.push("--release".to_string());
+    }
+    let mut features = args.features.clone();
+    if dynamic {
+        features.push("bevy/dynamic_linking".to_string());
+    }
+    if !features.is_empty() {
+        cargo.push("--features".to_string());
+        cargo.push(features.join(","));
+    }
+    if let Some(bin) = &args.bin {
+        cargo.push("--bin".to_string());
+        cargo.push(bin.clone());
+    }
+    if !args.args.is_empty() {
+        cargo.push("--".to_string());
+        cargo.extend(args.args.iter().cloned());
+    }
+    cargo
+}
+
+/// Whether the project (or, for workspaces, any crate under `crates/`)
+/// declares a `bevy` dependency; `bevy/dynamic_linking` only exists then.
+fn depends_on_bevy(project: &Path) -> bool {
+    let mut manifests = vec![project.join("Cargo.toml")];
+    if let Ok(entries) = std::fs::read_dir(project.join("crates")) {
+        manifests.extend(
+            entries
+                .flatten()
+                .map(|entry| entry.path().join("Cargo.toml")),
+        );
+    }
+    manifests.iter().any(|manifest| {
+        std::fs::read_to_string(manifest)
+            .ok()
+            .and_then(|contents| contents.parse::<toml::Table>().ok())
+            .and_then(|table| {
+                table
+                    .get("dependencies")
+                    .and_then(|dependencies| dependencies.get("bevy"))
+                    .map(|_| ())
+            })
+            .is_some()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args() -> RunArgs {
+        RunArgs {
+            project: None,
+            release: false,
+            features: Vec::new(),
+            bin: None,
+            no_dynamic: false,
+            args: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn dev_runs_add_dynamic_linking_to_the_features() {
+        let mut run = args();
+        run.features = vec!["devtools".to_string()];
+        assert_eq!(
+            cargo_args(&run, true),
+            vec!["run", "--features", "devtools,bevy/dynamic_linking"]
+        );
+    }
+
+    #[test]
+    fn release_runs_pass_game_arguments_through() {
+        let mut run = args();
+        run.release = true;
+        run.args = vec!["--level".to_string(), "3".to_string()];
+        assert_eq!(
+            cargo_args(&run, false),
+            vec!["run", "--release", "--", "--level", "3"]
+        );
+    }
+}
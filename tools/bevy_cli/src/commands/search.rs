@@ -0,0 +1,37 @@
+use clap::Args;
+
+use crate::config::CliConfig;
+use crate::i18n::localize;
+use crate::output;
+use crate::registry;
+
+#[derive(Args)]
+pub struct SearchArgs {
+    /// Text to look for in template names and descriptions; lists everything
+    /// when omitted
+    pub query: Option<String>,
+}
+
+pub fn run(args: SearchArgs) -> anyhow::Result<()> {
+    let config = CliConfig::load()?;
+    let mut found = registry::search(&config.registries, args.query.as_deref().unwrap_or(""))?;
+    if found.is_empty() {
+        println!("{}", localize!("no-templates-found"));
+        return Ok(());
+    }
+    output::sort_localized(&mut found, |template| template.entry.name.as_str());
+    let count = found.len();
+    for template in found {
+        println!(
+            "{}",
+            localize!(
+                "search-result",
+                name = template.entry.name,
+                registry = template.registry,
+                description = template.entry.description.as_deref().unwrap_or("-")
+            )
+        );
+    }
+    println!("{}", localize!("templates-found", count = count));
+    Ok(())
+}
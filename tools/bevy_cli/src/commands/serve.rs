@@ -0,0 +1,257 @@
+//! `bevy serve`: a local development server for web builds.
+//!
+//! Serves the assembled `dist/web/` bundle on localhost with the MIME types
+//! and cross-origin isolation headers (COOP/COEP) wasm needs, watches the
+//! sources, and rebuilds on change; served pages poll a reload endpoint and
+//! refresh themselves when a rebuild lands. Dependency-free on purpose: a
+//! dev server this small does not justify an async stack.
+
+use std::io::{BufRead, BufReader, Write};
+use std::net::{TcpListener, TcpStream};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::Context;
+use clap::Args;
+
+use crate::commands::build::{self, BuildArgs, Platform};
+use crate::i18n::localize;
+use crate::output;
+
+#[derive(Args)]
+pub struct ServeArgs {
+    /// Project directory; defaults to the nearest project root
+    #[arg(long)]
+    pub project: Option<PathBuf>,
+
+    /// Port to listen on
+    #[arg(long, default_value_t = 8080)]
+    pub port: u16,
+
+    /// Serve a release build (slower rebuilds, realistic performance)
+    #[arg(long)]
+    pub release: bool,
+
+    /// Serve what is already in `dist/web/` without watching or rebuilding
+    #[arg(long)]
+    pub no_watch: bool,
+}
+
+/// The endpoint served pages poll for the current build generation.
+const RELOAD_ENDPOINT: &str = "/__reload";
+
+/// How often the watcher compares the source tree.
+const POLL_INTERVAL: Duration = Duration::from_millis(1000);
+
+pub fn run(args: ServeArgs) -> anyhow::Result<()> {
+    let project = args
+        .project
+        .clone()
+        .unwrap_or_else(|| crate::project::locate(Path::new(".")));
+    anyhow::ensure!(
+        project.join("Cargo.toml").is_file(),
+        "{} does not contain a Cargo.toml",
+        project.display()
+    );
+    let dist = project.join("dist").join("web");
+    // `--no-watch` serves an existing bundle as-is; everything else starts
+    // from a fresh build.
+    if !args.no_watch || !dist.is_dir() {
+        build_web(&project, args.release)?;
+    }
+
+    // The generation counter ties rebuilds to browser reloads: the watcher
+    // bumps it, served pages poll it.
+    let generation = Arc::new(AtomicU64::new(1));
+    if !args.no_watch {
+        let watcher_project = project.clone();
+        let watcher_generation = Arc::clone(&generation);
+        let release = args.release;
+        std::thread::spawn(move || {
+            watch(&watcher_project, release, &watcher_generation);
+        });
+    }
+
+    let listener = TcpListener::bind(("127.0.0.1", args.port))
+        .with_context(|| format!("failed to listen on port {}", args.port))?;
+    println!(
+        "{}",
+        localize!("serve-listening", path = dist.display(), port = args.port)
+    );
+    for stream in listener.incoming() {
+        let Ok(stream) = stream else { continue };
+        // One request per connection; a dev server has no concurrency needs
+        // beyond not dying on a bad request.
+        if let Err(error) = handle(stream, &dist, &generation) {
+            output::warn(&format!("request failed: {error:#}"));
+        }
+    }
+    Ok(())
+}
+
+fn build_web(project: &Path, release: bool) -> anyhow::Result<()> {
+    build::run(BuildArgs {
+        project: Some(project.to_path_buf()),
+        platform: Some(Platform::Web),
+        release,
+        features: Vec::new(),
+        no_wasm_opt: !release,
+    })
+}
+
+/// Polls the source tree and rebuilds when it changes. Build failures are
+/// reported and the old bundle keeps being served; the next edit retries.
+fn watch(project: &Path, release: bool, generation: &AtomicU64) {
+    let mut last = tree_stamp(project);
+    loop {
+        std::thread::sleep(POLL_INTERVAL);
+        let current = tree_stamp(project);
+        if current == last {
+            continue;
+        }
+        last = current;
+        match build_web(project, release) {
+            Ok(()) => {
+                generation.fetch_add(1, Ordering::SeqCst);
+                println!("{}", localize!("serve-reloaded"));
+            }
+            Err(error) => output::warn(&format!("rebuild failed: {error:#}")),
+        }
+    }
+}
+
+/// A cheap fingerprint of everything a rebuild depends on: path, size and
+/// mtime of the sources, assets and manifest — content hashing every poll
+/// would thrash large asset directories.
+fn tree_stamp(project: &Path) -> u64 {
+    use std::hash::{Hash, Hasher};
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    let mut stack = vec![project.join("src"), project.join("assets")];
+    stack.push(project.join("Cargo.toml"));
+    while let Some(path) = stack.pop() {
+        if path.is_dir() {
+            if let Ok(entries) = std::fs::read_dir(&path) {
+                stack.extend(entries.flatten().map(|entry| entry.path()));
+            }
+            continue;
+        }
+        let Ok(metadata) = path.metadata() else {
+            continue;
+        };
+        path.to_string_lossy().hash(&mut hasher);
+        metadata.len().hash(&mut hasher);
+        if let Ok(modified) = metadata.modified() {
+            modified.hash(&mut hasher);
+        }
+    }
+    hasher.finish()
+}
+
+fn handle(mut stream: TcpStream, dist: &Path, generation: &AtomicU64) -> anyhow::Result<()> {
+    let mut reader = BufReader::new(stream.try_clone()?);
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line)?;
+    let path = request_line.split_whitespace().nth(1).unwrap_or("/");
+    // Drain the headers so well-behaved clients see a clean close.
+    let mut line = String::new();
+    while reader.read_line(&mut line)? > 2 {
+        line.clear();
+    }
+
+    if path == RELOAD_ENDPOINT {
+        let stamp = generation.load(Ordering::SeqCst).to_string();
+        return respond(&mut stream, "200 OK", "text/plain", stamp.as_bytes());
+    }
+
+    let rel = path.trim_start_matches('/');
+    let rel = if rel.is_empty() { "index.html" } else { rel };
+    // Reject traversal instead of resolving it; the server only ever serves
+    // the bundle.
+    if rel.split('/').any(|segment| segment == "..") {
+        return respond(&mut stream, "403 Forbidden", "text/plain", b"forbidden");
+    }
+    let file = dist.join(rel);
+    match std::fs::read(&file) {
+        Ok(contents) if rel == "index.html" => {
+            let page = inject_reload(&String::from_utf8_lossy(&contents));
+            respond(&mut stream, "200 OK", "text/html", page.as_bytes())
+        }
+        Ok(contents) => respond(&mut stream, "200 OK", mime_type(rel), &contents),
+        Err(_) => respond(&mut stream, "404 Not Found", "text/plain", b"not found"),
+    }
+}
+
+fn respond(
+    stream: &mut TcpStream,
+    status: &str,
+    content_type: &str,
+    body: &[u8],
+) -> anyhow::Result<()> {
+    // COOP/COEP make the page cross-origin isolated, which wasm threads and
+    // SharedArrayBuffer require; no-cache keeps reloads honest.
+    let headers = format!(
+        "HTTP/1.1 {status}\r\nContent-Type: {content_type}\r\nContent-Length: {}\r\nCross-Origin-Opener-Policy: same-origin\r\nCross-Origin-Embedder-Policy: require-corp\r\nCache-Control: no-cache\r\nConnection: close\r\n\r\n",
+        body.len()
+    );
+    stream.write_all(headers.as_bytes())?;
+    stream.write_all(body)?;
+    Ok(())
+}
+
+/// The content type for a served path, by extension.
+fn mime_type(path: &str) -> &'static str {
+    match path.rsplit('.').next().unwrap_or_default() {
+        "html" => "text/html",
+        "js" | "mjs" => "text/javascript",
+        "wasm" => "application/wasm",
+        "css" => "text/css",
+        "json" => "application/json",
+        "png" => "image/png",
+        "jpg" | "jpeg" => "image/jpeg",
+        "gif" => "image/gif",
+        "svg" => "image/svg+xml",
+        "ico" => "image/x-icon",
+        "wav" => "audio/wav",
+        "ogg" | "oga" => "audio/ogg",
+        "mp3" => "audio/mpeg",
+        "ttf" => "font/ttf",
+        "otf" => "font/otf",
+        "ron" | "toml" | "txt" => "text/plain",
+        _ => "application/octet-stream",
+    }
+}
+
+/// Injects the live-reload poller into a served page, just before
+/// `</body>` when there is one.
+fn inject_reload(page: &str) -> String {
+    let script = format!(
+        "<script>(async () => {{ const initial = await (await fetch(\"{RELOAD_ENDPOINT}\")).text(); setInterval(async () => {{ try {{ const stamp = await (await fetch(\"{RELOAD_ENDPOINT}\")).text(); if (stamp !== initial) location.reload(); }} catch (_) {{}} }}, 1000); }})();</script>"
+    );
+    match page.rfind("</body>") {
+        Some(index) => format!("{}{script}{}", &page[..index], &page[index..]),
+        None => format!("{page}{script}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mime_types_cover_the_bundle_formats() {
+        assert_eq!(mime_type("game_bg.wasm"), "application/wasm");
+        assert_eq!(mime_type("game.js"), "text/javascript");
+        assert_eq!(mime_type("assets/theme.ron"), "text/plain");
+        assert_eq!(mime_type("unknown.bin"), "application/octet-stream");
+    }
+
+    #[test]
+    fn reload_script_lands_inside_the_body() {
+        let page = inject_reload("<html><body>hi</body></html>");
+        let script = page.find("<script>").unwrap();
+        assert!(script < page.find("</body>").unwrap());
+        assert!(page.contains(RELOAD_ENDPOINT));
+    }
+}
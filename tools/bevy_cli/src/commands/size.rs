@@ -0,0 +1,276 @@
+//! `bevy size`: where the bytes go, and whether they fit.
+//!
+//! Reports the built binary's size — per crate through `cargo bloat` when
+//! it is installed — and the asset directory grouped by file type, then
+//! checks both against the budgets `[size]` in `Bevy.toml` declares.
+//! Exceeding a budget is an error, so `bevy size` in CI keeps a web build
+//! from quietly growing past what players will wait for.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+use clap::Args;
+use serde::Deserialize;
+
+use super::build::Platform;
+use crate::fs_util;
+use crate::i18n::localize;
+use crate::output;
+
+#[derive(Args)]
+pub struct SizeArgs {
+    /// Project directory; defaults to the nearest project root
+    #[arg(long)]
+    pub project: Option<PathBuf>,
+
+    /// Platform whose dist/ artifact to measure; omitted measures the
+    /// release binary under target/
+    #[arg(long, value_enum)]
+    pub platform: Option<Platform>,
+
+    /// Skip the per-crate breakdown even when cargo-bloat is installed
+    #[arg(long)]
+    pub no_bloat: bool,
+}
+
+/// The `[size]` section of `Bevy.toml`: budgets as `"25 MB"`-style strings.
+#[derive(Debug, Default, Deserialize)]
+struct SizeSection {
+    /// Budget for the built binary (for web, the wasm file).
+    #[serde(default)]
+    binary: Option<String>,
+    /// Budget for the assets directory as a whole.
+    #[serde(default)]
+    assets: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct ProjectConfig {
+    #[serde(default)]
+    size: SizeSection,
+}
+
+pub fn run(args: SizeArgs) -> anyhow::Result<()> {
+    let project = args
+        .project
+        .clone()
+        .unwrap_or_else(|| crate::project::locate(Path::new(".")));
+    anyhow::ensure!(
+        project.join("Cargo.toml").is_file(),
+        "{} does not contain a Cargo.toml",
+        project.display()
+    );
+    let config: ProjectConfig = load_config(&project)?;
+    let name = super::bundle::package_name(&project)?;
+
+    let binary = binary_path(&project, &name, args.platform);
+    let binary_size = binary
+        .as_ref()
+        .and_then(|path| path.metadata().ok())
+        .map(|m| m.len());
+    match (&binary, binary_size) {
+        (Some(path), Some(size)) => {
+            println!(
+                "{}",
+                localize!(
+                    "size-binary",
+                    file = path.strip_prefix(&project).unwrap_or(path).display(),
+                    size = fs_util::human_size(size)
+                )
+            );
+        }
+        _ => output::warn(&localize!("size-no-binary")),
+    }
+    if !args.no_bloat && args.platform.is_none() {
+        if super::doctor::on_path("cargo-bloat") {
+            crate::subprocess::Subprocess::new("cargo")
+                .args(["bloat", "--release", "--crates", "-n", "15"])
+                .current_dir(&project)
+                .run()?;
+        } else {
+            output::warn(&localize!("size-no-bloat"));
+        }
+    }
+
+    let assets = project.join("assets");
+    let mut assets_total = 0;
+    if assets.is_dir() {
+        let breakdown = by_extension(&asset_files(&assets));
+        assets_total = breakdown.values().sum();
+        for (extension, bytes) in sorted_desc(&breakdown) {
+            println!(
+                "{}",
+                localize!(
+                    "size-asset-type",
+                    extension = extension,
+                    size = fs_util::human_size(bytes)
+                )
+            );
+        }
+        println!(
+            "{}",
+            localize!(
+                "size-assets-total",
+                size = fs_util::human_size(assets_total)
+            )
+        );
+    }
+
+    check_budget("binary", binary_size, config.size.binary.as_deref())?;
+    check_budget("assets", Some(assets_total), config.size.assets.as_deref())?;
+    Ok(())
+}
+
+/// Fails when a measured size exceeds its configured budget.
+pub(crate) fn check_budget(
+    what: &str,
+    measured: Option<u64>,
+    budget: Option<&str>,
+) -> anyhow::Result<()> {
+    let (Some(measured), Some(budget)) = (measured, budget) else {
+        return Ok(());
+    };
+    let limit = parse_size(budget)?;
+    anyhow::ensure!(
+        measured <= limit,
+        "{}",
+        localize!(
+            "size-over-budget",
+            what = what,
+            size = fs_util::human_size(measured),
+            budget = fs_util::human_size(limit)
+        )
+    );
+    Ok(())
+}
+
+/// A human budget string — `"300 KB"`, `"25MB"`, `"1048576"` — in bytes.
+pub(crate) fn parse_size(text: &str) -> anyhow::Result<u64> {
+    let trimmed = text.trim();
+    let split = trimmed
+        .find(|character: char| !character.is_ascii_digit())
+        .unwrap_or(trimmed.len());
+    let (number, unit) = trimmed.split_at(split);
+    let value: u64 = number
+        .parse()
+        .with_context(|| format!("bad size `{text}`; expected e.g. \"25 MB\""))?;
+    let factor = match unit.trim().to_ascii_lowercase().as_str() {
+        "" | "b" => 1,
+        "kb" | "kib" => 1024,
+        "mb" | "mib" => 1024 * 1024,
+        "gb" | "gib" => 1024 * 1024 * 1024,
+        other => anyhow::bail!("unknown size unit `{other}` in `{text}`"),
+    };
+    Ok(value * factor)
+}
+
+/// Where the measurable artifact lives: `dist/<platform>/` for platform
+/// builds (the wasm file for web), the release binary otherwise.
+fn binary_path(project: &Path, name: &str, platform: Option<Platform>) -> Option<PathBuf> {
+    match platform {
+        Some(Platform::Web) => {
+            // The bindgen'd wasm is what ships; it carries the `_bg` suffix.
+            let bundled = project.join("dist/web").join(format!("{name}_bg.wasm"));
+            bundled.is_file().then_some(bundled)
+        }
+        Some(platform) => {
+            let path = project
+                .join("dist")
+                .join(platform.name())
+                .join(format!("{name}{}", platform.binary_extension()));
+            path.is_file().then_some(path)
+        }
+        None => {
+            let path = project.join("target/release").join(name);
+            path.is_file().then_some(path)
+        }
+    }
+}
+
+/// Every file under the assets tree with its size.
+fn asset_files(assets: &Path) -> Vec<(PathBuf, u64)> {
+    let mut files = Vec::new();
+    let mut stack = vec![assets.to_path_buf()];
+    while let Some(path) = stack.pop() {
+        if path.is_dir() {
+            if let Ok(entries) = std::fs::read_dir(&path) {
+                stack.extend(entries.flatten().map(|entry| entry.path()));
+            }
+            continue;
+        }
+        if let Ok(metadata) = path.metadata() {
+            files.push((path, metadata.len()));
+        }
+    }
+    files
+}
+
+/// Total bytes per lowercase file extension; extensionless files group
+/// under `(none)`.
+fn by_extension(files: &[(PathBuf, u64)]) -> BTreeMap<String, u64> {
+    let mut totals = BTreeMap::new();
+    for (path, size) in files {
+        let extension = path.extension().map_or_else(
+            || "(none)".to_string(),
+            |ext| ext.to_string_lossy().to_lowercase(),
+        );
+        *totals.entry(extension).or_insert(0) += size;
+    }
+    totals
+}
+
+fn sorted_desc(totals: &BTreeMap<String, u64>) -> Vec<(String, u64)> {
+    let mut sorted: Vec<(String, u64)> = totals
+        .iter()
+        .map(|(extension, bytes)| (extension.clone(), *bytes))
+        .collect();
+    sorted.sort_by_key(|entry| std::cmp::Reverse(entry.1));
+    sorted
+}
+
+fn load_config(project: &Path) -> anyhow::Result<ProjectConfig> {
+    let manifest_path = project.join(crate::project::MANIFEST);
+    if !manifest_path.is_file() {
+        return Ok(ProjectConfig::default());
+    }
+    toml::from_str(&std::fs::read_to_string(&manifest_path)?)
+        .with_context(|| format!("failed to parse {}", manifest_path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn budget_strings_parse_in_binary_units() {
+        assert_eq!(parse_size("1048576").unwrap(), 1024 * 1024);
+        assert_eq!(parse_size("300 KB").unwrap(), 300 * 1024);
+        assert_eq!(parse_size("25MB").unwrap(), 25 * 1024 * 1024);
+        assert!(parse_size("fast").is_err());
+        assert!(parse_size("3 parsecs").is_err());
+    }
+
+    #[test]
+    fn asset_totals_group_by_lowercased_extension() {
+        let files = vec![
+            (PathBuf::from("assets/Hero.PNG"), 100),
+            (PathBuf::from("assets/map.png"), 50),
+            (PathBuf::from("assets/theme.ogg"), 500),
+            (PathBuf::from("assets/LICENSE"), 10),
+        ];
+        let totals = by_extension(&files);
+        assert_eq!(totals["png"], 150);
+        assert_eq!(totals["ogg"], 500);
+        assert_eq!(totals["(none)"], 10);
+        assert_eq!(sorted_desc(&totals)[0].0, "ogg");
+    }
+
+    #[test]
+    fn measurements_over_budget_fail() {
+        assert!(check_budget("assets", Some(2048), Some("1 KB")).is_err());
+        assert!(check_budget("assets", Some(512), Some("1 KB")).is_ok());
+        assert!(check_budget("assets", None, Some("1 KB")).is_ok());
+        assert!(check_budget("assets", Some(u64::MAX), None).is_ok());
+    }
+}
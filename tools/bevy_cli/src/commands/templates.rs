@@ -0,0 +1,450 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+use clap::{Args, Subcommand};
+
+use crate::i18n::localize;
+use crate::output;
+use crate::template::analyze::{self, Analysis, BUILTIN_VARS};
+use crate::template::manifest::TemplateManifest;
+use crate::template::render;
+use crate::template::source::TemplateSource;
+
+#[derive(Args)]
+pub struct TemplatesArgs {
+    #[command(subcommand)]
+    pub command: TemplatesCommand,
+}
+
+#[derive(Subcommand)]
+pub enum TemplatesCommand {
+    /// Report which context variables and filters a template uses, and
+    /// check them against the manifest declarations
+    Analyze {
+        /// Template directory or installed template name
+        template: PathBuf,
+    },
+
+    /// Emit a DOT graph of a template's file relationships: extends,
+    /// include and import edges, the conditions gating each file, and
+    /// which files are raw-copied
+    Graph {
+        /// Template directory or installed template name
+        template: PathBuf,
+
+        /// Write the graph here instead of stdout
+        #[arg(long, value_name = "FILE")]
+        out: Option<PathBuf>,
+    },
+
+    /// Render two templates with the same context and show how the
+    /// generated projects would differ
+    Compare {
+        /// First template (directory or installed name)
+        a: PathBuf,
+        /// Second template (directory or installed name)
+        b: PathBuf,
+        /// Context for the comparison render, `key=value`; keys a template
+        /// does not declare are ignored for that template (repeatable)
+        #[arg(long = "context", value_name = "KEY=VALUE")]
+        context: Vec<String>,
+    },
+}
+
+pub fn run(args: TemplatesArgs) -> anyhow::Result<()> {
+    match args.command {
+        TemplatesCommand::Analyze { template } => analyze_template(&template),
+        TemplatesCommand::Graph { template, out } => graph_template(&template, out.as_deref()),
+        TemplatesCommand::Compare { a, b, context } => compare_templates(&a, &b, &context),
+    }
+}
+
+/// Resolves a template argument: an existing directory wins, otherwise the
+/// installed template of that name.
+fn resolve_source(template: &PathBuf) -> anyhow::Result<TemplateSource> {
+    if template.exists() {
+        return Ok(TemplateSource::Dir(template.clone()));
+    }
+    let installed = crate::registry::installed_templates_dir()?.join(template);
+    anyhow::ensure!(
+        installed.is_dir(),
+        "`{}` is neither a directory nor an installed template",
+        template.display()
+    );
+    Ok(TemplateSource::Dir(installed))
+}
+
+/// Scans every renderable file (and path) of a template and reports drift
+/// between the variables it uses and the manifest's declarations. Unused
+/// declarations warn; variables used without a declaration fail, since
+/// rendering would too.
+fn analyze_template(template: &PathBuf) -> anyhow::Result<()> {
+    let source = resolve_source(template)?;
+    let manifest = match source.manifest_contents()? {
+        Some(contents) => TemplateManifest::parse(&contents)?,
+        None => TemplateManifest::default(),
+    };
+    let raw_copy = render::compile_globs(&manifest.raw_copy)?;
+
+    let mut analysis = Analysis::default();
+    for entry in source.entries()? {
+        if render::matches_any(&raw_copy, &entry.rel_path) {
+            continue;
+        }
+        if let Some(path) = entry.rel_path.to_str() {
+            analyze::scan(path, &mut analysis);
+        }
+        if let Ok(text) = std::str::from_utf8(&entry.contents) {
+            analyze::scan(text, &mut analysis);
+        }
+    }
+
+    let declared: BTreeSet<&str> = manifest.vars.keys().map(String::as_str).collect();
+    let used: BTreeSet<&str> = analysis.context_variables();
+    // A used name that is neither declared nor built-in is a render error
+    // waiting to happen.
+    let undeclared: Vec<&str> = used
+        .iter()
+        .filter(|name| !declared.contains(**name) && !BUILTIN_VARS.contains(*name))
+        .copied()
+        .collect();
+    let unused: Vec<&str> = declared.difference(&used).copied().collect();
+
+    println!(
+        "{}",
+        localize!("analyze-variables", names = join_or_dash(&used))
+    );
+    let filters: BTreeSet<&str> = analysis.filters.iter().map(String::as_str).collect();
+    println!(
+        "{}",
+        localize!("analyze-filters", names = join_or_dash(&filters))
+    );
+    for name in &unused {
+        output::warn(&localize!("analyze-unused-var", name = name));
+    }
+    if undeclared.is_empty() {
+        output::ok(&localize!("analyze-in-sync"));
+        Ok(())
+    } else {
+        anyhow::bail!(localize!(
+            "analyze-undeclared",
+            count = undeclared.len(),
+            names = undeclared.join(", ")
+        ));
+    }
+}
+
+fn join_or_dash(names: &BTreeSet<&str>) -> String {
+    if names.is_empty() {
+        "-".to_string()
+    } else {
+        names.iter().copied().collect::<Vec<_>>().join(", ")
+    }
+}
+
+/// Emits a Graphviz DOT description of a template: one node per file,
+/// labeled with the variables gating it through `{% if %}` conditions,
+/// edges for Tera `extends`/`include`/`import` references, and dashed
+/// nodes for files the manifest raw-copies past the renderer.
+fn graph_template(template: &PathBuf, out: Option<&Path>) -> anyhow::Result<()> {
+    let source = resolve_source(template)?;
+    let manifest = match source.manifest_contents()? {
+        Some(contents) => TemplateManifest::parse(&contents)?,
+        None => TemplateManifest::default(),
+    };
+    let raw_copy = render::compile_globs(&manifest.raw_copy)?;
+
+    let mut dot = String::from("digraph template {\n    rankdir = LR;\n    node [shape = box, fontname = \"monospace\"];\n");
+    for entry in source.entries()? {
+        let Some(path) = entry.rel_path.to_str() else {
+            continue;
+        };
+        if path == crate::template::manifest::MANIFEST_FILE {
+            continue;
+        }
+        if render::matches_any(&raw_copy, &entry.rel_path) {
+            dot.push_str(&format!("    \"{path}\" [style = dashed];\n"));
+            continue;
+        }
+        let Ok(text) = std::str::from_utf8(&entry.contents) else {
+            dot.push_str(&format!("    \"{path}\";\n"));
+            continue;
+        };
+        let conditions = condition_vars(text);
+        if conditions.is_empty() {
+            dot.push_str(&format!("    \"{path}\";\n"));
+        } else {
+            dot.push_str(&format!(
+                "    \"{path}\" [label = \"{path}\\nif: {}\"];\n",
+                conditions.join(", ")
+            ));
+        }
+        for (directive, referenced) in file_references(text) {
+            dot.push_str(&format!(
+                "    \"{path}\" -> \"{referenced}\" [label = \"{directive}\"];\n"
+            ));
+        }
+    }
+    dot.push_str("}\n");
+
+    match out {
+        Some(out) => {
+            crate::fs_util::write_file(out, dot.as_bytes(), false)?;
+            output::ok(&localize!("graph-written", file = out.display()));
+        }
+        None => print!("{dot}"),
+    }
+    Ok(())
+}
+
+/// The `extends`/`include`/`import` directives of a Tera file, as
+/// `(directive, referenced file)` pairs in source order.
+fn file_references(text: &str) -> Vec<(String, String)> {
+    let directive = regex::Regex::new(r#"\{%-?\s*(extends|include|import)\s+"([^"]+)""#)
+        .expect("directive pattern compiles");
+    directive
+        .captures_iter(text)
+        .map(|captures| (captures[1].to_string(), captures[2].to_string()))
+        .collect()
+}
+
+/// The variable names appearing in the file's `{% if %}` and `{% elif %}`
+/// conditions, deduplicated in first-use order — the conditions that gate
+/// what the file renders.
+fn condition_vars(text: &str) -> Vec<String> {
+    let conditional = regex::Regex::new(r"\{%-?\s*(?:el)?if\s+(.+?)\s*-?%\}")
+        .expect("conditional pattern compiles");
+    let identifier = regex::Regex::new(r"[A-Za-z_][A-Za-z0-9_]*").expect("identifier compiles");
+    let mut vars = Vec::new();
+    for captures in conditional.captures_iter(text) {
+        for name in identifier.find_iter(&captures[1]) {
+            let name = name.as_str();
+            // Keywords and literals are not variables.
+            if matches!(name, "and" | "or" | "not" | "in" | "is" | "true" | "false") {
+                continue;
+            }
+            if !vars.iter().any(|existing| existing == name) {
+                vars.push(name.to_string());
+            }
+        }
+    }
+    vars
+}
+
+/// Renders both templates with the same context and reports, per rendered
+/// path, whether it exists only on one side or differs in content — the
+/// projects the templates would generate are compared, not their sources,
+/// so manifest defaults and `raw_copy` rules are part of the comparison.
+fn compare_templates(a: &PathBuf, b: &PathBuf, context: &[String]) -> anyhow::Result<()> {
+    let first = render_virtual(a, "a", context)?;
+    let second = render_virtual(b, "b", context)?;
+
+    let paths: BTreeSet<&String> = first.keys().chain(second.keys()).collect();
+    let mut differences = 0usize;
+    let mut identical = 0usize;
+    for path in paths {
+        match (first.get(path), second.get(path)) {
+            (Some(_), None) => {
+                println!(
+                    "{}",
+                    localize!("compare-only", template = a.display(), file = path)
+                );
+                differences += 1;
+            }
+            (None, Some(_)) => {
+                println!(
+                    "{}",
+                    localize!("compare-only", template = b.display(), file = path)
+                );
+                differences += 1;
+            }
+            (Some(left), Some(right)) if left == right => identical += 1,
+            (Some(left), Some(right)) => {
+                differences += 1;
+                match (std::str::from_utf8(left), std::str::from_utf8(right)) {
+                    (Ok(left), Ok(right)) => {
+                        println!("{}", localize!("compare-differs", file = path));
+                        for line in line_diff(left, right) {
+                            println!("  {line}");
+                        }
+                    }
+                    _ => println!("{}", localize!("compare-binary-differs", file = path)),
+                }
+            }
+            (None, None) => unreachable!("path came from one of the maps"),
+        }
+    }
+
+    if differences == 0 {
+        output::ok(&localize!("compare-identical", count = identical));
+    } else {
+        println!(
+            "{}",
+            localize!(
+                "compare-summary",
+                count = differences,
+                identical = identical
+            )
+        );
+    }
+    Ok(())
+}
+
+/// Renders a template into a throwaway directory and returns its files
+/// keyed by path (with `/` separators). Context keys the template does not
+/// declare are dropped rather than rejected, so one `--context` can serve
+/// two templates with different variable sets; built-in variables get the
+/// same defaults `bevy new` would use.
+fn render_virtual(
+    template: &PathBuf,
+    side: &str,
+    context: &[String],
+) -> anyhow::Result<BTreeMap<String, Vec<u8>>> {
+    use crate::template::vars::{self, VarValue};
+
+    let source = resolve_source(template)?;
+    let manifest = match source.manifest_contents()? {
+        Some(contents) => TemplateManifest::parse(&contents)?,
+        None => TemplateManifest::default(),
+    };
+
+    let declared: Vec<String> = context
+        .iter()
+        .filter(|flag| {
+            flag.split_once('=')
+                .is_some_and(|(key, _)| manifest.vars.contains_key(key))
+        })
+        .cloned()
+        .collect();
+    let mut values = vars::resolve(&manifest.vars, &declared)?;
+    let overridden = |key: &str, default: &str| {
+        context
+            .iter()
+            .filter_map(|flag| flag.split_once('='))
+            .find(|(name, _)| *name == key)
+            .map(|(_, value)| value.to_string())
+            .unwrap_or_else(|| default.to_string())
+    };
+    let name = overridden("project_name", "my_game");
+    values.insert(
+        "plugin_struct".to_string(),
+        VarValue::String(format!("{}Plugin", super::new::pascal_case(&name))),
+    );
+    values.insert("project_name".to_string(), VarValue::String(name));
+    for (key, default) in [
+        ("bevy_version", "0.12"),
+        ("author", ""),
+        ("email", ""),
+        ("authors", ""),
+    ] {
+        values.insert(key.to_string(), VarValue::String(overridden(key, default)));
+    }
+    values
+        .entry("bevy_features".to_string())
+        .or_insert(VarValue::List(Vec::new()));
+
+    let target = std::env::temp_dir().join(format!("bevy-compare-{}-{side}", std::process::id()));
+    if target.exists() {
+        std::fs::remove_dir_all(&target)?;
+    }
+    let rendered = render::render(&source, &manifest, &target, &values);
+    let files = rendered.and_then(|()| collect_rendered(&target));
+    let _ = std::fs::remove_dir_all(&target);
+    files.with_context(|| format!("failed to render {}", template.display()))
+}
+
+/// Reads a rendered tree into memory, keyed by `/`-separated paths so the
+/// two sides compare identically on every platform.
+fn collect_rendered(root: &Path) -> anyhow::Result<BTreeMap<String, Vec<u8>>> {
+    fn visit(root: &Path, dir: &Path, files: &mut BTreeMap<String, Vec<u8>>) -> anyhow::Result<()> {
+        for entry in std::fs::read_dir(dir)? {
+            let path = entry?.path();
+            if path.is_dir() {
+                visit(root, &path, files)?;
+            } else {
+                let rel = path
+                    .strip_prefix(root)?
+                    .components()
+                    .map(|component| component.as_os_str().to_string_lossy())
+                    .collect::<Vec<_>>()
+                    .join("/");
+                files.insert(rel, std::fs::read(&path)?);
+            }
+        }
+        Ok(())
+    }
+    let mut files = BTreeMap::new();
+    visit(root, root, &mut files)?;
+    Ok(files)
+}
+
+/// A minimal line diff: longest-common-subsequence over lines, emitting
+/// only the `-`/`+` lines. Rendered template files are small, so the
+/// quadratic table is fine.
+pub(crate) fn line_diff(left: &str, right: &str) -> Vec<String> {
+    let left: Vec<&str> = left.lines().collect();
+    let right: Vec<&str> = right.lines().collect();
+    let mut lcs = vec![vec![0usize; right.len() + 1]; left.len() + 1];
+    for (i, left_line) in left.iter().enumerate().rev() {
+        for (j, right_line) in right.iter().enumerate().rev() {
+            lcs[i][j] = if left_line == right_line {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+    let (mut i, mut j) = (0, 0);
+    let mut diff = Vec::new();
+    while i < left.len() && j < right.len() {
+        if left[i] == right[j] {
+            i += 1;
+            j += 1;
+        } else if lcs[i + 1][j] >= lcs[i][j + 1] {
+            diff.push(format!("-{}", left[i]));
+            i += 1;
+        } else {
+            diff.push(format!("+{}", right[j]));
+            j += 1;
+        }
+    }
+    diff.extend(left[i..].iter().map(|line| format!("-{line}")));
+    diff.extend(right[j..].iter().map(|line| format!("+{line}")));
+    diff
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn line_diff_reports_changed_lines_only() {
+        let diff = line_diff("a\nb\nc\n", "a\nx\nc\nd\n");
+        assert_eq!(diff, vec!["-b", "+x", "+d"]);
+    }
+
+    #[test]
+    fn line_diff_of_identical_text_is_empty() {
+        assert!(line_diff("a\nb\n", "a\nb\n").is_empty());
+    }
+
+    #[test]
+    fn tera_directives_become_graph_edges() {
+        let refs = file_references("{% extends \"base.rs.tera\" %}\n{%- include \"ui.tera\" %}");
+        assert_eq!(
+            refs,
+            vec![
+                ("extends".to_string(), "base.rs.tera".to_string()),
+                ("include".to_string(), "ui.tera".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn condition_vars_skip_keywords_and_deduplicate() {
+        let vars =
+            condition_vars("{% if with_assets and not ci %}x{% elif with_assets %}y{% endif %}");
+        assert_eq!(vars, vec!["with_assets", "ci"]);
+    }
+}
@@ -0,0 +1,123 @@
+//! `bevy test`: `cargo test` set up for headless Bevy.
+//!
+//! Integration tests that build an `App` need a renderer even on machines
+//! without a display. The wrapper points wgpu and GL at software rendering
+//! and strips the window requirement via environment defaults, so projects
+//! do not carry per-repo CI hacks. An explicit environment always wins.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+use clap::Args;
+
+#[derive(Args)]
+pub struct TestArgs {
+    /// Project directory; defaults to the nearest project root
+    #[arg(long)]
+    pub project: Option<PathBuf>,
+
+    /// Test with the release profile
+    #[arg(long)]
+    pub release: bool,
+
+    /// Cargo features to enable, comma-separated or repeated
+    #[arg(long, value_delimiter = ',')]
+    pub features: Vec<String>,
+
+    /// Keep the environment untouched and only forward to cargo
+    #[arg(long)]
+    pub no_headless: bool,
+
+    /// Arguments passed through to the test harness
+    #[arg(last = true)]
+    pub args: Vec<String>,
+}
+
+/// Environment defaults for headless test runs: software GL for the GL
+/// backend, llvmpipe for Mesa, and no GPU power preference games. Applied
+/// only where the variable is not already set.
+const HEADLESS_ENV: &[(&str, &str)] = &[
+    ("LIBGL_ALWAYS_SOFTWARE", "1"),
+    ("GALLIUM_DRIVER", "llvmpipe"),
+    ("WGPU_BACKEND", "gl"),
+    ("WGPU_POWER_PREF", "low"),
+];
+
+pub fn run(args: TestArgs) -> anyhow::Result<()> {
+    let project = args
+        .project
+        .clone()
+        .unwrap_or_else(|| crate::project::locate(Path::new(".")));
+    anyhow::ensure!(
+        project.join("Cargo.toml").is_file(),
+        "{} does not contain a Cargo.toml",
+        project.display()
+    );
+    let mut command = std::process::Command::new("cargo");
+    command.args(cargo_args(&args)).current_dir(&project);
+    if std::env::var_os("RUST_BACKTRACE").is_none() {
+        command.env("RUST_BACKTRACE", "1");
+    }
+    if !args.no_headless {
+        for (name, value) in HEADLESS_ENV {
+            if std::env::var_os(name).is_none() {
+                command.env(name, value);
+            }
+        }
+    }
+    let status = command.status().context("failed to run cargo")?;
+    anyhow::ensure!(status.success(), "cargo test failed");
+    Ok(())
+}
+
+/// The cargo argument vector for this invocation; separated from [`run`] so
+/// the flag plumbing is testable without spawning cargo.
+fn cargo_args(args: &TestArgs) -> Vec<String> {
+    let mut cargo = vec!["test".to_string()];
+    if args.release {
+        cargo.push("--release".to_string());
+    }
+    if !args.features.is_empty() {
+        cargo.push("--features".to_string());
+        cargo.push(args.features.join(","));
+    }
+    if !args.args.is_empty() {
+        cargo.push("--".to_string());
+        cargo.extend(args.args.iter().cloned());
+    }
+    cargo
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn features_and_harness_arguments_are_forwarded() {
+        let test = TestArgs {
+            project: None,
+            release: true,
+            features: vec!["headless".to_string()],
+            no_headless: false,
+            args: vec!["--nocapture".to_string()],
+        };
+        assert_eq!(
+            cargo_args(&test),
+            vec![
+                "test",
+                "--release",
+                "--features",
+                "headless",
+                "--",
+                "--nocapture"
+            ]
+        );
+    }
+
+    #[test]
+    fn headless_defaults_cover_the_gl_stack() {
+        let names: Vec<_> = HEADLESS_ENV.iter().map(|(name, _)| *name).collect();
+        assert!(names.contains(&"LIBGL_ALWAYS_SOFTWARE"));
+        assert!(names.contains(&"WGPU_BACKEND"));
+    }
+}
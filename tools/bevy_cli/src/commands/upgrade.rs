@@ -0,0 +1,268 @@
+//! `bevy upgrade`: bump an existing project to a newer Bevy.
+//!
+//! Rewrites the `bevy` requirement (and the known ecosystem crates that
+//! track Bevy releases) in every manifest of the project via `toml_edit`,
+//! so formatting and comments survive, then runs `cargo update` and prints
+//! the migration guide for each release crossed by the jump.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+use clap::Args;
+
+use crate::i18n::localize;
+use crate::{output, versions};
+
+#[derive(Args)]
+pub struct UpgradeArgs {
+    /// Project directory; defaults to the nearest project root
+    #[arg(long)]
+    pub project: Option<PathBuf>,
+
+    /// Bevy version to upgrade to; `latest` asks crates.io
+    #[arg(long, default_value = "latest")]
+    pub to: String,
+
+    /// Show what would change without touching any file
+    #[arg(long)]
+    pub dry_run: bool,
+}
+
+/// Ecosystem crates whose releases track Bevy's, with the version to pin
+/// per Bevy minor. Kept in step with the incompatibility table in `check`.
+pub(crate) const ECOSYSTEM: &[(&str, &[(&str, &str)])] = &[
+    (
+        "0.12",
+        &[
+            ("bevy_rapier2d", "0.23"),
+            ("bevy_rapier3d", "0.23"),
+            ("bevy_egui", "0.23"),
+            ("leafwing-input-manager", "0.11"),
+        ],
+    ),
+    (
+        "0.11",
+        &[
+            ("bevy_rapier2d", "0.22"),
+            ("bevy_rapier3d", "0.22"),
+            ("bevy_egui", "0.21"),
+            ("leafwing-input-manager", "0.10"),
+        ],
+    ),
+];
+
+/// Bevy minors with a published migration guide, oldest first.
+const GUIDE_MINORS: &[&str] = &["0.9", "0.10", "0.11", "0.12"];
+
+pub fn run(args: UpgradeArgs) -> anyhow::Result<()> {
+    let project = args
+        .project
+        .clone()
+        .unwrap_or_else(|| crate::project::locate(Path::new(".")));
+    anyhow::ensure!(
+        project.join("Cargo.toml").is_file(),
+        "{} does not contain a Cargo.toml",
+        project.display()
+    );
+    let target = versions::resolve(&args.to)?;
+
+    let mut manifests = vec![project.join("Cargo.toml")];
+    if let Ok(entries) = std::fs::read_dir(project.join("crates")) {
+        manifests.extend(
+            entries
+                .flatten()
+                .map(|entry| entry.path().join("Cargo.toml"))
+                .filter(|manifest| manifest.is_file()),
+        );
+    }
+
+    let mut previous = None;
+    let mut changes = 0usize;
+    for manifest in &manifests {
+        let contents = std::fs::read_to_string(manifest)
+            .with_context(|| format!("failed to read {}", manifest.display()))?;
+        let (edited, bumped) = bump_manifest(&contents, &target)?;
+        for bump in &bumped {
+            if bump.name == "bevy" && previous.is_none() {
+                previous = Some(bump.from.clone());
+            }
+            println!(
+                "{}",
+                localize!(
+                    "upgrade-bumped",
+                    name = bump.name,
+                    from = bump.from,
+                    to = bump.to
+                )
+            );
+            changes += 1;
+        }
+        if !args.dry_run && edited != contents {
+            std::fs::write(manifest, edited)
+                .with_context(|| format!("failed to write {}", manifest.display()))?;
+        }
+    }
+    if changes == 0 {
+        output::ok(&localize!("upgrade-nothing", version = target));
+        return Ok(());
+    }
+
+    for url in migration_links(previous.as_deref().unwrap_or_default(), &target) {
+        println!("{}", localize!("upgrade-guide", url = url));
+    }
+    if args.dry_run {
+        println!("{}", localize!("upgrade-dry-run", count = changes));
+        return Ok(());
+    }
+    crate::subprocess::Subprocess::new("cargo")
+        .arg("update")
+        .current_dir(&project)
+        .run()?;
+    output::ok(&localize!(
+        "upgrade-done",
+        version = target,
+        count = changes
+    ));
+    Ok(())
+}
+
+/// One requirement change made (or proposed) in a manifest.
+struct Bump {
+    name: String,
+    from: String,
+    to: String,
+}
+
+/// Rewrites the version requirements in one manifest, returning the edited
+/// text and every requirement that changed. Handles plain-string
+/// dependencies, tables with a `version` key, and `[workspace.dependencies]`.
+fn bump_manifest(contents: &str, bevy: &str) -> anyhow::Result<(String, Vec<Bump>)> {
+    let mut doc: toml_edit::Document = contents.parse().context("invalid Cargo.toml")?;
+    let minor = minor_of(bevy);
+    let pins: Vec<(&str, &str)> = ECOSYSTEM
+        .iter()
+        .find(|(bevy_minor, _)| *bevy_minor == minor)
+        .map(|(_, pins)| pins.to_vec())
+        .unwrap_or_default();
+    let mut bumped = Vec::new();
+    let tables = [
+        vec!["dependencies"],
+        vec!["dev-dependencies"],
+        vec!["build-dependencies"],
+        vec!["workspace", "dependencies"],
+    ];
+    for path in &tables {
+        let Some(table) = table_at(&mut doc, path) else {
+            continue;
+        };
+        let names: Vec<String> = table.iter().map(|(name, _)| name.to_string()).collect();
+        for name in names {
+            let new_version = if name == "bevy" {
+                bevy
+            } else if let Some((_, version)) = pins.iter().find(|(pin, _)| *pin == name) {
+                version
+            } else {
+                continue;
+            };
+            let Some(dependency) = table.get_mut(&name) else {
+                continue;
+            };
+            let slot = if dependency.is_str() {
+                Some(dependency)
+            } else {
+                dependency
+                    .as_table_like_mut()
+                    .and_then(|dependency| dependency.get_mut("version"))
+            };
+            let Some(slot) = slot else { continue };
+            let old = slot.as_str().unwrap_or_default().to_string();
+            if old == new_version {
+                continue;
+            }
+            *slot = toml_edit::value(new_version);
+            bumped.push(Bump {
+                name,
+                from: old,
+                to: new_version.to_string(),
+            });
+        }
+    }
+    Ok((doc.to_string(), bumped))
+}
+
+/// The table-like item at a dotted path in the document, if present.
+fn table_at<'a>(
+    doc: &'a mut toml_edit::Document,
+    path: &[&str],
+) -> Option<&'a mut dyn toml_edit::TableLike> {
+    let mut item = doc.as_item_mut();
+    for segment in path {
+        item = item.get_mut(segment)?;
+    }
+    item.as_table_like_mut()
+}
+
+/// `0.12.1` -> `0.12`.
+fn minor_of(version: &str) -> String {
+    version
+        .trim_start_matches(['^', '=', '~'])
+        .split('.')
+        .take(2)
+        .collect::<Vec<_>>()
+        .join(".")
+}
+
+/// Migration guide URLs for every release step between `from` and `to`.
+fn migration_links(from: &str, to: &str) -> Vec<String> {
+    let position = |version: &str| {
+        GUIDE_MINORS
+            .iter()
+            .position(|minor| *minor == minor_of(version))
+    };
+    let (Some(start), Some(end)) = (position(from), position(to)) else {
+        return Vec::new();
+    };
+    (start..end)
+        .map(|step| {
+            format!(
+                "https://bevyengine.org/learn/migration-guides/{}-to-{}/",
+                GUIDE_MINORS[step],
+                GUIDE_MINORS[step + 1]
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn all_dependency_shapes_are_bumped() {
+        let manifest = "[dependencies]\n\
+                        bevy = \"0.11\"\n\
+                        bevy_rapier3d = { version = \"0.22\", features = [\"simd-stable\"] }\n\
+                        serde = \"1\"\n\
+                        [workspace.dependencies]\n\
+                        bevy_egui = \"0.21\"\n";
+        let (edited, bumped) = bump_manifest(manifest, "0.12").unwrap();
+        assert!(edited.contains("bevy = \"0.12\""));
+        assert!(edited.contains("version = \"0.23\", features = [\"simd-stable\"]"));
+        assert!(edited.contains("bevy_egui = \"0.23\""));
+        assert!(edited.contains("serde = \"1\""));
+        assert_eq!(bumped.len(), 3);
+        assert_eq!(bumped[0].name, "bevy");
+    }
+
+    #[test]
+    fn migration_links_cover_each_release_step() {
+        assert_eq!(
+            migration_links("0.10.1", "0.12"),
+            vec![
+                "https://bevyengine.org/learn/migration-guides/0.10-to-0.11/",
+                "https://bevyengine.org/learn/migration-guides/0.11-to-0.12/",
+            ]
+        );
+        assert!(migration_links("0.12", "0.12").is_empty());
+    }
+}
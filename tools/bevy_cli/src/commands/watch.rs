@@ -0,0 +1,269 @@
+//! `bevy watch`: rebuild and rerun the game on change.
+//!
+//! Polls the source and asset trees like `bevy serve` does for web builds,
+//! but for native development: a code change kills the running game and
+//! relaunches it through `cargo run`, while an asset-only change leaves the
+//! process alone — Bevy's asset server hot-reloads changed files in place.
+//! Debounce and ignore patterns live under `[watch]` in `Bevy.toml` so
+//! editors that write temp files or churn large directories can be tuned
+//! out per project.
+
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use anyhow::Context;
+use clap::Args;
+use serde::Deserialize;
+
+use crate::i18n::localize;
+use crate::output;
+
+#[derive(Args)]
+pub struct WatchArgs {
+    /// Project directory; defaults to the nearest project root
+    #[arg(long)]
+    pub project: Option<PathBuf>,
+
+    /// Run the release profile instead of dev
+    #[arg(long)]
+    pub release: bool,
+
+    /// Cargo features to enable, comma-separated or repeated
+    #[arg(long, value_delimiter = ',')]
+    pub features: Vec<String>,
+
+    /// Arguments passed through to the game binary
+    #[arg(last = true)]
+    pub args: Vec<String>,
+}
+
+/// The `[watch]` section of `Bevy.toml`.
+#[derive(Debug, Default, Deserialize)]
+struct WatchSection {
+    /// How long the tree must stay quiet before a restart, in milliseconds.
+    #[serde(default)]
+    debounce_ms: Option<u64>,
+    /// Glob patterns, relative to the project root, that never trigger.
+    #[serde(default)]
+    ignore: Vec<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct ProjectConfig {
+    #[serde(default)]
+    watch: WatchSection,
+}
+
+/// How often the watcher compares the trees.
+const POLL_INTERVAL: Duration = Duration::from_millis(1000);
+
+/// Quiet period before a restart when `[watch] debounce_ms` is absent.
+const DEFAULT_DEBOUNCE: Duration = Duration::from_millis(300);
+
+pub fn run(args: WatchArgs) -> anyhow::Result<()> {
+    let project = args
+        .project
+        .clone()
+        .unwrap_or_else(|| crate::project::locate(Path::new(".")));
+    anyhow::ensure!(
+        project.join("Cargo.toml").is_file(),
+        "{} does not contain a Cargo.toml",
+        project.display()
+    );
+    let config = load_config(&project)?;
+    let debounce = config
+        .watch
+        .debounce_ms
+        .map_or(DEFAULT_DEBOUNCE, Duration::from_millis);
+    let ignore = compile_ignore(&config.watch.ignore)?;
+
+    println!("{}", localize!("watch-started", path = project.display()));
+    let mut game = Some(launch(&project, &args)?);
+    let mut code = code_stamp(&project, &ignore);
+    let mut assets = asset_stamp(&project, &ignore);
+    loop {
+        std::thread::sleep(POLL_INTERVAL);
+        if let Some(child) = &mut game {
+            if let Some(status) = child.try_wait()? {
+                // A crashed or quit game is not respawned until the next
+                // edit, so a startup panic does not loop at full speed.
+                println!(
+                    "{}",
+                    localize!(
+                        "watch-exited",
+                        code = status
+                            .code()
+                            .map_or_else(|| "signal".to_string(), |code| code.to_string())
+                    )
+                );
+                game = None;
+            }
+        }
+        let current_assets = asset_stamp(&project, &ignore);
+        if current_assets != assets {
+            assets = current_assets;
+            println!("{}", localize!("watch-assets"));
+        }
+        let current_code = code_stamp(&project, &ignore);
+        if current_code == code {
+            continue;
+        }
+        // Debounce: wait until the tree stays unchanged for the configured
+        // quiet period, so an editor save-all triggers one restart.
+        code = current_code;
+        let mut quiet_since = Instant::now();
+        while quiet_since.elapsed() < debounce {
+            std::thread::sleep(debounce.min(POLL_INTERVAL));
+            let settled = code_stamp(&project, &ignore);
+            if settled != code {
+                code = settled;
+                quiet_since = Instant::now();
+            }
+        }
+        if let Some(mut child) = game.take() {
+            let _ = child.kill();
+            let _ = child.wait();
+        }
+        println!("{}", localize!("watch-restarting"));
+        match launch(&project, &args) {
+            Ok(child) => game = Some(child),
+            Err(error) => output::warn(&format!("relaunch failed: {error:#}")),
+        }
+    }
+}
+
+/// Spawns the game through `cargo run`; compile errors surface on the
+/// child's inherited stderr and the watcher keeps going.
+fn launch(project: &Path, args: &WatchArgs) -> anyhow::Result<std::process::Child> {
+    let mut command = std::process::Command::new("cargo");
+    command.args(cargo_args(args)).current_dir(project);
+    if std::env::var_os("RUST_BACKTRACE").is_none() {
+        command.env("RUST_BACKTRACE", "1");
+    }
+    command.spawn().context("failed to run cargo")
+}
+
+/// The cargo argument vector for a (re)launch; separated for testing.
+fn cargo_args(args: &WatchArgs) -> Vec<String> {
+    let mut cargo = vec!["run".to_string()];
+    if args.release {
+        cargo.push("--release".to_string());
+    }
+    if !args.features.is_empty() {
+        cargo.push("--features".to_string());
+        cargo.push(args.features.join(","));
+    }
+    if !args.args.is_empty() {
+        cargo.push("--".to_string());
+        cargo.extend(args.args.iter().cloned());
+    }
+    cargo
+}
+
+fn compile_ignore(patterns: &[String]) -> anyhow::Result<Vec<glob::Pattern>> {
+    patterns
+        .iter()
+        .map(|pattern| {
+            glob::Pattern::new(pattern).with_context(|| format!("bad ignore pattern `{pattern}`"))
+        })
+        .collect()
+}
+
+/// Whether a project-relative path matches any `[watch] ignore` pattern.
+fn is_ignored(relative: &Path, ignore: &[glob::Pattern]) -> bool {
+    ignore.iter().any(|pattern| pattern.matches_path(relative))
+}
+
+/// Everything a rebuild depends on: sources, manifests, build config.
+fn code_stamp(project: &Path, ignore: &[glob::Pattern]) -> u64 {
+    let mut roots = vec![project.join("src"), project.join("crates")];
+    roots.push(project.join("Cargo.toml"));
+    roots.push(project.join(crate::project::MANIFEST));
+    tree_stamp(project, roots, ignore)
+}
+
+fn asset_stamp(project: &Path, ignore: &[glob::Pattern]) -> u64 {
+    tree_stamp(project, vec![project.join("assets")], ignore)
+}
+
+/// A cheap fingerprint — path, size and mtime — of the given roots, with
+/// ignored paths skipped; the same trade-off as the `bevy serve` watcher.
+fn tree_stamp(project: &Path, roots: Vec<PathBuf>, ignore: &[glob::Pattern]) -> u64 {
+    use std::hash::{Hash, Hasher};
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    let mut stack = roots;
+    while let Some(path) = stack.pop() {
+        let relative = path.strip_prefix(project).unwrap_or(&path);
+        if is_ignored(relative, ignore) {
+            continue;
+        }
+        if path.is_dir() {
+            if let Ok(entries) = std::fs::read_dir(&path) {
+                stack.extend(entries.flatten().map(|entry| entry.path()));
+            }
+            continue;
+        }
+        let Ok(metadata) = path.metadata() else {
+            continue;
+        };
+        path.to_string_lossy().hash(&mut hasher);
+        metadata.len().hash(&mut hasher);
+        if let Ok(modified) = metadata.modified() {
+            modified.hash(&mut hasher);
+        }
+    }
+    hasher.finish()
+}
+
+fn load_config(project: &Path) -> anyhow::Result<ProjectConfig> {
+    let manifest_path = project.join(crate::project::MANIFEST);
+    if !manifest_path.is_file() {
+        return Ok(ProjectConfig::default());
+    }
+    toml::from_str(&std::fs::read_to_string(&manifest_path)?)
+        .with_context(|| format!("failed to parse {}", manifest_path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ignore_patterns_match_project_relative_paths() {
+        let ignore =
+            compile_ignore(&["assets/generated/**".to_string(), "*.tmp".to_string()]).unwrap();
+        assert!(is_ignored(Path::new("assets/generated/atlas.png"), &ignore));
+        assert!(is_ignored(Path::new("scratch.tmp"), &ignore));
+        assert!(!is_ignored(Path::new("assets/sprites/hero.png"), &ignore));
+    }
+
+    #[test]
+    fn watch_sections_parse_debounce_and_ignores() {
+        let config: ProjectConfig =
+            toml::from_str("[watch]\ndebounce_ms = 500\nignore = [\"*.blend\"]\n").unwrap();
+        assert_eq!(config.watch.debounce_ms, Some(500));
+        assert_eq!(config.watch.ignore, vec!["*.blend"]);
+    }
+
+    #[test]
+    fn release_watches_pass_features_and_game_arguments() {
+        let args = WatchArgs {
+            project: None,
+            release: true,
+            features: vec!["devtools".to_string()],
+            args: vec!["--level".to_string(), "3".to_string()],
+        };
+        assert_eq!(
+            cargo_args(&args),
+            vec![
+                "run",
+                "--release",
+                "--features",
+                "devtools",
+                "--",
+                "--level",
+                "3"
+            ]
+        );
+    }
+}
@@ -0,0 +1,116 @@
+use std::path::PathBuf;
+
+use anyhow::Context;
+use serde::Deserialize;
+
+/// Configuration of the CLI itself, loaded from `bevy/config.toml` in the
+/// platform config directory (e.g. `~/.config/bevy/config.toml`).
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CliConfig {
+    /// Template registries, queried in descending `priority` order.
+    #[serde(default)]
+    pub registries: Vec<RegistrySpec>,
+    /// Always use the screen-reader friendly output mode, as if
+    /// `--accessible` were passed.
+    #[serde(default)]
+    pub accessible: bool,
+}
+
+/// A source of installable templates: a local directory or a git URL hosting
+/// a `templates.toml` index.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RegistrySpec {
+    pub name: String,
+    pub url: String,
+    /// Registries with a higher priority are queried first.
+    #[serde(default)]
+    pub priority: i32,
+    /// Name of an environment variable holding an access token for this
+    /// registry, injected into https URLs when fetching.
+    #[serde(default)]
+    pub auth: Option<String>,
+}
+
+/// The registry queried when the user has not configured any.
+const DEFAULT_REGISTRY_NAME: &str = "bevy-assets";
+const DEFAULT_REGISTRY_URL: &str = "https://github.com/bevyengine/bevy-assets";
+
+impl CliConfig {
+    pub fn config_path() -> Option<PathBuf> {
+        dirs::config_dir().map(|dir| dir.join("bevy").join("config.toml"))
+    }
+
+    /// Loads the config file if present, then applies defaults and the
+    /// deprecated `BEVY_ASSETS_REPO` override.
+    pub fn load() -> anyhow::Result<Self> {
+        let mut config = match Self::config_path().filter(|path| path.exists()) {
+            Some(path) => {
+                let contents = std::fs::read_to_string(&path)
+                    .with_context(|| format!("failed to read {}", path.display()))?;
+                Self::parse(&contents)
+                    .with_context(|| format!("failed to parse {}", path.display()))?
+            }
+            None => Self::default(),
+        };
+        if config.registries.is_empty() {
+            config.registries.push(RegistrySpec {
+                name: DEFAULT_REGISTRY_NAME.to_string(),
+                url: DEFAULT_REGISTRY_URL.to_string(),
+                priority: 0,
+                auth: None,
+            });
+        }
+        // `BEVY_ASSETS_REPO` predates registry lists; keep honoring it as a
+        // highest-priority registry so existing setups don't break.
+        if let Ok(url) = std::env::var("BEVY_ASSETS_REPO") {
+            let priority = config
+                .registries
+                .iter()
+                .map(|registry| registry.priority)
+                .max()
+                .unwrap_or(0)
+                + 1;
+            config.registries.push(RegistrySpec {
+                name: "BEVY_ASSETS_REPO".to_string(),
+                url,
+                priority,
+                auth: None,
+            });
+        }
+        // Highest priority first; equal priorities keep config order.
+        config
+            .registries
+            .sort_by_key(|registry| std::cmp::Reverse(registry.priority));
+        Ok(config)
+    }
+
+    pub fn parse(contents: &str) -> anyhow::Result<Self> {
+        Ok(toml::from_str(contents)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_registry_list() {
+        let config = CliConfig::parse(
+            "[[registries]]\nname = \"work\"\nurl = \"https://example.com/t\"\npriority = 5\nauth = \"WORK_TOKEN\"\n\
+             [[registries]]\nname = \"local\"\nurl = \"/srv/templates\"\n",
+        )
+        .unwrap();
+        assert_eq!(config.registries.len(), 2);
+        assert_eq!(config.registries[0].priority, 5);
+        assert_eq!(config.registries[0].auth.as_deref(), Some("WORK_TOKEN"));
+    }
+
+    #[test]
+    fn rejects_unknown_keys() {
+        assert!(
+            CliConfig::parse("[[registries]]\nname = \"x\"\nurl = \"y\"\ntoken = \"z\"\n").is_err()
+        );
+    }
+}
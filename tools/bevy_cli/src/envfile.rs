@@ -0,0 +1,59 @@
+use std::path::Path;
+
+use anyhow::Context;
+
+/// Parses a dotenv-style file: one `KEY=VALUE` per line, `#` comments and
+/// blank lines ignored. Returns keys in file order.
+pub fn parse(contents: &str) -> anyhow::Result<Vec<(String, String)>> {
+    let mut entries = Vec::new();
+    for (index, line) in contents.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let (key, value) = line
+            .split_once('=')
+            .with_context(|| format!("line {}: expected `KEY=VALUE`, got `{line}`", index + 1))?;
+        let key = key.trim();
+        anyhow::ensure!(
+            !key.is_empty() && key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_'),
+            "line {}: invalid key `{key}`",
+            index + 1
+        );
+        entries.push((key.to_string(), value.trim().to_string()));
+    }
+    Ok(entries)
+}
+
+pub fn load(path: &Path) -> anyhow::Result<Vec<(String, String)>> {
+    let contents = std::fs::read_to_string(path)
+        .with_context(|| format!("failed to read {}", path.display()))?;
+    parse(&contents).with_context(|| format!("failed to parse {}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_keys_and_skips_comments() {
+        let entries = parse("# comment\nLOG_LEVEL=info\n\nWINDOW_WIDTH = 1280\n").unwrap();
+        assert_eq!(
+            entries,
+            vec![
+                ("LOG_LEVEL".to_string(), "info".to_string()),
+                ("WINDOW_WIDTH".to_string(), "1280".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn rejects_lines_without_separator() {
+        assert!(parse("JUST_A_KEY\n").is_err());
+    }
+
+    #[test]
+    fn rejects_invalid_keys() {
+        assert!(parse("BAD KEY=1\n").is_err());
+    }
+}
@@ -0,0 +1,322 @@
+use std::path::Path;
+
+use anyhow::Context;
+
+/// FNV-1a, 64-bit. Unlike `DefaultHasher` this is stable across platforms
+/// and compiler releases, so hashes written into an asset manifest at bundle
+/// time still match when a shipped game — built with a different toolchain —
+/// re-checks them.
+pub fn fnv1a64(bytes: &[u8]) -> u64 {
+    let mut hash = 0xcbf2_9ce4_8422_2325u64;
+    for &byte in bytes {
+        hash ^= u64::from(byte);
+        hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+    }
+    hash
+}
+
+/// Writes a file, restricting permissions before any contents hit the disk
+/// when the file is going to hold secrets.
+///
+/// On unix, sensitive files are created with mode 0600 so they are never
+/// readable by other users, not even between creation and a later chmod. On
+/// other platforms the contents are written normally; per-user ACLs are left
+/// to the platform defaults.
+pub fn write_file(path: &Path, contents: &[u8], sensitive: bool) -> anyhow::Result<()> {
+    use std::io::Write;
+
+    let mut options = std::fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    if sensitive {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    #[cfg(not(unix))]
+    let _ = sensitive;
+    let mut file = options
+        .open(path)
+        .with_context(|| format!("failed to create {}", path.display()))?;
+    file.write_all(contents)
+        .with_context(|| format!("failed to write {}", path.display()))?;
+    Ok(())
+}
+
+/// Recursively copies a directory, preserving nothing but file contents.
+pub fn copy_dir(from: &Path, to: &Path) -> anyhow::Result<()> {
+    std::fs::create_dir_all(to).with_context(|| format!("failed to create {}", to.display()))?;
+    for entry in
+        std::fs::read_dir(from).with_context(|| format!("failed to read {}", from.display()))?
+    {
+        let entry = entry?;
+        let target = to.join(entry.file_name());
+        // Symlinks are followed, not recreated — target filesystems (FAT,
+        // exFAT) may not support them. Broken links are skipped outright.
+        if entry.file_type()?.is_symlink() && !entry.path().exists() {
+            continue;
+        }
+        if entry.path().is_dir() {
+            copy_dir(&entry.path(), &target)?;
+        } else {
+            std::fs::copy(entry.path(), &target)
+                .with_context(|| format!("failed to copy {}", entry.path().display()))?;
+        }
+    }
+    Ok(())
+}
+
+/// System directories generation must never target directly or sit inside.
+#[cfg(unix)]
+const PROTECTED_ROOTS: &[&str] = &[
+    "/bin", "/boot", "/dev", "/etc", "/lib", "/proc", "/sbin", "/sys", "/usr", "/var",
+];
+#[cfg(not(unix))]
+const PROTECTED_ROOTS: &[&str] = &[
+    "C:\\Windows",
+    "C:\\Program Files",
+    "C:\\Program Files (x86)",
+];
+
+/// Checks whether generating into `target` would touch a protected
+/// location: the filesystem root, the home directory itself, anything under
+/// a system directory, or a path suspiciously close to the root. Returns
+/// the reason when it would, for the caller to turn into a refusal that an
+/// explicit override flag can lift.
+pub fn protected_path(target: &Path) -> Option<String> {
+    let absolute = absolutize(target);
+    if absolute.parent().is_none() {
+        return Some("it is the filesystem root".to_string());
+    }
+    if let Some(home) = dirs::home_dir() {
+        if absolute == home {
+            return Some("it is your home directory".to_string());
+        }
+    }
+    for root in PROTECTED_ROOTS {
+        if absolute.starts_with(root) {
+            return Some(format!("it is inside the system directory {root}"));
+        }
+    }
+    // Depth heuristic: `/my_game` is one typo away from `/`; real projects
+    // live at least two levels down.
+    if absolute.components().count() <= 2 {
+        return Some("it is directly under the filesystem root".to_string());
+    }
+    None
+}
+
+/// What the filesystem holding a target directory can actually do. External
+/// drives are commonly FAT/exFAT: case-insensitive, no symlinks, no unix
+/// permission bits.
+#[derive(Debug, Clone, Copy)]
+pub struct FsCapabilities {
+    pub case_insensitive: bool,
+    pub symlinks: bool,
+    pub unix_permissions: bool,
+}
+
+/// Probes the filesystem holding `path` (or its nearest existing ancestor)
+/// by writing into a throwaway directory, so generation can warn about a
+/// FAT-formatted USB stick instead of failing with raw OS errors halfway
+/// through. `None` when nothing can be written there at all.
+pub fn probe_capabilities(path: &Path) -> Option<FsCapabilities> {
+    let mut base = absolutize(path);
+    while !base.exists() {
+        base = base.parent()?.to_path_buf();
+    }
+    let probe = base.join(format!(".bevy-fs-probe-{}", std::process::id()));
+    std::fs::create_dir(&probe).ok()?;
+    let capabilities = probe_in(&probe);
+    let _ = std::fs::remove_dir_all(&probe);
+    capabilities
+}
+
+fn probe_in(probe: &Path) -> Option<FsCapabilities> {
+    let upper = probe.join("CaseProbe");
+    std::fs::write(&upper, b"probe").ok()?;
+    let case_insensitive = probe.join("caseprobe").exists();
+
+    #[cfg(unix)]
+    let symlinks = std::os::unix::fs::symlink(&upper, probe.join("link")).is_ok();
+    #[cfg(not(unix))]
+    let symlinks = false;
+
+    #[cfg(unix)]
+    let unix_permissions = {
+        use std::os::unix::fs::PermissionsExt;
+        std::fs::set_permissions(&upper, std::fs::Permissions::from_mode(0o600))
+            .and_then(|()| std::fs::metadata(&upper))
+            .map(|metadata| metadata.permissions().mode() & 0o777 == 0o600)
+            .unwrap_or(false)
+    };
+    #[cfg(not(unix))]
+    let unix_permissions = false;
+
+    Some(FsCapabilities {
+        case_insensitive,
+        symlinks,
+        unix_permissions,
+    })
+}
+
+/// Free bytes on the filesystem holding `path` (or its nearest existing
+/// ancestor), via `df` on unix. `None` when that cannot be determined —
+/// callers should skip the check rather than block the operation.
+pub fn available_space(path: &Path) -> Option<u64> {
+    if !cfg!(unix) {
+        return None;
+    }
+    let mut probe = absolutize(path);
+    while !probe.exists() {
+        probe = probe.parent()?.to_path_buf();
+    }
+    let output = std::process::Command::new("df")
+        .arg("-Pk")
+        .arg(&probe)
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    parse_df_output(&String::from_utf8_lossy(&output.stdout))
+}
+
+/// Extracts the available-kilobytes column from POSIX `df -Pk` output.
+fn parse_df_output(output: &str) -> Option<u64> {
+    let line = output.lines().nth(1)?;
+    let available_kib: u64 = line.split_whitespace().nth(3)?.parse().ok()?;
+    Some(available_kib * 1024)
+}
+
+/// Total size of every file under `dir`, in bytes.
+pub fn dir_size(dir: &Path) -> u64 {
+    let mut size = 0;
+    let mut stack = vec![dir.to_path_buf()];
+    while let Some(path) = stack.pop() {
+        if path.is_dir() {
+            if let Ok(entries) = std::fs::read_dir(&path) {
+                stack.extend(entries.flatten().map(|entry| entry.path()));
+            }
+        } else if let Ok(metadata) = path.metadata() {
+            size += metadata.len();
+        }
+    }
+    size
+}
+
+/// Fails early — before anything is cloned, rendered, or copied — when the
+/// filesystem holding `target` has less than `required` bytes free, instead
+/// of letting the operation die on a cryptic mid-write IO error.
+pub fn ensure_space(target: &Path, required: u64) -> anyhow::Result<()> {
+    let Some(available) = available_space(target) else {
+        return Ok(());
+    };
+    anyhow::ensure!(
+        available >= required,
+        "not enough disk space at {}: an estimated {} needed, {} available",
+        target.display(),
+        human_size(required),
+        human_size(available)
+    );
+    Ok(())
+}
+
+/// `1536` -> `1.5 KiB`, for space estimates in error messages.
+pub fn human_size(bytes: u64) -> String {
+    const UNITS: &[&str] = &["B", "KiB", "MiB", "GiB", "TiB"];
+    let mut size = bytes as f64;
+    let mut unit = 0;
+    while size >= 1024.0 && unit + 1 < UNITS.len() {
+        size /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{bytes} B")
+    } else {
+        format!("{size:.1} {}", UNITS[unit])
+    }
+}
+
+/// Lexically absolutizes a path against the current directory, resolving
+/// `.` and `..` without touching the filesystem — the target of a
+/// generation does not exist yet.
+fn absolutize(path: &Path) -> std::path::PathBuf {
+    let base = if path.is_absolute() {
+        std::path::PathBuf::new()
+    } else {
+        std::env::current_dir().unwrap_or_default()
+    };
+    let mut absolute = base;
+    for component in path.components() {
+        match component {
+            std::path::Component::CurDir => {}
+            std::path::Component::ParentDir => {
+                absolute.pop();
+            }
+            other => absolute.push(other),
+        }
+    }
+    absolute
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(unix)]
+    fn sensitive_files_are_owner_only() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = std::env::temp_dir().join("bevy_cli_fs_util_test");
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join(".env");
+        write_file(&path, b"SECRET=1\n", true).unwrap();
+        let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn system_and_shallow_paths_are_protected() {
+        assert!(protected_path(Path::new("/")).is_some());
+        assert!(protected_path(Path::new("/etc/my_game")).is_some());
+        assert!(protected_path(Path::new("/my_game")).is_some());
+        if let Some(home) = dirs::home_dir() {
+            assert!(protected_path(&home).is_some());
+            assert!(protected_path(&home.join("projects/my_game")).is_none());
+        }
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn capability_probe_reports_a_sane_temp_filesystem() {
+        let capabilities = probe_capabilities(&std::env::temp_dir()).unwrap();
+        assert!(capabilities.symlinks);
+        assert!(capabilities.unix_permissions);
+    }
+
+    #[test]
+    fn df_output_yields_the_available_column_in_bytes() {
+        let output = "Filesystem 1024-blocks Used Available Capacity Mounted on\n\
+                      /dev/sda1 102400 51200 40960 56% /\n";
+        assert_eq!(parse_df_output(output), Some(40960 * 1024));
+        assert_eq!(parse_df_output("garbage"), None);
+    }
+
+    #[test]
+    fn sizes_format_with_binary_units() {
+        assert_eq!(human_size(512), "512 B");
+        assert_eq!(human_size(1536), "1.5 KiB");
+        assert_eq!(human_size(3 * 1024 * 1024), "3.0 MiB");
+    }
+
+    #[test]
+    fn relative_targets_resolve_against_the_current_directory() {
+        let absolute = absolutize(Path::new("projects/../my_game"));
+        assert!(absolute.is_absolute());
+        assert!(absolute.ends_with("my_game"));
+        assert!(!absolute.to_string_lossy().contains(".."));
+    }
+}
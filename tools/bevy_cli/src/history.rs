@@ -0,0 +1,102 @@
+//! Persistent history of CLI invocations, backing `bevy history`,
+//! `bevy last`, and `bevy redo`.
+
+use std::path::PathBuf;
+
+use anyhow::Context;
+use serde::{Deserialize, Serialize};
+
+use crate::registry;
+
+/// Most entries kept on disk; older invocations fall off the end.
+const MAX_ENTRIES: usize = 50;
+
+/// One recorded invocation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HistoryEntry {
+    /// Unix timestamp (seconds) of the invocation.
+    pub timestamp: u64,
+    /// Full argument vector after the program name.
+    pub args: Vec<String>,
+}
+
+impl HistoryEntry {
+    /// The command as the user would type it.
+    pub fn command_line(&self) -> String {
+        let mut line = "bevy".to_string();
+        for arg in &self.args {
+            line.push(' ');
+            if arg.contains(' ') {
+                line.push('"');
+                line.push_str(arg);
+                line.push('"');
+            } else {
+                line.push_str(arg);
+            }
+        }
+        line
+    }
+}
+
+fn history_path() -> anyhow::Result<PathBuf> {
+    Ok(registry::cache_dir()?.join("history.jsonl"))
+}
+
+/// Loads history, most recent entry first. A missing file is an empty
+/// history; corrupt lines are skipped rather than failing the command.
+pub fn load() -> anyhow::Result<Vec<HistoryEntry>> {
+    let path = history_path()?;
+    let contents = match std::fs::read_to_string(&path) {
+        Ok(contents) => contents,
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(error) => {
+            return Err(error).with_context(|| format!("failed to read {}", path.display()))
+        }
+    };
+    let mut entries: Vec<HistoryEntry> = contents
+        .lines()
+        .filter_map(|line| serde_json::from_str(line).ok())
+        .collect();
+    entries.reverse();
+    Ok(entries)
+}
+
+/// Appends an invocation, trimming the file to the entry cap.
+pub fn record(args: &[String]) -> anyhow::Result<()> {
+    let timestamp = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_secs())
+        .unwrap_or(0);
+    let mut entries = load()?;
+    entries.reverse();
+    entries.push(HistoryEntry {
+        timestamp,
+        args: args.to_vec(),
+    });
+    if entries.len() > MAX_ENTRIES {
+        let excess = entries.len() - MAX_ENTRIES;
+        entries.drain(..excess);
+    }
+    let path = history_path()?;
+    std::fs::create_dir_all(path.parent().unwrap())?;
+    let mut contents = String::new();
+    for entry in &entries {
+        contents.push_str(&serde_json::to_string(entry)?);
+        contents.push('\n');
+    }
+    crate::fs_util::write_file(&path, contents.as_bytes(), false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn command_line_quotes_spaced_arguments() {
+        let entry = HistoryEntry {
+            timestamp: 0,
+            args: vec!["new".to_string(), "my game".to_string()],
+        };
+        assert_eq!(entry.command_line(), "bevy new \"my game\"");
+    }
+}
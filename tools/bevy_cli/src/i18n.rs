@@ -0,0 +1,255 @@
+//! Minimal message localization for CLI output.
+//!
+//! Messages live in `locales/<locale>.toml`, embedded in the binary. The
+//! active locale comes from `BEVY_CLI_LOCALE`, then the language part of
+//! `LANG`, falling back to English; unknown locales and missing keys also
+//! fall back to English so output never goes blank.
+//!
+//! A message is either a plain string with `{placeholder}` interpolation, or
+//! a table of plural forms keyed by CLDR category (`one`, `other`) selected
+//! by a `count` argument.
+
+use std::collections::BTreeMap;
+use std::sync::OnceLock;
+
+use serde::Deserialize;
+
+/// Embedded message tables, one per supported locale.
+const LOCALES: &[(&str, &str)] = &[
+    ("en", include_str!("../locales/en.toml")),
+    ("fr", include_str!("../locales/fr.toml")),
+];
+
+/// Localizes a message by key, interpolating `{placeholder}` arguments.
+/// Plural messages take the count first; it is also available as `{count}`:
+///
+/// ```ignore
+/// localize!("created-project", name = project, path = dir.display())
+/// localize!("templates-found", count = found.len())
+/// ```
+macro_rules! localize {
+    ($key:expr, count = $count:expr $(, $name:ident = $value:expr)* $(,)?) => {
+        crate::i18n::format(
+            $key,
+            Some($count as i64),
+            &[
+                ("count", ($count).to_string())
+                $(, (stringify!($name), $value.to_string()))*
+            ],
+        )
+    };
+    ($key:expr $(, $name:ident = $value:expr)* $(,)?) => {
+        crate::i18n::format(
+            $key,
+            None,
+            &[$((stringify!($name), $value.to_string())),*],
+        )
+    };
+}
+pub(crate) use localize;
+
+/// One entry of a locale table.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum Message {
+    Simple(String),
+    /// Plural forms keyed by CLDR category.
+    Plural(BTreeMap<String, String>),
+}
+
+fn tables() -> &'static BTreeMap<&'static str, BTreeMap<String, Message>> {
+    static TABLES: OnceLock<BTreeMap<&'static str, BTreeMap<String, Message>>> = OnceLock::new();
+    TABLES.get_or_init(|| {
+        LOCALES
+            .iter()
+            .map(|(locale, contents)| {
+                let table = toml::from_str(contents)
+                    .unwrap_or_else(|error| panic!("invalid locale table `{locale}`: {error}"));
+                (*locale, table)
+            })
+            .collect()
+    })
+}
+
+/// The active locale, e.g. `en`.
+pub fn locale() -> String {
+    let raw = std::env::var("BEVY_CLI_LOCALE")
+        .or_else(|_| std::env::var("LANG"))
+        .unwrap_or_default();
+    let language = raw.split(['_', '.', '-']).next().unwrap_or("");
+    if tables().contains_key(language) {
+        language.to_string()
+    } else {
+        "en".to_string()
+    }
+}
+
+/// The CLDR plural category for a cardinal count in the given locale.
+///
+/// Only the languages the CLI ships messages for are implemented; notably,
+/// French treats 0 and 1 as singular where English does not.
+pub fn plural_category(locale: &str, count: i64) -> &'static str {
+    match locale {
+        "fr" => {
+            if count.abs() <= 1 {
+                "one"
+            } else {
+                "other"
+            }
+        }
+        _ => {
+            if count == 1 {
+                "one"
+            } else {
+                "other"
+            }
+        }
+    }
+}
+
+fn lookup(key: &str) -> (&'static str, &'static Message) {
+    let tables = tables();
+    let locale = locale();
+    if let Some(message) = tables.get(locale.as_str()).and_then(|table| table.get(key)) {
+        // Leak-free: `locale` is one of the static table keys.
+        let (static_locale, _) = tables.get_key_value(locale.as_str()).unwrap();
+        return (static_locale, message);
+    }
+    (
+        "en",
+        tables["en"]
+            .get(key)
+            .unwrap_or_else(|| panic!("unknown i18n message key `{key}`")),
+    )
+}
+
+/// Localizes and interpolates a message; `count` selects the plural form of
+/// plural messages and must be given for them.
+pub fn format(key: &str, count: Option<i64>, args: &[(&str, String)]) -> String {
+    let (locale, message) = lookup(key);
+    let template = match (message, count) {
+        (Message::Simple(template), _) => template,
+        (Message::Plural(forms), Some(count)) => {
+            let category = plural_category(locale, count);
+            forms
+                .get(category)
+                .or_else(|| forms.get("other"))
+                .unwrap_or_else(|| panic!("message `{key}` has no `{category}`/`other` form"))
+        }
+        (Message::Plural(_), None) => {
+            panic!("message `{key}` is plural; pass `count = ...` to localize!")
+        }
+    };
+    interpolate(template, args)
+}
+
+/// Replaces `{name}` placeholders with the given arguments.
+pub fn interpolate(template: &str, args: &[(&str, String)]) -> String {
+    let mut output = template.to_string();
+    for (name, value) in args {
+        output = output.replace(&format!("{{{name}}}"), value);
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use super::*;
+
+    /// Placeholder names used by a message, across all plural forms.
+    fn placeholders(message: &Message) -> BTreeSet<&str> {
+        let mut found = BTreeSet::new();
+        let forms: Vec<&str> = match message {
+            Message::Simple(template) => vec![template],
+            Message::Plural(forms) => forms.values().map(String::as_str).collect(),
+        };
+        for mut rest in forms {
+            while let Some(start) = rest.find('{') {
+                let Some(len) = rest[start..].find('}') else {
+                    break;
+                };
+                found.insert(&rest[start + 1..start + len]);
+                rest = &rest[start + len..];
+            }
+        }
+        found
+    }
+
+    #[test]
+    fn every_locale_has_the_same_keys() {
+        let tables = tables();
+        let english: BTreeSet<_> = tables["en"].keys().collect();
+        for (locale, table) in tables {
+            let keys: BTreeSet<_> = table.keys().collect();
+            assert_eq!(keys, english, "locale `{locale}` diverges from `en`");
+        }
+    }
+
+    #[test]
+    fn every_locale_uses_the_same_placeholders() {
+        let tables = tables();
+        for (key, english_message) in &tables["en"] {
+            for (locale, table) in tables {
+                assert_eq!(
+                    placeholders(&table[key]),
+                    placeholders(english_message),
+                    "placeholders of `{key}` diverge in locale `{locale}`"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn interpolation_replaces_named_arguments() {
+        assert_eq!(
+            interpolate(
+                "Created `{name}` at {path}",
+                &[
+                    ("name", "game".to_string()),
+                    ("path", "/tmp/game".to_string()),
+                ]
+            ),
+            "Created `game` at /tmp/game"
+        );
+    }
+
+    #[test]
+    fn english_singular_is_exactly_one() {
+        assert_eq!(plural_category("en", 0), "other");
+        assert_eq!(plural_category("en", 1), "one");
+        assert_eq!(plural_category("en", 3), "other");
+    }
+
+    #[test]
+    fn french_zero_is_singular() {
+        assert_eq!(plural_category("fr", 0), "one");
+        assert_eq!(plural_category("fr", 1), "one");
+        assert_eq!(plural_category("fr", 2), "other");
+    }
+
+    #[test]
+    fn plural_messages_select_a_form() {
+        std::env::set_var("BEVY_CLI_LOCALE", "en");
+        assert_eq!(
+            format("templates-found", Some(1), &[("count", "1".to_string())]),
+            "1 template found"
+        );
+        assert_eq!(
+            format("templates-found", Some(4), &[("count", "4".to_string())]),
+            "4 templates found"
+        );
+        std::env::remove_var("BEVY_CLI_LOCALE");
+    }
+
+    #[test]
+    fn unknown_locales_fall_back_to_english() {
+        std::env::set_var("BEVY_CLI_LOCALE", "tlh");
+        assert_eq!(
+            format("no-templates-found", None, &[]),
+            "No templates found."
+        );
+        std::env::remove_var("BEVY_CLI_LOCALE");
+    }
+}
@@ -0,0 +1,42 @@
+//! Project scaffolding and workflow tooling for Bevy.
+//!
+//! The crate ships the `bevy` command-line binary, but everything the CLI
+//! does is also reachable as a library so GUI launchers and other tools can
+//! generate projects programmatically. The supported entry point is
+//! [`ProjectBuilder`]:
+//!
+//! ```no_run
+//! use bevy_cli::ProjectBuilder;
+//!
+//! let project = ProjectBuilder::new("my_game")
+//!     .bevy_version("0.12")
+//!     .ci(true)
+//!     .progress(|event| println!("{}% {}", event.percent, event.phase))
+//!     .create()?;
+//! # anyhow::Ok(())
+//! ```
+//!
+//! Errors are [`anyhow::Error`] values carrying the full context chain; the
+//! modules below are exported for the binary and for callers that need to
+//! reach past the builder, but only [`ProjectBuilder`] and the types it
+//! takes are covered by semver.
+
+pub mod archive;
+pub mod builder;
+pub mod commands;
+pub mod config;
+pub mod envfile;
+pub mod fs_util;
+pub mod history;
+pub mod i18n;
+pub mod output;
+pub mod project;
+pub mod registry;
+pub mod scaffold;
+pub mod subprocess;
+pub mod template;
+pub mod vcs;
+pub mod versions;
+pub mod wizard;
+
+pub use builder::ProjectBuilder;
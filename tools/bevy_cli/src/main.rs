@@ -0,0 +1,153 @@
+use clap::Parser;
+
+use bevy_cli::{commands, config, history, output};
+
+#[derive(Parser)]
+#[command(
+    name = "bevy",
+    version,
+    about = bevy_cli::i18n::format("cli-about", None, &[])
+)]
+struct Cli {
+    /// Screen-reader friendly output: textual ERROR/WARN/OK prefixes, plain
+    /// progress percentages, no symbols
+    #[arg(long, global = true)]
+    accessible: bool,
+
+    /// Progress reporting format; `json` emits newline-delimited events on
+    /// stderr for frontends wrapping the CLI
+    #[arg(long, global = true, value_enum, default_value_t = output::ProgressFormat::Human)]
+    progress: output::ProgressFormat,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(clap::Subcommand)]
+enum Command {
+    /// Create a new Bevy project from a template
+    New(Box<commands::new::NewArgs>),
+    /// Build the project for a platform and stage the artifact in dist/
+    Build(commands::build::BuildArgs),
+    /// Run the project through cargo with Bevy-friendly defaults
+    Run(commands::run::RunArgs),
+    /// Rerun the game whenever sources change; assets hot-reload in place
+    Watch(commands::watch::WatchArgs),
+    /// Run the game with tracing enabled and collect the profile
+    Profile(commands::profile::ProfileArgs),
+    /// Run criterion benchmarks with comparable settings and baselines
+    Bench(commands::bench::BenchArgs),
+    /// Report binary and asset sizes against configured budgets
+    Size(commands::size::SizeArgs),
+    /// Run cargo check plus Bevy-aware diagnostics
+    Check(commands::check::CheckArgs),
+    /// Run cargo test with a headless-rendering environment
+    Test(commands::test::TestArgs),
+    /// Remove build output, dist/, and asset or tool caches
+    Clean(commands::clean::CleanArgs),
+    /// Diagnose the build environment and suggest fixes
+    Doctor(commands::doctor::DoctorArgs),
+    /// Bump bevy and ecosystem crates to a newer release
+    Upgrade(commands::upgrade::UpgradeArgs),
+    /// Apply per-release code migration rules, with a diff preview
+    Migrate(commands::migrate::MigrateArgs),
+    /// Add an ecosystem crate and register its plugin
+    Add(commands::add::AddArgs),
+    /// Remove a dependency and strip its plugin registration
+    Remove(commands::remove::RemoveArgs),
+    /// Print a report of the project's Bevy setup
+    Info(commands::info::InfoArgs),
+    /// Produce a per-platform distributable archive
+    Package(commands::package::PackageArgs),
+    /// Push packaged builds to a distribution service
+    Deploy(commands::deploy::DeployArgs),
+    /// Serve the web build locally, rebuilding and reloading on change
+    Serve(commands::serve::ServeArgs),
+    /// Search configured template registries
+    Search(commands::search::SearchArgs),
+    /// Install a template from a registry
+    Install(commands::install::InstallArgs),
+    /// Manage runtime configuration files of a project
+    Env(commands::env::EnvArgs),
+    /// Validate a local `.env` against `.env.example`
+    ConfigCheck(commands::config_check::ConfigCheckArgs),
+    /// Show recorded command history
+    History(commands::history::HistoryArgs),
+    /// Show the most recent recorded command
+    Last(commands::history::LastArgs),
+    /// Re-run a command from the history
+    Redo(commands::history::RedoArgs),
+    /// Work with source assets and their exported forms
+    Assets(commands::assets::AssetsArgs),
+    /// Run several installs and project generations from a plan file
+    Batch(commands::batch::BatchArgs),
+    /// Package a project for distribution through a resumable pipeline
+    Bundle(commands::bundle::BundleArgs),
+    /// Generate one project per student from a roster
+    Classroom(commands::classroom::ClassroomArgs),
+    /// Generate code and assets into an existing project
+    Generate(commands::generate::GenerateArgs),
+    /// Tooling for template authors
+    Templates(commands::templates::TemplatesArgs),
+}
+
+fn main() {
+    let cli = Cli::parse();
+    let accessible =
+        cli.accessible || config::CliConfig::load().is_ok_and(|config| config.accessible);
+    if accessible {
+        output::set_mode(output::OutputMode::Accessible);
+    }
+    output::set_progress_format(cli.progress);
+    // History meta-commands are not themselves recorded.
+    if !matches!(
+        cli.command,
+        Command::History(_) | Command::Last(_) | Command::Redo(_)
+    ) {
+        let args: Vec<String> = std::env::args().skip(1).collect();
+        if let Err(error) = history::record(&args) {
+            output::warn(&format!("could not record history: {error:#}"));
+        }
+    }
+    if let Err(error) = run(cli) {
+        output::error(&format!("{error:#}"));
+        std::process::exit(1);
+    }
+}
+
+fn run(cli: Cli) -> anyhow::Result<()> {
+    match cli.command {
+        Command::New(args) => commands::new::run(*args),
+        Command::Build(args) => commands::build::run(args),
+        Command::Run(args) => commands::run::run(args),
+        Command::Watch(args) => commands::watch::run(args),
+        Command::Profile(args) => commands::profile::run(args),
+        Command::Bench(args) => commands::bench::run(args),
+        Command::Size(args) => commands::size::run(args),
+        Command::Check(args) => commands::check::run(args),
+        Command::Test(args) => commands::test::run(args),
+        Command::Clean(args) => commands::clean::run(args),
+        Command::Doctor(args) => commands::doctor::run(args),
+        Command::Upgrade(args) => commands::upgrade::run(args),
+        Command::Migrate(args) => commands::migrate::run(args),
+        Command::Add(args) => commands::add::run(args),
+        Command::Remove(args) => commands::remove::run(args),
+        Command::Info(args) => commands::info::run(args),
+        Command::Package(args) => commands::package::run(args),
+        Command::Deploy(args) => commands::deploy::run(args),
+        Command::Serve(args) => commands::serve::run(args),
+        Command::Search(args) => commands::search::run(args),
+        Command::Install(args) => commands::install::run(args),
+        Command::Env(args) => commands::env::run(args),
+        Command::ConfigCheck(args) => commands::config_check::run(args),
+        Command::History(args) => commands::history::run(args),
+        Command::Last(args) => commands::history::run_last(args),
+        Command::Redo(args) => commands::history::run_redo(args),
+        Command::Assets(args) => commands::assets::run(args),
+        Command::Batch(args) => commands::batch::run(args),
+        Command::Bundle(args) => commands::bundle::run(args),
+        Command::Classroom(args) => commands::classroom::run(args),
+        Command::Generate(args) => commands::generate::run(args),
+        Command::Templates(args) => commands::templates::run(args),
+    }
+}
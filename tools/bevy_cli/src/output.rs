@@ -0,0 +1,259 @@
+//! Shared helpers for ordering CLI output.
+//!
+//! Lists shown to the user (search results, template listings, env keys) are
+//! sorted with a collation approximating the ICU root order instead of byte
+//! order: case and accents are ignored on the first pass and only break ties,
+//! so `Éclair` sorts between `apple` and `Zèbre` rather than after `z`. CJK
+//! and other scripts fall back to codepoint order, matching the ICU root
+//! locale's behavior for unihan.
+
+use std::sync::OnceLock;
+
+use clap::ValueEnum;
+use serde::Serialize;
+use unicode_normalization::UnicodeNormalization;
+
+/// How status output is decorated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputMode {
+    /// Symbols and drawn progress bars.
+    Pretty,
+    /// Screen-reader friendly: textual ERROR/WARN/OK prefixes, plain
+    /// percentages, no symbols.
+    Accessible,
+}
+
+static MODE: OnceLock<OutputMode> = OnceLock::new();
+
+/// Sets the process-wide output mode; called once at startup from the
+/// `--accessible` flag or the `accessible` config key.
+pub fn set_mode(mode: OutputMode) {
+    let _ = MODE.set(mode);
+}
+
+pub fn mode() -> OutputMode {
+    *MODE.get().unwrap_or(&OutputMode::Pretty)
+}
+
+/// The severity of a status line.
+#[derive(Debug, Clone, Copy)]
+pub enum Status {
+    Ok,
+    Warn,
+    Error,
+}
+
+/// Formats a status line for the given mode.
+fn decorate(mode: OutputMode, status: Status, message: &str) -> String {
+    let prefix = match (mode, status) {
+        (OutputMode::Pretty, Status::Ok) => "✓",
+        (OutputMode::Pretty, Status::Warn) => "⚠",
+        (OutputMode::Pretty, Status::Error) => "✗",
+        (OutputMode::Accessible, Status::Ok) => "OK",
+        (OutputMode::Accessible, Status::Warn) => "WARN",
+        (OutputMode::Accessible, Status::Error) => "ERROR",
+    };
+    format!("{prefix} {message}")
+}
+
+pub fn ok(message: &str) {
+    println!("{}", decorate(mode(), Status::Ok, message));
+}
+
+pub fn warn(message: &str) {
+    eprintln!("{}", decorate(mode(), Status::Warn, message));
+}
+
+pub fn error(message: &str) {
+    eprintln!("{}", decorate(mode(), Status::Error, message));
+}
+
+/// Formats a progress line: a drawn bar in pretty mode, a plain percentage
+/// in accessible mode.
+pub fn progress_line(mode: OutputMode, percent: u8, label: &str) -> String {
+    let percent = percent.min(100);
+    match mode {
+        OutputMode::Pretty => {
+            let filled = usize::from(percent) / 10;
+            format!(
+                "[{:#<filled$}{:-<rest$}] {percent}% {label}",
+                "",
+                "",
+                rest = 10 - filled
+            )
+        }
+        OutputMode::Accessible => format!("{percent}% {label}"),
+    }
+}
+
+pub fn progress(percent: u8, label: &str) {
+    println!("{}", progress_line(mode(), percent, label));
+}
+
+/// How long-running operations report progress.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum ProgressFormat {
+    /// Human-readable progress lines, honoring the output mode.
+    #[default]
+    Human,
+    /// Newline-delimited JSON events on stderr, for frontends wrapping the
+    /// CLI; see [`ProgressEvent`] for the schema.
+    Json,
+}
+
+static PROGRESS_FORMAT: OnceLock<ProgressFormat> = OnceLock::new();
+
+/// Sets the process-wide progress format; called once at startup from the
+/// `--progress` flag.
+pub fn set_progress_format(format: ProgressFormat) {
+    let _ = PROGRESS_FORMAT.set(format);
+}
+
+pub fn progress_format() -> ProgressFormat {
+    *PROGRESS_FORMAT.get().unwrap_or(&ProgressFormat::Human)
+}
+
+/// One progress event, emitted as a single JSON line on stderr in
+/// `--progress json` mode.
+///
+/// The schema is stable: `phase` names the operation (`clone`, `download`,
+/// `render`, `install`, ...), `percent` runs 0-100, `file` is the entry
+/// currently being processed and `bytes` the amount transferred so far; the
+/// last two are omitted when unknown.
+#[derive(Debug, Serialize)]
+pub struct ProgressEvent<'a> {
+    pub phase: &'a str,
+    pub percent: u8,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bytes: Option<u64>,
+}
+
+type ProgressHook = Box<dyn Fn(&ProgressEvent) + Send + Sync>;
+
+static PROGRESS_HOOK: OnceLock<ProgressHook> = OnceLock::new();
+
+/// Routes progress events to a callback instead of the terminal, for
+/// embedders driving generation through [`crate::ProjectBuilder`]. Process-
+/// wide; the first hook installed wins.
+pub fn set_progress_hook(hook: impl Fn(&ProgressEvent) + Send + Sync + 'static) {
+    let _ = PROGRESS_HOOK.set(Box::new(hook));
+}
+
+/// Reports progress in the configured format: to the installed hook when an
+/// embedder claimed progress, otherwise a JSON line on stderr for machine
+/// consumers, or a human progress line labeled with the current file
+/// (falling back to the phase name).
+pub fn progress_event(event: &ProgressEvent) {
+    if let Some(hook) = PROGRESS_HOOK.get() {
+        hook(event);
+        return;
+    }
+    match progress_format() {
+        ProgressFormat::Json => eprintln!(
+            "{}",
+            serde_json::to_string(event).expect("progress events serialize")
+        ),
+        ProgressFormat::Human => progress(event.percent, event.file.unwrap_or(event.phase)),
+    }
+}
+
+/// The primary collation key of a string: NFD-normalized, lowercased, with
+/// combining marks removed.
+fn primary_key(text: &str) -> String {
+    text.nfd()
+        .filter(|c| !unicode_normalization::char::is_combining_mark(*c))
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// Compares two strings with locale-friendly collation: primary strength
+/// first (ignoring case and accents), full codepoint order as tiebreaker so
+/// the result is total and deterministic.
+pub fn collate(a: &str, b: &str) -> std::cmp::Ordering {
+    primary_key(a).cmp(&primary_key(b)).then_with(|| a.cmp(b))
+}
+
+/// Sorts items by a string key using [`collate`].
+pub fn sort_localized<T>(items: &mut [T], key: impl Fn(&T) -> &str) {
+    items.sort_by(|a, b| collate(key(a), key(b)));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accessible_mode_uses_textual_prefixes() {
+        assert_eq!(
+            decorate(OutputMode::Accessible, Status::Error, "boom"),
+            "ERROR boom"
+        );
+        assert_eq!(decorate(OutputMode::Pretty, Status::Ok, "done"), "✓ done");
+    }
+
+    #[test]
+    fn accessible_progress_is_a_plain_percentage() {
+        assert_eq!(
+            progress_line(OutputMode::Accessible, 40, "rendering"),
+            "40% rendering"
+        );
+        assert_eq!(
+            progress_line(OutputMode::Pretty, 40, "rendering"),
+            "[####------] 40% rendering"
+        );
+    }
+
+    #[test]
+    fn progress_events_serialize_to_the_documented_schema() {
+        let full = ProgressEvent {
+            phase: "download",
+            percent: 30,
+            file: Some("assets.tar"),
+            bytes: Some(4096),
+        };
+        assert_eq!(
+            serde_json::to_string(&full).unwrap(),
+            r#"{"phase":"download","percent":30,"file":"assets.tar","bytes":4096}"#
+        );
+        let sparse = ProgressEvent {
+            phase: "render",
+            percent: 100,
+            file: None,
+            bytes: None,
+        };
+        assert_eq!(
+            serde_json::to_string(&sparse).unwrap(),
+            r#"{"phase":"render","percent":100}"#
+        );
+    }
+
+    #[test]
+    fn accented_names_sort_with_their_base_letter() {
+        let mut names = vec!["Zèbre", "apple", "Éclair", "zebra"];
+        sort_localized(&mut names, |name| name);
+        assert_eq!(names, vec!["apple", "Éclair", "zebra", "Zèbre"]);
+    }
+
+    #[test]
+    fn case_is_ignored_at_primary_strength() {
+        let mut names = vec!["banana", "Apple", "cherry"];
+        sort_localized(&mut names, |name| name);
+        assert_eq!(names, vec!["Apple", "banana", "cherry"]);
+    }
+
+    #[test]
+    fn cjk_names_sort_by_codepoint() {
+        let mut names = vec!["光", "一", "丁"];
+        sort_localized(&mut names, |name| name);
+        assert_eq!(names, vec!["一", "丁", "光"]);
+    }
+
+    #[test]
+    fn collation_is_deterministic_for_equal_primaries() {
+        // `e` and `é` share a primary key; the codepoint tiebreaker keeps
+        // the order total.
+        assert_ne!(collate("e", "é"), std::cmp::Ordering::Equal);
+    }
+}
@@ -0,0 +1,163 @@
+//! The `Bevy.toml` project manifest.
+//!
+//! `bevy new` writes a `[project]` section recording what was generated —
+//! template, Bevy version, target platforms, chosen options — so later
+//! subcommands can read the project's shape back instead of guessing from
+//! the filesystem. The same file holds the user-maintained `[assets]` and
+//! `[bundle]` sections; writes here only ever replace the `[project]` table.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+use serde::{Deserialize, Serialize};
+
+use crate::fs_util;
+
+/// File name of the project manifest, at the project root.
+pub const MANIFEST: &str = "Bevy.toml";
+
+/// The `[project]` section: a record of what `bevy new` generated.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ProjectSection {
+    pub name: String,
+    /// The template the project was generated from; absent for the
+    /// built-in default.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub template: Option<String>,
+    /// `game` or `plugin`.
+    pub kind: String,
+    /// The resolved Bevy version the project was generated against.
+    pub bevy_version: String,
+    pub edition: String,
+    /// Extra deployment targets the project was prepared for (`web`,
+    /// `android`, `ios`); empty means host-only.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub targets: Vec<String>,
+    /// Source layout preset (`flat`, `plugins`, `feature-modules`).
+    pub layout: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub bevy_features: Vec<String>,
+    #[serde(skip_serializing_if = "is_false")]
+    pub workspace: bool,
+    #[serde(skip_serializing_if = "is_false")]
+    pub with_assets: bool,
+    #[serde(skip_serializing_if = "is_false")]
+    pub with_states: bool,
+    #[serde(skip_serializing_if = "is_false")]
+    pub xtask: bool,
+}
+
+fn is_false(flag: &bool) -> bool {
+    !flag
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct ManifestDoc {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    project: Option<ProjectSection>,
+}
+
+/// Writes (or replaces) the `[project]` section of `dir/Bevy.toml`,
+/// preserving every other table a template or the user put there.
+pub fn write(dir: &Path, section: &ProjectSection) -> anyhow::Result<()> {
+    let path = dir.join(MANIFEST);
+    let rendered = toml::to_string_pretty(&ManifestDoc {
+        project: Some(section.clone()),
+    })?;
+    let contents = match std::fs::read_to_string(&path) {
+        Ok(existing) => {
+            let mut doc: toml_edit::Document = existing
+                .parse()
+                .with_context(|| format!("failed to parse {}", path.display()))?;
+            let fresh: toml_edit::Document = rendered.parse().expect("serialized manifest parses");
+            doc["project"] = fresh["project"].clone();
+            doc.to_string()
+        }
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => rendered,
+        Err(error) => {
+            return Err(error).with_context(|| format!("failed to read {}", path.display()))
+        }
+    };
+    fs_util::write_file(&path, contents.as_bytes(), false)
+}
+
+/// Loads the `[project]` section of `dir/Bevy.toml`. `None` when the file
+/// or the section is absent — hand-rolled projects never wrote one.
+pub fn load(dir: &Path) -> anyhow::Result<Option<ProjectSection>> {
+    let path = dir.join(MANIFEST);
+    let contents = match std::fs::read_to_string(&path) {
+        Ok(contents) => contents,
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(error) => {
+            return Err(error).with_context(|| format!("failed to read {}", path.display()))
+        }
+    };
+    let doc: ManifestDoc =
+        toml::from_str(&contents).with_context(|| format!("failed to parse {}", path.display()))?;
+    Ok(doc.project)
+}
+
+/// Finds the project root by walking up from `start` to the nearest
+/// directory holding a `Bevy.toml`, so subcommands run from `src/` or
+/// `assets/sprites/` still find their configuration. Falls back to `start`
+/// when no manifest exists anywhere above.
+pub fn locate(start: &Path) -> PathBuf {
+    let mut dir = start.to_path_buf();
+    // Relative starts need resolving before there are parents to walk.
+    if let Ok(absolute) = dir.canonicalize() {
+        dir = absolute;
+    }
+    let mut current = dir.clone();
+    loop {
+        if current.join(MANIFEST).is_file() {
+            return current;
+        }
+        match current.parent() {
+            Some(parent) => current = parent.to_path_buf(),
+            None => return start.to_path_buf(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn writes_preserve_foreign_tables() {
+        let dir = std::env::temp_dir().join("bevy_cli_project_manifest_test");
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(
+            dir.join(MANIFEST),
+            "[assets.naming]\nstyle = \"snake_case\"\n",
+        )
+        .unwrap();
+        write(
+            &dir,
+            &ProjectSection {
+                name: "my_game".to_string(),
+                kind: "game".to_string(),
+                bevy_version: "0.12".to_string(),
+                edition: "2021".to_string(),
+                layout: "flat".to_string(),
+                ..ProjectSection::default()
+            },
+        )
+        .unwrap();
+        let contents = std::fs::read_to_string(dir.join(MANIFEST)).unwrap();
+        assert!(contents.contains("[assets.naming]"));
+        let section = load(&dir).unwrap().unwrap();
+        assert_eq!(section.name, "my_game");
+        assert_eq!(section.bevy_version, "0.12");
+        assert!(!section.workspace);
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn locate_falls_back_to_the_start_directory() {
+        let start = Path::new("/definitely/not/a/project");
+        assert_eq!(locate(start), start);
+    }
+}
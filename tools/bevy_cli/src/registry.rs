@@ -0,0 +1,309 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+use serde::Deserialize;
+
+use crate::config::RegistrySpec;
+use crate::vcs::{self, Vcs};
+
+/// Name of the index file at a registry root.
+pub const INDEX_FILE: &str = "templates.toml";
+
+/// The parsed `templates.toml` index of a registry.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct RegistryIndex {
+    #[serde(default)]
+    pub templates: Vec<IndexEntry>,
+    /// Non-template entries: asset packs and crate-based assets.
+    #[serde(default)]
+    pub assets: Vec<AssetEntry>,
+}
+
+/// One installable template listed in a registry index.
+#[derive(Debug, Clone, Deserialize)]
+pub struct IndexEntry {
+    pub name: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    /// Path of the template directory, relative to the registry root.
+    pub path: String,
+}
+
+/// One installable asset listed in a registry index: either a pack of
+/// files (`path`, copied into the project's assets) or a crate
+/// (`crate`, added as a dependency).
+#[derive(Debug, Clone, Deserialize)]
+pub struct AssetEntry {
+    pub name: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    /// Directory of asset files, relative to the registry root.
+    #[serde(default)]
+    pub path: Option<String>,
+    /// Name on crates.io, for crate-based assets.
+    #[serde(default, rename = "crate")]
+    pub crate_name: Option<String>,
+}
+
+impl RegistryIndex {
+    pub fn parse(contents: &str) -> anyhow::Result<Self> {
+        Ok(toml::from_str(contents)?)
+    }
+}
+
+/// A template found while querying registries, together with where it came
+/// from.
+pub struct FoundTemplate {
+    pub registry: String,
+    pub entry: IndexEntry,
+    /// Local directory holding the template files.
+    pub dir: PathBuf,
+}
+
+/// An asset found while querying registries, with where it came from.
+pub struct FoundAsset {
+    pub registry: String,
+    pub entry: AssetEntry,
+    /// Local directory holding the pack files, for `path` entries.
+    pub dir: Option<PathBuf>,
+}
+
+/// Root of the CLI's per-user cache (cloned registries, installed templates).
+pub fn cache_dir() -> anyhow::Result<PathBuf> {
+    dirs::data_dir()
+        .map(|dir| dir.join("bevy_cli"))
+        .context("could not determine a per-user data directory")
+}
+
+/// Directory where installed templates live, each under its own name.
+pub fn installed_templates_dir() -> anyhow::Result<PathBuf> {
+    Ok(cache_dir()?.join("templates"))
+}
+
+/// Makes the contents of a registry available locally and returns its root.
+///
+/// Local paths (or `file://` URLs) are used in place; git URLs are cloned
+/// into the cache directory, refreshed with a `git pull` on later calls. An
+/// `auth` env var configured for the registry is sent as an `Authorization`
+/// header on each git invocation — never spliced into the URL, where git
+/// would persist it in the cached checkout's `.git/config`.
+pub fn fetch(spec: &RegistrySpec) -> anyhow::Result<PathBuf> {
+    let url = spec.url.strip_prefix("file://").unwrap_or(&spec.url);
+    let as_path = Path::new(url);
+    if as_path.is_dir() {
+        return Ok(as_path.to_path_buf());
+    }
+
+    let git = match &spec.auth {
+        Some(auth_var) => {
+            let token = std::env::var(auth_var).with_context(|| {
+                format!("registry `{}` needs a token in ${auth_var}", spec.name)
+            })?;
+            // The same credential shape as `https://<token>@host`: basic
+            // auth with the token as the user name and an empty password.
+            vcs::ShellGit::with_auth_header(format!(
+                "Authorization: Basic {}",
+                base64(format!("{token}:").as_bytes())
+            ))
+        }
+        None => vcs::ShellGit::default(),
+    };
+
+    let checkout = cache_dir()?.join("registries").join(&spec.name);
+    let cloning = !checkout.join(".git").exists();
+    if cloning && crate::output::progress_format() == crate::output::ProgressFormat::Json {
+        crate::output::progress_event(&crate::output::ProgressEvent {
+            phase: "clone",
+            percent: 0,
+            file: None,
+            bytes: None,
+        });
+    }
+    vcs::ensure_checkout(&git, &spec.url, None, &checkout)
+        .with_context(|| format!("while fetching registry `{}` ({})", spec.name, spec.url))?;
+    if cloning && crate::output::progress_format() == crate::output::ProgressFormat::Json {
+        crate::output::progress_event(&crate::output::ProgressEvent {
+            phase: "clone",
+            percent: 100,
+            file: None,
+            bytes: None,
+        });
+    }
+    Ok(checkout)
+}
+
+/// Standard base64 with padding — just enough for the `Authorization`
+/// header, not worth a dependency.
+fn base64(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut out = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let word = (u32::from(chunk[0]) << 16)
+            | (u32::from(chunk.get(1).copied().unwrap_or(0)) << 8)
+            | u32::from(chunk.get(2).copied().unwrap_or(0));
+        out.push(ALPHABET[((word >> 18) & 63) as usize] as char);
+        out.push(ALPHABET[((word >> 12) & 63) as usize] as char);
+        for (index, shift) in [(1, 6), (2, 0)] {
+            out.push(if chunk.len() > index {
+                ALPHABET[((word >> shift) & 63) as usize] as char
+            } else {
+                '='
+            });
+        }
+    }
+    out
+}
+
+/// Repository hosting the official default template, tagged per Bevy
+/// release.
+const OFFICIAL_DEFAULT_REPO: &str = "https://github.com/bevyengine/bevy_cli_default_template";
+
+/// Returns a local checkout of the official default template for the given
+/// Bevy release, downloading and caching it on first use. Returns `None`
+/// when the release has no published template or the download fails, in
+/// which case callers fall back to the embedded copy.
+pub fn official_default(bevy_version: &str) -> anyhow::Result<Option<std::path::PathBuf>> {
+    // Releases tag their template `v<major>.<minor>`.
+    let minor: String = bevy_version
+        .split('.')
+        .take(2)
+        .collect::<Vec<_>>()
+        .join(".");
+    let checkout = cache_dir()?.join("official-default").join(&minor);
+    if checkout
+        .join(crate::template::manifest::MANIFEST_FILE)
+        .exists()
+    {
+        return Ok(Some(checkout));
+    }
+    std::fs::create_dir_all(checkout.parent().unwrap())?;
+    let tag = format!("v{minor}");
+    let result = vcs::ShellGit::default().clone_repo(OFFICIAL_DEFAULT_REPO, Some(&tag), &checkout);
+    match result {
+        Ok(()) => Ok(Some(checkout)),
+        Err(_) => {
+            // Leave nothing half-cloned behind, so the next run retries.
+            let _ = std::fs::remove_dir_all(&checkout);
+            Ok(None)
+        }
+    }
+}
+
+/// Loads the index of an already-fetched registry.
+pub fn load_index(registry_root: &Path) -> anyhow::Result<RegistryIndex> {
+    let path = registry_root.join(INDEX_FILE);
+    let contents = std::fs::read_to_string(&path)
+        .with_context(|| format!("registry has no readable {}", path.display()))?;
+    RegistryIndex::parse(&contents).with_context(|| format!("failed to parse {}", path.display()))
+}
+
+/// Queries every registry in order and returns entries whose name or
+/// description contains `query` (case-insensitive). An empty query matches
+/// everything.
+pub fn search(registries: &[RegistrySpec], query: &str) -> anyhow::Result<Vec<FoundTemplate>> {
+    let query = query.to_lowercase();
+    let mut found = Vec::new();
+    for spec in registries {
+        let root = fetch(spec)
+            .with_context(|| format!("failed to fetch registry `{}` ({})", spec.name, spec.url))?;
+        for entry in load_index(&root)?.templates {
+            let matches = entry.name.to_lowercase().contains(&query)
+                || entry
+                    .description
+                    .as_deref()
+                    .is_some_and(|description| description.to_lowercase().contains(&query));
+            if matches {
+                let dir = root.join(&entry.path);
+                found.push(FoundTemplate {
+                    registry: spec.name.clone(),
+                    entry,
+                    dir,
+                });
+            }
+        }
+    }
+    Ok(found)
+}
+
+/// Queries every registry's asset entries, with the same matching rules as
+/// [`search`].
+pub fn search_assets(registries: &[RegistrySpec], query: &str) -> anyhow::Result<Vec<FoundAsset>> {
+    let query = query.to_lowercase();
+    let mut found = Vec::new();
+    for spec in registries {
+        let root = fetch(spec)
+            .with_context(|| format!("failed to fetch registry `{}` ({})", spec.name, spec.url))?;
+        for entry in load_index(&root)?.assets {
+            let matches = entry.name.to_lowercase().contains(&query)
+                || entry
+                    .description
+                    .as_deref()
+                    .is_some_and(|description| description.to_lowercase().contains(&query));
+            if matches {
+                let dir = entry.path.as_ref().map(|path| root.join(path));
+                found.push(FoundAsset {
+                    registry: spec.name.clone(),
+                    entry,
+                    dir,
+                });
+            }
+        }
+    }
+    Ok(found)
+}
+
+/// Finds an asset entry by exact name, taking the first hit in registry
+/// order.
+pub fn find_asset(registries: &[RegistrySpec], name: &str) -> anyhow::Result<FoundAsset> {
+    search_assets(registries, "")?
+        .into_iter()
+        .find(|found| found.entry.name == name)
+        .with_context(|| format!("no registry provides an asset named `{name}`"))
+}
+
+/// Finds a template by exact name, taking the first hit in registry order.
+pub fn find(registries: &[RegistrySpec], name: &str) -> anyhow::Result<FoundTemplate> {
+    search(registries, "")?
+        .into_iter()
+        .find(|found| found.entry.name == name)
+        .with_context(|| format!("no registry provides a template named `{name}`"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_index() {
+        let index = RegistryIndex::parse(
+            "[[templates]]\nname = \"2d\"\ndescription = \"2D starter\"\npath = \"templates/2d\"\n",
+        )
+        .unwrap();
+        assert_eq!(index.templates.len(), 1);
+        assert_eq!(index.templates[0].name, "2d");
+    }
+
+    #[test]
+    fn parses_asset_entries_of_both_kinds() {
+        let index = RegistryIndex::parse(
+            "[[assets]]\nname = \"pixel-ui\"\npath = \"assets/pixel-ui\"\n\n\
+             [[assets]]\nname = \"kenney-input\"\ncrate = \"bevy_kenney_input\"\n",
+        )
+        .unwrap();
+        assert_eq!(index.assets.len(), 2);
+        assert_eq!(index.assets[0].path.as_deref(), Some("assets/pixel-ui"));
+        assert_eq!(
+            index.assets[1].crate_name.as_deref(),
+            Some("bevy_kenney_input")
+        );
+    }
+
+    #[test]
+    fn base64_matches_the_rfc_vectors() {
+        assert_eq!(base64(b""), "");
+        assert_eq!(base64(b"f"), "Zg==");
+        assert_eq!(base64(b"fo"), "Zm8=");
+        assert_eq!(base64(b"foo"), "Zm9v");
+        assert_eq!(base64(b"hunter2:"), "aHVudGVyMjo=");
+    }
+}
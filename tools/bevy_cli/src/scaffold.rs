@@ -0,0 +1,889 @@
+//! Post-generation scaffolding: optional pieces added to a crate after its
+//! template has rendered, with the Cargo.toml edits done through `toml_edit`
+//! so existing sections and formatting survive.
+
+use std::path::Path;
+
+use anyhow::Context;
+use toml_edit::{table, value, Document};
+
+use crate::fs_util;
+use crate::template::manifest::BinSpec;
+use crate::template::render;
+
+/// Adds a runnable minimal example under `examples/`.
+pub fn add_examples(crate_dir: &Path) -> anyhow::Result<()> {
+    let examples = crate_dir.join("examples");
+    std::fs::create_dir_all(&examples)?;
+    fs_util::write_file(
+        &examples.join("hello.rs"),
+        include_str!("../templates/scaffold/example.rs").as_bytes(),
+        false,
+    )
+}
+
+/// Adds a criterion benchmark harness under `benches/` and wires the
+/// dev-dependency and `[[bench]]` target into Cargo.toml.
+pub fn add_benches(crate_dir: &Path) -> anyhow::Result<()> {
+    let benches = crate_dir.join("benches");
+    std::fs::create_dir_all(&benches)?;
+    fs_util::write_file(
+        &benches.join("startup.rs"),
+        include_str!("../templates/scaffold/bench.rs").as_bytes(),
+        false,
+    )?;
+
+    let manifest_path = crate_dir.join("Cargo.toml");
+    let mut manifest = read_manifest(&manifest_path)?;
+    manifest["dev-dependencies"]
+        .or_insert(table())
+        .as_table_mut()
+        .context("`dev-dependencies` is not a table")?
+        .insert("criterion", value("0.5"));
+    let mut bench = toml_edit::Table::new();
+    bench.insert("name", value("startup"));
+    bench.insert("harness", value(false));
+    manifest["bench"]
+        .or_insert(toml_edit::Item::ArrayOfTables(Default::default()))
+        .as_array_of_tables_mut()
+        .context("`bench` is not an array of tables")?
+        .push(bench);
+    write_manifest(&manifest_path, &manifest)
+}
+
+/// Writes a `rust-toolchain.toml` pinning the toolchain channel, the
+/// components every Bevy project wants (clippy, rustfmt), and the wasm
+/// target used for web builds.
+pub fn add_toolchain(project_dir: &Path, channel: &str) -> anyhow::Result<()> {
+    let contents = format!(
+        r#"[toolchain]
+channel = "{channel}"
+components = ["clippy", "rustfmt"]
+targets = ["wasm32-unknown-unknown"]
+"#
+    );
+    fs_util::write_file(
+        &project_dir.join("rust-toolchain.toml"),
+        contents.as_bytes(),
+        false,
+    )
+}
+
+/// Writes the fast-compile `.cargo/config.toml`: platform-appropriate fast
+/// linkers (mold/lld/rust-lld), optimized dependencies in dev, and a `rund`
+/// alias for Bevy's dynamic_linking dev setup.
+pub fn add_fast_compiles(project_dir: &Path) -> anyhow::Result<()> {
+    let cargo_dir = project_dir.join(".cargo");
+    std::fs::create_dir_all(&cargo_dir)?;
+    fs_util::write_file(
+        &cargo_dir.join("config.toml"),
+        include_str!("../templates/scaffold/fast_compiles_config.toml").as_bytes(),
+        false,
+    )
+}
+
+/// Makes a generated project buildable for the browser: a Trunk-ready
+/// `index.html` with a styled canvas, a `Trunk.toml` for local serving, and a
+/// CI job that builds the wasm target.
+pub fn add_web_target(project_dir: &Path, project_name: &str) -> anyhow::Result<()> {
+    let mut context = tera::Context::new();
+    context.insert("project_name", project_name);
+    let index = render::render_str(
+        include_str!("../templates/scaffold/web/index.html.tera"),
+        &context,
+    )?;
+    fs_util::write_file(&project_dir.join("index.html"), index.as_bytes(), false)?;
+    fs_util::write_file(
+        &project_dir.join("Trunk.toml"),
+        include_str!("../templates/scaffold/web/Trunk.toml").as_bytes(),
+        false,
+    )?;
+    let workflows = project_dir.join(".github/workflows");
+    std::fs::create_dir_all(&workflows)?;
+    fs_util::write_file(
+        &workflows.join("web.yml"),
+        include_str!("../templates/scaffold/web/web.yml").as_bytes(),
+        false,
+    )
+}
+
+/// Prepares a project for Android builds via cargo-apk: the
+/// `[package.metadata.android]` manifest section, an NDK environment file
+/// template, an icon placeholder, and a CI job. The metadata lands in
+/// `crate_dir`'s manifest (the game binary crate in workspace mode), the
+/// support files at the project root.
+pub fn add_android_target(
+    project_dir: &Path,
+    crate_dir: &Path,
+    project_name: &str,
+) -> anyhow::Result<()> {
+    let manifest_path = crate_dir.join("Cargo.toml");
+    let mut manifest = read_manifest(&manifest_path)?;
+    let android = manifest["package"]["metadata"]
+        .or_insert(table())
+        .as_table_mut()
+        .context("`package.metadata` is not a table")?
+        .entry("android")
+        .or_insert(table())
+        .as_table_mut()
+        .context("`package.metadata.android` is not a table")?;
+    android.insert("apk_label", value(project_name));
+    // Java package identifiers cannot contain hyphens.
+    android.insert(
+        "package",
+        value(format!("org.bevyengine.{}", project_name.replace('-', "_"))),
+    );
+    let mut sdk = toml_edit::Table::new();
+    sdk.insert("min_sdk_version", value(24));
+    sdk.insert("target_sdk_version", value(33));
+    android.insert("sdk", toml_edit::Item::Table(sdk));
+    write_manifest(&manifest_path, &manifest)?;
+
+    let android_dir = project_dir.join("mobile/android");
+    std::fs::create_dir_all(android_dir.join("icons"))?;
+    fs_util::write_file(
+        &android_dir.join("ndk.env.example"),
+        include_str!("../templates/scaffold/mobile/ndk.env.example").as_bytes(),
+        false,
+    )?;
+    fs_util::write_file(
+        &android_dir.join("icons/README.md"),
+        include_str!("../templates/scaffold/mobile/android_icons.md").as_bytes(),
+        false,
+    )?;
+    let workflows = project_dir.join(".github/workflows");
+    std::fs::create_dir_all(&workflows)?;
+    fs_util::write_file(
+        &workflows.join("android.yml"),
+        include_str!("../templates/scaffold/mobile/android.yml").as_bytes(),
+        false,
+    )
+}
+
+/// Prepares a project for iOS builds: an xcodegen `project.yml`, an icon
+/// placeholder, and a CI job that generates the Xcode project and builds the
+/// iOS target.
+pub fn add_ios_target(project_dir: &Path, project_name: &str) -> anyhow::Result<()> {
+    let mut context = tera::Context::new();
+    context.insert("project_name", project_name);
+    let project = render::render_str(
+        include_str!("../templates/scaffold/mobile/project.yml.tera"),
+        &context,
+    )?;
+    let ios_dir = project_dir.join("mobile/ios");
+    std::fs::create_dir_all(ios_dir.join("icons"))?;
+    fs_util::write_file(&ios_dir.join("project.yml"), project.as_bytes(), false)?;
+    fs_util::write_file(
+        &ios_dir.join("icons/README.md"),
+        include_str!("../templates/scaffold/mobile/ios_icons.md").as_bytes(),
+        false,
+    )?;
+    let workflows = project_dir.join(".github/workflows");
+    std::fs::create_dir_all(&workflows)?;
+    fs_util::write_file(
+        &workflows.join("ios.yml"),
+        include_str!("../templates/scaffold/mobile/ios.yml").as_bytes(),
+        false,
+    )
+}
+
+/// Splits a project into several `[[bin]]` targets sharing a library crate:
+/// writes `src/lib.rs` (unless the template already provided one) plus one
+/// `src/bin/<name>.rs` per target, wires the `[[bin]]` sections and any
+/// per-bin cargo features into Cargo.toml, and adds a CI job per binary.
+pub fn add_bins(
+    project_dir: &Path,
+    crate_dir: &Path,
+    project_name: &str,
+    bins: &[BinSpec],
+) -> anyhow::Result<()> {
+    let mut context = tera::Context::new();
+    context.insert("project_name", project_name);
+    // The shared library is referenced by its crate name, hyphens and all
+    // normalized the way cargo does.
+    context.insert("crate_name", &project_name.replace('-', "_"));
+    context.insert("bins", bins);
+
+    let lib = crate_dir.join("src/lib.rs");
+    if !lib.exists() {
+        let contents = render::render_str(
+            include_str!("../templates/scaffold/bin_lib.rs.tera"),
+            &context,
+        )?;
+        fs_util::write_file(&lib, contents.as_bytes(), false)?;
+    }
+    let bin_dir = crate_dir.join("src/bin");
+    std::fs::create_dir_all(&bin_dir)?;
+    let bin_main = render::render_str(
+        include_str!("../templates/scaffold/bin_main.rs.tera"),
+        &context,
+    )?;
+
+    let manifest_path = crate_dir.join("Cargo.toml");
+    let mut manifest = read_manifest(&manifest_path)?;
+    for bin in bins {
+        fs_util::write_file(
+            &bin_dir.join(format!("{}.rs", bin.name)),
+            bin_main.as_bytes(),
+            false,
+        )?;
+        let mut entry = toml_edit::Table::new();
+        entry.insert("name", value(bin.name.as_str()));
+        entry.insert("path", value(format!("src/bin/{}.rs", bin.name)));
+        if !bin.features.is_empty() {
+            let mut required = toml_edit::Array::new();
+            for feature in &bin.features {
+                required.push(feature.as_str());
+            }
+            entry.insert("required-features", value(required));
+        }
+        manifest["bin"]
+            .or_insert(toml_edit::Item::ArrayOfTables(Default::default()))
+            .as_array_of_tables_mut()
+            .context("`bin` is not an array of tables")?
+            .push(entry);
+        let features = manifest["features"]
+            .or_insert(table())
+            .as_table_mut()
+            .context("`features` is not a table")?;
+        for feature in &bin.features {
+            if !features.contains_key(feature) {
+                features.insert(feature, value(toml_edit::Array::new()));
+            }
+        }
+    }
+    write_manifest(&manifest_path, &manifest)?;
+
+    let workflows = project_dir.join(".github/workflows");
+    std::fs::create_dir_all(&workflows)?;
+    let ci = render::render_str(
+        include_str!("../templates/scaffold/bins.yml.tera"),
+        &context,
+    )?;
+    fs_util::write_file(&workflows.join("bins.yml"), ci.as_bytes(), false)
+}
+
+/// Bootstraps an `assets/` directory with a placeholder sprite and sound
+/// (fonts are licensed, so that directory only gets a note), and replaces
+/// `main.rs` with code that loads them, demonstrating the asset pipeline.
+pub fn add_assets(crate_dir: &Path) -> anyhow::Result<()> {
+    let assets = crate_dir.join("assets");
+    std::fs::create_dir_all(assets.join("sprites"))?;
+    std::fs::create_dir_all(assets.join("sounds"))?;
+    std::fs::create_dir_all(assets.join("fonts"))?;
+    fs_util::write_file(
+        &assets.join("sprites/placeholder.png"),
+        include_bytes!("../templates/scaffold/placeholder.png"),
+        false,
+    )?;
+    fs_util::write_file(
+        &assets.join("sounds/blip.wav"),
+        include_bytes!("../templates/scaffold/blip.wav"),
+        false,
+    )?;
+    fs_util::write_file(
+        &assets.join("fonts/README.md"),
+        include_str!("../templates/scaffold/fonts_readme.md").as_bytes(),
+        false,
+    )?;
+    fs_util::write_file(
+        &crate_dir.join("src/main.rs"),
+        include_str!("../templates/scaffold/assets_main.rs").as_bytes(),
+        false,
+    )
+}
+
+/// Replaces the bare `main.rs` with the canonical Bevy app structure: a
+/// `GameState` enum (Loading/Menu/InGame), one plugin per state, and the
+/// transition systems between them.
+pub fn add_states(crate_dir: &Path) -> anyhow::Result<()> {
+    fs_util::write_file(
+        &crate_dir.join("src/main.rs"),
+        include_str!("../templates/scaffold/states_main.rs").as_bytes(),
+        false,
+    )?;
+    fs_util::write_file(
+        &crate_dir.join("src/states.rs"),
+        include_str!("../templates/scaffold/states.rs").as_bytes(),
+        false,
+    )
+}
+
+/// CI providers the scaffolding can target; every provider gets the same
+/// format, clippy, and test checks.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default, clap::ValueEnum, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ContinuousIntegration {
+    /// GitHub Actions: `.github/workflows/ci.yml`
+    #[default]
+    Github,
+    /// GitLab CI: `.gitlab-ci.yml`
+    Gitlab,
+    /// Azure Pipelines: `azure-pipelines.yml`, with a Linux, Windows, and
+    /// macOS matrix
+    Azure,
+    /// CircleCI: `.circleci/config.yml`, with a wasm job when the project
+    /// targets the web
+    Circleci,
+    /// Woodpecker CI: `.woodpecker/check.yml`, for self-hosted forges
+    Woodpecker,
+    /// Forgejo/Gitea Actions: `.forgejo/workflows/ci.yml`; the runner is
+    /// GitHub-Actions-compatible, so it shares that workflow
+    Forgejo,
+}
+
+/// What the generated CI matrix covers; every flag feeds the Tera context
+/// shared by all providers.
+#[derive(clap::Args, Clone, Debug, Default)]
+pub struct CiOptions {
+    /// Operating systems for the CI matrix, comma separated (e.g.
+    /// `ubuntu-latest,windows-latest`); each provider has its own default
+    #[arg(long = "ci-os", value_name = "OS", value_delimiter = ',')]
+    pub os: Vec<String>,
+
+    /// Also test on the beta toolchain
+    #[arg(long = "ci-beta")]
+    pub beta: bool,
+
+    /// Generate the wasm build job even without a web target
+    #[arg(long = "ci-wasm")]
+    pub wasm: bool,
+
+    /// Leave the cargo fmt gate out of the pipeline
+    #[arg(long = "no-ci-fmt")]
+    pub no_fmt: bool,
+
+    /// Leave the clippy gate out of the pipeline
+    #[arg(long = "no-ci-clippy")]
+    pub no_clippy: bool,
+}
+
+/// Writes a CI pipeline running format, clippy, and test checks on every
+/// push and pull/merge request. The matrix — operating systems, stable,
+/// beta, and MSRV toolchains, the wasm job, which gates run — comes from
+/// [`CiOptions`]; when an MSRV is declared it is always tested so the
+/// declared minimum stays honest.
+pub fn add_ci(
+    project_dir: &Path,
+    msrv: Option<&str>,
+    provider: ContinuousIntegration,
+    options: &CiOptions,
+) -> anyhow::Result<()> {
+    let mut toolchains = vec!["stable".to_string()];
+    if options.beta {
+        toolchains.push("beta".to_string());
+    }
+    if let Some(msrv) = msrv {
+        toolchains.push(msrv.to_string());
+    }
+    let oses = if options.os.is_empty() {
+        match provider {
+            // Azure historically showcased the full OS spread; the others
+            // default to the cheapest runner.
+            ContinuousIntegration::Azure => vec![
+                "ubuntu-latest".to_string(),
+                "windows-latest".to_string(),
+                "macOS-latest".to_string(),
+            ],
+            _ => vec!["ubuntu-latest".to_string()],
+        }
+    } else {
+        options.os.clone()
+    };
+    let azure_matrix: Vec<serde_json::Value> = oses
+        .iter()
+        .flat_map(|os| {
+            toolchains.iter().map(move |toolchain| {
+                serde_json::json!({
+                    "name": ci_matrix_name(os, toolchain),
+                    "image": os,
+                    "toolchain": toolchain,
+                })
+            })
+        })
+        .collect();
+
+    let mut context = tera::Context::new();
+    context.insert("msrv", &msrv);
+    context.insert("toolchains", &toolchains);
+    context.insert("oses", &oses);
+    context.insert("azure_matrix", &azure_matrix);
+    context.insert("fmt", &!options.no_fmt);
+    context.insert("clippy", &!options.no_clippy);
+    // `add_web_target` wrote this when the project builds for the browser.
+    context.insert(
+        "wasm",
+        &(options.wasm || project_dir.join("Trunk.toml").is_file()),
+    );
+    match provider {
+        ContinuousIntegration::Github => {
+            let workflow =
+                render::render_str(include_str!("../templates/scaffold/ci.yml.tera"), &context)?;
+            let workflows = project_dir.join(".github/workflows");
+            std::fs::create_dir_all(&workflows)?;
+            fs_util::write_file(&workflows.join("ci.yml"), workflow.as_bytes(), false)
+        }
+        ContinuousIntegration::Gitlab => {
+            let pipeline = render::render_str(
+                include_str!("../templates/scaffold/gitlab-ci.yml.tera"),
+                &context,
+            )?;
+            fs_util::write_file(
+                &project_dir.join(".gitlab-ci.yml"),
+                pipeline.as_bytes(),
+                false,
+            )
+        }
+        ContinuousIntegration::Azure => {
+            let pipeline = render::render_str(
+                include_str!("../templates/scaffold/azure-pipelines.yml.tera"),
+                &context,
+            )?;
+            fs_util::write_file(
+                &project_dir.join("azure-pipelines.yml"),
+                pipeline.as_bytes(),
+                false,
+            )
+        }
+        ContinuousIntegration::Circleci => {
+            let config = render::render_str(
+                include_str!("../templates/scaffold/circleci-config.yml.tera"),
+                &context,
+            )?;
+            let circleci = project_dir.join(".circleci");
+            std::fs::create_dir_all(&circleci)?;
+            fs_util::write_file(&circleci.join("config.yml"), config.as_bytes(), false)
+        }
+        ContinuousIntegration::Woodpecker => {
+            let pipeline = render::render_str(
+                include_str!("../templates/scaffold/woodpecker.yml.tera"),
+                &context,
+            )?;
+            let woodpecker = project_dir.join(".woodpecker");
+            std::fs::create_dir_all(&woodpecker)?;
+            fs_util::write_file(&woodpecker.join("check.yml"), pipeline.as_bytes(), false)
+        }
+        ContinuousIntegration::Forgejo => {
+            let workflow =
+                render::render_str(include_str!("../templates/scaffold/ci.yml.tera"), &context)?;
+            let workflows = project_dir.join(".forgejo/workflows");
+            std::fs::create_dir_all(&workflows)?;
+            fs_util::write_file(&workflows.join("ci.yml"), workflow.as_bytes(), false)
+        }
+    }
+}
+
+/// An Azure matrix key: letters, numbers, and underscores only.
+fn ci_matrix_name(os: &str, toolchain: &str) -> String {
+    format!("{os}_{toolchain}")
+        .chars()
+        .map(|character| {
+            if character.is_ascii_alphanumeric() {
+                character
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+/// Source-tree organizations for the default template, mirroring the main
+/// community conventions for structuring Bevy games.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default, clap::ValueEnum, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Layout {
+    /// Everything in `main.rs` until the project decides otherwise
+    #[default]
+    Flat,
+    /// A `src/plugins/` directory with one plugin per file
+    Plugins,
+    /// One module per gameplay feature, each owning its components, systems,
+    /// and plugin
+    FeatureModules,
+}
+
+/// Replaces the default template's `src/` with the selected layout preset.
+/// `Flat` keeps the rendered template untouched.
+pub fn apply_layout(crate_dir: &Path, layout: Layout) -> anyhow::Result<()> {
+    let files: &[(&str, &str)] = match layout {
+        Layout::Flat => return Ok(()),
+        Layout::Plugins => &[
+            (
+                "src/main.rs",
+                include_str!("../templates/scaffold/layouts/plugins_main.rs"),
+            ),
+            (
+                "src/plugins/mod.rs",
+                include_str!("../templates/scaffold/layouts/plugins_mod.rs"),
+            ),
+            (
+                "src/plugins/camera.rs",
+                include_str!("../templates/scaffold/layouts/plugins_camera.rs"),
+            ),
+            (
+                "src/plugins/player.rs",
+                include_str!("../templates/scaffold/layouts/plugins_player.rs"),
+            ),
+        ],
+        Layout::FeatureModules => &[
+            (
+                "src/main.rs",
+                include_str!("../templates/scaffold/layouts/features_main.rs"),
+            ),
+            (
+                "src/player/mod.rs",
+                include_str!("../templates/scaffold/layouts/features_player.rs"),
+            ),
+            (
+                "src/ui/mod.rs",
+                include_str!("../templates/scaffold/layouts/features_ui.rs"),
+            ),
+        ],
+    };
+    for (rel, contents) in files {
+        let path = crate_dir.join(rel);
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        fs_util::write_file(&path, contents.as_bytes(), false)?;
+    }
+    Ok(())
+}
+
+/// Writes license files for an SPDX-style expression (`MIT`,
+/// `MIT OR Apache-2.0`), rendered through Tera so the copyright holder,
+/// year, and project name are substituted instead of copied verbatim. Dual
+/// licenses follow the Rust convention of `LICENSE-MIT` / `LICENSE-APACHE`;
+/// a license the template itself shipped is left untouched.
+pub fn add_license(
+    project_dir: &Path,
+    spec: &str,
+    values: &std::collections::BTreeMap<String, crate::template::vars::VarValue>,
+) -> anyhow::Result<()> {
+    if glob::glob(&project_dir.join("LICENSE*").to_string_lossy())?
+        .next()
+        .is_some()
+    {
+        return Ok(());
+    }
+    let mut context = render::context_from_vars(values);
+    context.insert("year", &current_year());
+    let ids: Vec<&str> = spec.split(" OR ").map(str::trim).collect();
+    for id in &ids {
+        let template = match *id {
+            "MIT" => include_str!("../templates/scaffold/licenses/MIT.tera"),
+            "Apache-2.0" => include_str!("../templates/scaffold/licenses/Apache-2.0.tera"),
+            "CC0-1.0" => include_str!("../templates/scaffold/licenses/CC0-1.0.tera"),
+            other => anyhow::bail!("no license text available for `{other}`"),
+        };
+        let file_name = if ids.len() > 1 {
+            match *id {
+                "Apache-2.0" => "LICENSE-APACHE".to_string(),
+                other => format!("LICENSE-{}", other.split('-').next().unwrap_or(other)),
+            }
+        } else {
+            "LICENSE".to_string()
+        };
+        let rendered = render::render_str(template, &context)?;
+        fs_util::write_file(&project_dir.join(file_name), rendered.as_bytes(), false)?;
+    }
+    Ok(())
+}
+
+/// The current year in UTC, from the system clock without a date dependency.
+fn current_year() -> i64 {
+    let days = (std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+        / 86_400) as i64;
+    // Civil-from-days conversion (Howard Hinnant's algorithm).
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146_096) / 365;
+    let y = yoe + era * 400;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    y + i64::from(mp >= 10)
+}
+
+/// Writes editor and lint configuration with Bevy-community defaults:
+/// `.editorconfig`, `rustfmt.toml`, and `clippy.toml`. The fmt and clippy
+/// checks these configure are already part of the CI workflow `add_ci`
+/// writes, so selecting both needs no extra wiring.
+pub fn add_editor_config(project_dir: &Path) -> anyhow::Result<()> {
+    for (name, contents) in [
+        (
+            ".editorconfig",
+            include_str!("../templates/scaffold/editorconfig"),
+        ),
+        (
+            "rustfmt.toml",
+            include_str!("../templates/scaffold/rustfmt.toml"),
+        ),
+        (
+            "clippy.toml",
+            include_str!("../templates/scaffold/clippy.toml"),
+        ),
+    ] {
+        fs_util::write_file(&project_dir.join(name), contents.as_bytes(), false)?;
+    }
+    Ok(())
+}
+
+/// Composable `.gitignore` sections selectable with `--gitignore`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, clap::ValueEnum, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GitignoreSection {
+    /// Cargo build artifacts
+    Rust,
+    /// Visual Studio Code workspace files
+    Vscode,
+    /// JetBrains IDE (RustRover, CLion) project files
+    Jetbrains,
+    /// OS junk: .DS_Store, Thumbs.db and friends
+    Os,
+    /// Asset-pipeline caches and editor backup files
+    Assets,
+}
+
+impl GitignoreSection {
+    fn contents(self) -> &'static str {
+        match self {
+            Self::Rust => "# Rust\n/target\n",
+            Self::Vscode => "# VS Code\n.vscode/\n*.code-workspace\n",
+            Self::Jetbrains => "# JetBrains IDEs\n.idea/\n*.iml\n",
+            Self::Os => "# OS junk\n.DS_Store\nThumbs.db\nDesktop.ini\n",
+            Self::Assets => {
+                "# Asset pipeline\n.bevy/\nimported_assets/\n*.blend1\n*.kra~\n*.import\n"
+            }
+        }
+    }
+}
+
+/// Writes a `.gitignore` composed from the selected sections. With no
+/// explicit selection a `.gitignore` the template shipped wins; otherwise
+/// the composed file replaces it.
+pub fn write_gitignore(project_dir: &Path, sections: &[GitignoreSection]) -> anyhow::Result<()> {
+    let path = project_dir.join(".gitignore");
+    if sections.is_empty() && path.exists() {
+        return Ok(());
+    }
+    let sections = if sections.is_empty() {
+        &[GitignoreSection::Rust]
+    } else {
+        sections
+    };
+    let mut contents = String::new();
+    for section in sections {
+        if !contents.is_empty() {
+            contents.push('\n');
+        }
+        contents.push_str(section.contents());
+    }
+    fs_util::write_file(&path, contents.as_bytes(), false)
+}
+
+/// Writes a README.md populated from the same Tera context the template
+/// rendered with: name, version and license badges, native (and wasm, when
+/// selected) build instructions, and a controls section to fill in. A README
+/// the template itself produced is left untouched.
+pub fn add_readme(
+    project_dir: &Path,
+    values: &std::collections::BTreeMap<String, crate::template::vars::VarValue>,
+    web: bool,
+) -> anyhow::Result<()> {
+    let readme_path = project_dir.join("README.md");
+    if readme_path.exists() {
+        return Ok(());
+    }
+    let mut context = render::context_from_vars(values);
+    context.insert("web", &web);
+    let readme = render::render_str(
+        include_str!("../templates/scaffold/readme.md.tera"),
+        &context,
+    )?;
+    fs_util::write_file(&readme_path, readme.as_bytes(), false)
+}
+
+/// Sets the Rust edition and, when given, the MSRV (`rust-version`) in a
+/// generated manifest. Workspace roots with `[workspace.package]` are edited
+/// there, so member crates inherit both fields; manifests with neither
+/// section are left alone.
+pub fn set_rust_versions(
+    manifest_path: &Path,
+    edition: &str,
+    msrv: Option<&str>,
+) -> anyhow::Result<()> {
+    let mut manifest = read_manifest(manifest_path)?;
+    let package = if manifest
+        .get("workspace")
+        .and_then(|workspace| workspace.get("package"))
+        .is_some()
+    {
+        manifest["workspace"]["package"]
+            .as_table_mut()
+            .context("`workspace.package` is not a table")?
+    } else if manifest.get("package").is_some() {
+        manifest["package"]
+            .as_table_mut()
+            .context("`package` is not a table")?
+    } else {
+        return Ok(());
+    };
+    package.insert("edition", value(edition));
+    if let Some(msrv) = msrv {
+        package.insert("rust-version", value(msrv));
+    }
+    write_manifest(manifest_path, &manifest)
+}
+
+/// Adds an `xtask` automation crate: starter dist/assets/ci tasks, workspace
+/// membership in the root manifest, and a `cargo xtask` alias merged into
+/// `.cargo/config.toml` without clobbering what's already there.
+pub fn add_xtask(project_dir: &Path) -> anyhow::Result<()> {
+    let xtask_dir = project_dir.join("xtask");
+    std::fs::create_dir_all(xtask_dir.join("src"))?;
+    fs_util::write_file(
+        &xtask_dir.join("Cargo.toml"),
+        include_str!("../templates/scaffold/xtask_cargo.toml").as_bytes(),
+        false,
+    )?;
+    fs_util::write_file(
+        &xtask_dir.join("src/main.rs"),
+        include_str!("../templates/scaffold/xtask_main.rs").as_bytes(),
+        false,
+    )?;
+
+    let manifest_path = project_dir.join("Cargo.toml");
+    let mut manifest = read_manifest(&manifest_path)?;
+    // Single-crate projects gain a [workspace] section here; the root package
+    // stays an implicit member.
+    manifest["workspace"]
+        .or_insert(table())
+        .as_table_mut()
+        .context("`workspace` is not a table")?
+        .entry("members")
+        .or_insert(value(toml_edit::Array::new()))
+        .as_array_mut()
+        .context("`workspace.members` is not an array")?
+        .push("xtask");
+    write_manifest(&manifest_path, &manifest)?;
+
+    let cargo_config = project_dir.join(".cargo/config.toml");
+    let mut config: Document = if cargo_config.exists() {
+        read_manifest(&cargo_config)?
+    } else {
+        std::fs::create_dir_all(cargo_config.parent().unwrap())?;
+        Document::new()
+    };
+    config["alias"]
+        .or_insert(table())
+        .as_table_mut()
+        .context("`alias` is not a table")?
+        .insert("xtask", value("run --package xtask --"));
+    fs_util::write_file(&cargo_config, config.to_string().as_bytes(), false)
+}
+
+pub fn read_manifest(path: &Path) -> anyhow::Result<Document> {
+    std::fs::read_to_string(path)
+        .with_context(|| format!("failed to read {}", path.display()))?
+        .parse()
+        .with_context(|| format!("failed to parse {}", path.display()))
+}
+
+pub fn write_manifest(path: &Path, manifest: &Document) -> anyhow::Result<()> {
+    fs_util::write_file(path, manifest.to_string().as_bytes(), false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn benches_are_wired_into_the_manifest() {
+        let dir = std::env::temp_dir().join("bevy_cli_scaffold_test");
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(
+            dir.join("Cargo.toml"),
+            "[package]\nname = \"demo\"\n\n[dependencies]\nbevy = \"0.12\"\n",
+        )
+        .unwrap();
+        add_benches(&dir).unwrap();
+        let manifest = std::fs::read_to_string(dir.join("Cargo.toml")).unwrap();
+        assert!(manifest.contains("criterion"));
+        assert!(manifest.contains("harness = false"));
+        assert!(dir.join("benches/startup.rs").exists());
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn each_ci_provider_writes_its_own_file() {
+        let dir = std::env::temp_dir().join("bevy_cli_ci_test");
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        let defaults = CiOptions::default();
+        add_ci(&dir, Some("1.76"), ContinuousIntegration::Github, &defaults).unwrap();
+        add_ci(&dir, Some("1.76"), ContinuousIntegration::Gitlab, &defaults).unwrap();
+        add_ci(&dir, Some("1.76"), ContinuousIntegration::Azure, &defaults).unwrap();
+        let workflow = std::fs::read_to_string(dir.join(".github/workflows/ci.yml")).unwrap();
+        assert!(workflow.contains("\"1.76\""));
+        let pipeline = std::fs::read_to_string(dir.join(".gitlab-ci.yml")).unwrap();
+        assert!(pipeline.contains("TOOLCHAIN: [\"stable\", \"1.76\"]"));
+        assert!(pipeline.contains("cargo clippy --all-targets -- -D warnings"));
+        assert!(pipeline.contains("- Cargo.lock"));
+        let azure = std::fs::read_to_string(dir.join("azure-pipelines.yml")).unwrap();
+        assert!(azure.contains("windows-latest"));
+        assert!(azure.contains("toolchain: \"1.76\""));
+        add_ci(&dir, None, ContinuousIntegration::Circleci, &defaults).unwrap();
+        let circle = std::fs::read_to_string(dir.join(".circleci/config.yml")).unwrap();
+        assert!(circle.contains("{{ checksum \"Cargo.lock\" }}"));
+        assert!(!circle.contains("wasm32-unknown-unknown"));
+        std::fs::write(dir.join("Trunk.toml"), "").unwrap();
+        add_ci(&dir, None, ContinuousIntegration::Circleci, &defaults).unwrap();
+        let circle = std::fs::read_to_string(dir.join(".circleci/config.yml")).unwrap();
+        assert!(circle.contains("wasm32-unknown-unknown"));
+        add_ci(
+            &dir,
+            Some("1.76"),
+            ContinuousIntegration::Woodpecker,
+            &defaults,
+        )
+        .unwrap();
+        let woodpecker = std::fs::read_to_string(dir.join(".woodpecker/check.yml")).unwrap();
+        assert!(woodpecker.contains("check-1.76:"));
+        add_ci(&dir, None, ContinuousIntegration::Forgejo, &defaults).unwrap();
+        let forgejo = std::fs::read_to_string(dir.join(".forgejo/workflows/ci.yml")).unwrap();
+        assert!(!forgejo.contains("\"1.76\""));
+        assert!(forgejo.contains("cargo clippy"));
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn ci_options_shape_the_matrix_and_gates() {
+        let dir = std::env::temp_dir().join("bevy_cli_ci_options_test");
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        let options = CiOptions {
+            os: vec!["ubuntu-latest".to_string(), "windows-latest".to_string()],
+            beta: true,
+            wasm: true,
+            no_fmt: true,
+            no_clippy: false,
+        };
+        add_ci(&dir, Some("1.76"), ContinuousIntegration::Github, &options).unwrap();
+        let workflow = std::fs::read_to_string(dir.join(".github/workflows/ci.yml")).unwrap();
+        assert!(workflow.contains("os: [ubuntu-latest, windows-latest]"));
+        assert!(workflow.contains("toolchain: [\"stable\", \"beta\", \"1.76\"]"));
+        assert!(workflow.contains("wasm32-unknown-unknown"));
+        assert!(!workflow.contains("cargo fmt"));
+        assert!(workflow.contains("cargo clippy"));
+        add_ci(&dir, None, ContinuousIntegration::Azure, &options).unwrap();
+        let azure = std::fs::read_to_string(dir.join("azure-pipelines.yml")).unwrap();
+        assert!(azure.contains("windows_latest_beta:"));
+        assert!(!azure.contains("macOS-latest"));
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,249 @@
+//! Shared subprocess plumbing for everything the CLI spawns — cargo, git,
+//! wasm-bindgen and friends.
+//!
+//! A [`Subprocess`] is a thin builder over [`std::process::Command`] that
+//! adds what ad-hoc spawning keeps reimplementing: a wall-clock timeout,
+//! kill-on-drop so an interrupted command never leaves orphans, optional
+//! scrubbing of secret-looking environment variables, and localized error
+//! messages. Commands should route new spawns through here; existing call
+//! sites migrate as they are touched.
+
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use anyhow::Context;
+
+use crate::i18n::localize;
+
+/// Environment variable name fragments that mark a value as a secret;
+/// matched case-insensitively when scrubbing the child environment.
+const SECRET_ENV_HINTS: &[&str] = &["token", "secret", "password", "api_key"];
+
+/// How often a running child is polled for exit or timeout.
+const POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+pub struct Subprocess {
+    program: String,
+    args: Vec<String>,
+    current_dir: Option<PathBuf>,
+    env: Vec<(String, String)>,
+    timeout: Option<Duration>,
+    scrub_secrets: bool,
+}
+
+impl Subprocess {
+    pub fn new(program: impl Into<String>) -> Self {
+        Self {
+            program: program.into(),
+            args: Vec::new(),
+            current_dir: None,
+            env: Vec::new(),
+            timeout: None,
+            scrub_secrets: false,
+        }
+    }
+
+    pub fn arg(mut self, arg: impl Into<String>) -> Self {
+        self.args.push(arg.into());
+        self
+    }
+
+    pub fn args<I: IntoIterator<Item = S>, S: Into<String>>(mut self, args: I) -> Self {
+        self.args.extend(args.into_iter().map(Into::into));
+        self
+    }
+
+    pub fn current_dir(mut self, dir: impl AsRef<Path>) -> Self {
+        self.current_dir = Some(dir.as_ref().to_path_buf());
+        self
+    }
+
+    pub fn env(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.env.push((name.into(), value.into()));
+        self
+    }
+
+    /// Sets a default: applied only when the variable is not already set in
+    /// the parent environment, which always wins.
+    pub fn env_default(self, name: &str, value: impl Into<String>) -> Self {
+        if std::env::var_os(name).is_some() {
+            self
+        } else {
+            self.env(name, value)
+        }
+    }
+
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Withholds inherited environment variables whose names look like
+    /// secrets (tokens, passwords, API keys) from the child. Use for
+    /// subprocesses that do not need credentials, e.g. build tooling.
+    pub fn scrub_secrets(mut self) -> Self {
+        self.scrub_secrets = true;
+        self
+    }
+
+    /// Runs the command with output streaming to the terminal; fails with a
+    /// localized message when it cannot be spawned, exits non-zero, or
+    /// outlives the timeout.
+    pub fn run(&self) -> anyhow::Result<()> {
+        let child = self.spawn(std::process::Stdio::inherit())?;
+        let status = self.supervise(child)?;
+        anyhow::ensure!(
+            status.success(),
+            "{}",
+            localize!(
+                "subprocess-failed",
+                program = self.program,
+                code = status
+                    .code()
+                    .map_or_else(|| "signal".to_string(), |code| code.to_string())
+            )
+        );
+        Ok(())
+    }
+
+    /// Runs the command and returns its captured stdout; stderr still
+    /// streams to the terminal so progress and errors stay visible.
+    pub fn capture(&self) -> anyhow::Result<String> {
+        let mut child = self.spawn(std::process::Stdio::piped())?;
+        let stdout = child.child.stdout.take().expect("stdout was piped");
+        // Read on a helper thread so a child filling the pipe cannot
+        // deadlock against the exit polling.
+        let reader = std::thread::spawn(move || {
+            let mut buffer = String::new();
+            let mut stdout = stdout;
+            stdout.read_to_string(&mut buffer).map(|_| buffer)
+        });
+        let status = self.supervise(child)?;
+        let output = reader
+            .join()
+            .expect("stdout reader does not panic")
+            .with_context(|| format!("failed to read {} output", self.program))?;
+        anyhow::ensure!(
+            status.success(),
+            "{}",
+            localize!(
+                "subprocess-failed",
+                program = self.program,
+                code = status
+                    .code()
+                    .map_or_else(|| "signal".to_string(), |code| code.to_string())
+            )
+        );
+        Ok(output)
+    }
+
+    fn spawn(&self, stdout: std::process::Stdio) -> anyhow::Result<KillOnDrop> {
+        let mut command = std::process::Command::new(&self.program);
+        command.args(&self.args).stdout(stdout);
+        if let Some(dir) = &self.current_dir {
+            command.current_dir(dir);
+        }
+        if self.scrub_secrets {
+            for (name, _) in std::env::vars_os() {
+                let lower = name.to_string_lossy().to_lowercase();
+                if SECRET_ENV_HINTS.iter().any(|hint| lower.contains(hint)) {
+                    command.env_remove(&name);
+                }
+            }
+        }
+        for (name, value) in &self.env {
+            command.env(name, value);
+        }
+        let child = command
+            .spawn()
+            .with_context(|| localize!("subprocess-missing", program = self.program))?;
+        Ok(KillOnDrop {
+            child,
+            exited: false,
+        })
+    }
+
+    /// Waits for the child, enforcing the timeout; a normally-exited child
+    /// is marked so the guard's drop does not try to kill it again.
+    fn supervise(&self, mut child: KillOnDrop) -> anyhow::Result<std::process::ExitStatus> {
+        let started = Instant::now();
+        loop {
+            if let Some(status) = child.child.try_wait()? {
+                child.exited = true;
+                return Ok(status);
+            }
+            if let Some(timeout) = self.timeout {
+                if started.elapsed() >= timeout {
+                    // The guard's drop kills and reaps the child.
+                    drop(child);
+                    anyhow::bail!(localize!(
+                        "subprocess-timeout",
+                        program = self.program,
+                        seconds = timeout.as_secs()
+                    ));
+                }
+            }
+            std::thread::sleep(POLL_INTERVAL);
+        }
+    }
+}
+
+/// Owns a running child and kills it when dropped, so a `?`-propagated
+/// error or ctrl-c never leaves a cargo or ffmpeg orphan behind.
+struct KillOnDrop {
+    child: std::process::Child,
+    exited: bool,
+}
+
+impl Drop for KillOnDrop {
+    fn drop(&mut self) {
+        if !self.exited {
+            let _ = self.child.kill();
+            let _ = self.child.wait();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A controllable stand-in process: the shell running a given script.
+    fn fake(script: &str) -> Subprocess {
+        Subprocess::new("sh").arg("-c").arg(script)
+    }
+
+    #[test]
+    fn captured_output_and_exit_codes_round_trip() {
+        assert_eq!(fake("echo hello").capture().unwrap(), "hello\n");
+        let error = fake("exit 3").run().unwrap_err().to_string();
+        assert!(error.contains('3'), "{error}");
+    }
+
+    #[test]
+    fn hung_processes_hit_the_timeout() {
+        let started = Instant::now();
+        let error = fake("sleep 30")
+            .timeout(Duration::from_millis(200))
+            .run()
+            .unwrap_err();
+        assert!(started.elapsed() < Duration::from_secs(5));
+        assert!(error.to_string().contains("sh"), "{error}");
+    }
+
+    #[test]
+    fn secret_environment_variables_are_scrubbed() {
+        std::env::set_var("BEVY_CLI_TEST_TOKEN", "hunter2");
+        let kept = fake("printenv BEVY_CLI_TEST_TOKEN || echo scrubbed")
+            .capture()
+            .unwrap();
+        assert_eq!(kept, "hunter2\n");
+        let scrubbed = fake("printenv BEVY_CLI_TEST_TOKEN || echo scrubbed")
+            .scrub_secrets()
+            .capture()
+            .unwrap();
+        assert_eq!(scrubbed, "scrubbed\n");
+        std::env::remove_var("BEVY_CLI_TEST_TOKEN");
+    }
+}
@@ -0,0 +1,175 @@
+use std::collections::BTreeSet;
+
+use regex::Regex;
+
+/// Context variables and filters referenced by a template's Tera files.
+#[derive(Debug, Default)]
+pub struct Analysis {
+    pub variables: BTreeSet<String>,
+    pub filters: BTreeSet<String>,
+    /// Names bound by `{% for %}` loops; they shadow context lookups and
+    /// must not count as used context variables.
+    pub locals: BTreeSet<String>,
+}
+
+/// Context values the CLI always provides, which templates may use without
+/// declaring them in the manifest.
+pub const BUILTIN_VARS: &[&str] = &[
+    "project_name",
+    "plugin_struct",
+    "bevy_version",
+    "bevy_features",
+    "author",
+    "email",
+    "authors",
+    "student_name",
+    "student_email",
+];
+
+/// Tera keywords and literals that look like identifiers but are not
+/// context variables.
+const KEYWORDS: &[&str] = &[
+    "if",
+    "elif",
+    "else",
+    "endif",
+    "for",
+    "endfor",
+    "in",
+    "and",
+    "or",
+    "not",
+    "is",
+    "set",
+    "set_global",
+    "true",
+    "false",
+    "True",
+    "False",
+    "loop",
+    "as",
+    "block",
+    "endblock",
+    "include",
+    "macro",
+    "endmacro",
+    "filter",
+    "endfilter",
+    "raw",
+    "endraw",
+];
+
+/// Scans one Tera source (file contents or a path string) and records the
+/// variables and filters it references.
+pub fn scan(text: &str, analysis: &mut Analysis) {
+    let block = Regex::new(r"\{\{(.*?)\}\}|\{%(.*?)%\}").unwrap();
+    let identifier = Regex::new(r"[A-Za-z_][A-Za-z0-9_]*").unwrap();
+    let filter = Regex::new(r"\|\s*([A-Za-z_][A-Za-z0-9_]*)").unwrap();
+    for capture in block.captures_iter(text) {
+        let expression = capture
+            .get(1)
+            .or_else(|| capture.get(2))
+            .map(|m| m.as_str())
+            .unwrap_or_default();
+        let expression = strip_strings(expression);
+        if let Some(bindings) = expression.trim_start().strip_prefix("for ") {
+            if let Some((bindings, _)) = bindings.split_once(" in ") {
+                for binding in bindings.split(',') {
+                    analysis.locals.insert(binding.trim().to_string());
+                }
+            }
+        }
+        let mut filter_names = BTreeSet::new();
+        for found in filter.captures_iter(&expression) {
+            filter_names.insert(found[1].to_string());
+        }
+        for found in identifier.find_iter(&expression) {
+            let name = found.as_str();
+            // Skip keywords, filters, member accesses like `loop.last`, and
+            // named arguments like `replace(from=..., to=...)`.
+            let preceded_by_dot = expression[..found.start()].ends_with('.');
+            let rest = expression[found.end()..].trim_start();
+            let named_argument = rest.starts_with('=') && !rest.starts_with("==");
+            if KEYWORDS.contains(&name)
+                || filter_names.contains(name)
+                || preceded_by_dot
+                || named_argument
+            {
+                continue;
+            }
+            analysis.variables.insert(name.to_string());
+        }
+        analysis.filters.extend(filter_names);
+    }
+}
+
+impl Analysis {
+    /// The context variables actually looked up: everything referenced minus
+    /// loop-local bindings.
+    pub fn context_variables(&self) -> BTreeSet<&str> {
+        self.variables
+            .difference(&self.locals)
+            .map(String::as_str)
+            .collect()
+    }
+}
+
+/// Blanks out string literals so their contents are not mistaken for
+/// identifiers.
+fn strip_strings(expression: &str) -> String {
+    let mut output = String::with_capacity(expression.len());
+    let mut in_string: Option<char> = None;
+    for c in expression.chars() {
+        match in_string {
+            Some(quote) if c == quote => {
+                in_string = None;
+                output.push(' ');
+            }
+            Some(_) => output.push(' '),
+            None if c == '"' || c == '\'' || c == '`' => {
+                in_string = Some(c);
+                output.push(' ');
+            }
+            None => output.push(c),
+        }
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn analyze(text: &str) -> Analysis {
+        let mut analysis = Analysis::default();
+        scan(text, &mut analysis);
+        analysis
+    }
+
+    #[test]
+    fn finds_variables_in_expressions_and_statements() {
+        let analysis = analyze(
+            "name = \"{{ project_name }}\"\n{% if use_physics %}x{% endif %}\n{% for feature in bevy_features %}{{ feature }}{% endfor %}",
+        );
+        let names: Vec<_> = analysis.context_variables().into_iter().collect();
+        assert_eq!(names, vec!["bevy_features", "project_name", "use_physics"]);
+    }
+
+    #[test]
+    fn filters_are_reported_separately() {
+        let analysis = analyze("{{ project_name | upper | replace(from=\"a\", to=\"b\") }}");
+        assert!(analysis.filters.contains("upper"));
+        assert!(analysis.filters.contains("replace"));
+        assert!(!analysis.variables.contains("upper"));
+        // Filter arguments count as identifiers we skip via strings, but
+        // named arguments remain out of the variable set.
+        assert!(analysis.variables.contains("project_name"));
+    }
+
+    #[test]
+    fn string_literals_and_member_accesses_are_ignored() {
+        let analysis = analyze("{% if loop.last and name == \"not_a_var\" %}{% endif %}");
+        let names: Vec<_> = analysis.variables.iter().map(String::as_str).collect();
+        assert_eq!(names, vec!["name"]);
+    }
+}
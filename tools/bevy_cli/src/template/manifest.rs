@@ -0,0 +1,85 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::template::vars::VarSpec;
+
+/// Name of the manifest file looked up in a template root.
+pub const MANIFEST_FILE: &str = "bevy_template.toml";
+
+/// Metadata describing a template, parsed from `bevy_template.toml` in the
+/// template root. Every field is optional so plain directories with no
+/// manifest still work as templates.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TemplateManifest {
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub description: Option<String>,
+    /// Variables the template expects, keyed by name.
+    #[serde(default)]
+    pub vars: BTreeMap<String, VarSpec>,
+    /// Glob patterns (relative to the template root, after rendering) of
+    /// files that will contain secrets, e.g. `".env"` or `"publish/*.toml"`.
+    /// These are created owner-readable only (0600 on unix).
+    #[serde(default)]
+    pub sensitive: Vec<String>,
+    /// Glob patterns (relative to the template root) copied verbatim: no
+    /// Tera rendering of contents or path segments, no `.tera` stripping.
+    /// Use this for asset trees (`"assets/**"`) that must never be sniffed
+    /// or rewritten.
+    #[serde(default)]
+    pub raw_copy: Vec<String>,
+    /// Extra `[[bin]]` targets to scaffold on top of the rendered project,
+    /// sharing a library crate; `bevy new --bins` overrides this list.
+    #[serde(default)]
+    pub bins: Vec<BinSpec>,
+    /// Set when generated projects rely on names differing only by case
+    /// (e.g. `Makefile` next to `makefile`); generation onto a
+    /// case-insensitive filesystem then warns up front.
+    #[serde(default)]
+    pub requires_case_sensitive: bool,
+    /// Disk space a generated project needs, in bytes, for the preflight
+    /// check — authors of asset-heavy templates should set this well above
+    /// the template's own size.
+    #[serde(default)]
+    pub required_space: Option<u64>,
+}
+
+/// One extra binary target: its name and the cargo features it requires.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct BinSpec {
+    pub name: String,
+    #[serde(default)]
+    pub features: Vec<String>,
+}
+
+impl TemplateManifest {
+    pub fn parse(contents: &str) -> anyhow::Result<Self> {
+        Ok(toml::from_str(contents)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_typed_vars() {
+        let manifest = TemplateManifest::parse(
+            "name = \"demo\"\n\
+             [vars.threads]\ntype = \"int\"\ndefault = 4\nmin = 1\n\
+             [vars.backend]\ntype = \"enum\"\nvalues = [\"vulkan\", \"metal\"]\n",
+        )
+        .unwrap();
+        assert_eq!(manifest.name.as_deref(), Some("demo"));
+        assert_eq!(manifest.vars.len(), 2);
+    }
+
+    #[test]
+    fn rejects_unknown_spec_fields() {
+        assert!(TemplateManifest::parse("[vars.x]\ntype = \"bool\"\nvalues = []\n").is_err());
+    }
+}
@@ -0,0 +1,5 @@
+pub mod analyze;
+pub mod manifest;
+pub mod render;
+pub mod source;
+pub mod vars;
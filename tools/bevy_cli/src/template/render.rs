@@ -0,0 +1,160 @@
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+use tera::Tera;
+
+use crate::fs_util;
+use crate::output;
+use crate::template::manifest::TemplateManifest;
+use crate::template::source::TemplateSource;
+use crate::template::vars::VarValue;
+
+/// Extension marking a file that is always rendered through Tera; the
+/// extension is stripped from the output path.
+const TERA_EXT: &str = "tera";
+
+/// Renders a template into `target_dir`, which must not already exist.
+///
+/// Files ending in `.tera` are rendered through Tera and written without the
+/// extension. Other files are rendered too when their contents are valid
+/// UTF-8, and copied verbatim otherwise (textures, audio, ...). Path segments
+/// may also contain Tera expressions, e.g. `src/{{ project_name }}.rs`.
+/// Entries matching the manifest's `raw_copy` globs bypass all of the above
+/// and are copied byte-for-byte under their original path.
+pub fn render(
+    source: &TemplateSource,
+    manifest: &TemplateManifest,
+    target_dir: &Path,
+    vars: &BTreeMap<String, VarValue>,
+) -> anyhow::Result<()> {
+    let context = context_from_vars(vars);
+    let sensitive = compile_globs(&manifest.sensitive)
+        .context("invalid `sensitive` pattern in template manifest")?;
+    let raw_copy = compile_globs(&manifest.raw_copy)
+        .context("invalid `raw_copy` pattern in template manifest")?;
+    anyhow::ensure!(
+        !target_dir.exists(),
+        "target directory {} already exists",
+        target_dir.display()
+    );
+    std::fs::create_dir_all(target_dir)
+        .with_context(|| format!("failed to create {}", target_dir.display()))?;
+
+    let entries = source.entries()?;
+    let total = entries.len().max(1);
+    for (index, entry) in entries.into_iter().enumerate() {
+        // Machine consumers get one event per file; in human mode a line per
+        // file would drown the command's summary, so rendering stays quiet.
+        if output::progress_format() == output::ProgressFormat::Json {
+            output::progress_event(&output::ProgressEvent {
+                phase: "render",
+                percent: ((index + 1) * 100 / total) as u8,
+                file: entry.rel_path.to_str(),
+                bytes: Some(entry.contents.len() as u64),
+            });
+        }
+        let (out_rel, rendered) = if matches_any(&raw_copy, &entry.rel_path) {
+            (entry.rel_path.clone(), entry.contents)
+        } else {
+            let (out_rel, is_template) = output_path(&entry.rel_path, &context)?;
+            let rendered = match std::str::from_utf8(&entry.contents) {
+                Ok(text) => render_str(text, &context)
+                    .with_context(|| format!("failed to render {}", entry.rel_path.display()))?
+                    .into_bytes(),
+                Err(_) if is_template => {
+                    anyhow::bail!("{} is not valid UTF-8", entry.rel_path.display())
+                }
+                Err(_) => entry.contents,
+            };
+            (out_rel, rendered)
+        };
+        let out_path = target_dir.join(&out_rel);
+        if let Some(parent) = out_path.parent() {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("failed to create {}", parent.display()))?;
+        }
+        let is_sensitive = matches_any(&sensitive, &out_rel);
+        fs_util::write_file(&out_path, &rendered, is_sensitive)?;
+    }
+    Ok(())
+}
+
+/// Compiles a list of manifest glob patterns.
+pub fn compile_globs(patterns: &[String]) -> anyhow::Result<Vec<glob::Pattern>> {
+    patterns
+        .iter()
+        .map(|pattern| {
+            glob::Pattern::new(pattern).with_context(|| format!("invalid glob `{pattern}`"))
+        })
+        .collect()
+}
+
+pub fn matches_any(patterns: &[glob::Pattern], path: &Path) -> bool {
+    patterns.iter().any(|pattern| pattern.matches_path(path))
+}
+
+pub fn context_from_vars(vars: &BTreeMap<String, VarValue>) -> tera::Context {
+    let mut context = tera::Context::new();
+    for (name, value) in vars {
+        context.insert(name, &value.to_tera());
+    }
+    context
+}
+
+pub fn render_str(template: &str, context: &tera::Context) -> anyhow::Result<String> {
+    Ok(Tera::one_off(template, context, false)?)
+}
+
+/// Computes the output path of a template entry: renders Tera expressions in
+/// path segments and strips a trailing `.tera` extension. Returns whether the
+/// entry was explicitly marked as a template.
+fn output_path(rel_path: &Path, context: &tera::Context) -> anyhow::Result<(PathBuf, bool)> {
+    let raw = rel_path
+        .to_str()
+        .with_context(|| format!("template path {} is not valid UTF-8", rel_path.display()))?;
+    let rendered =
+        render_str(raw, context).with_context(|| format!("failed to render path `{raw}`"))?;
+    let is_template = Path::new(&rendered)
+        .extension()
+        .is_some_and(|ext| ext == TERA_EXT);
+    let out = if is_template {
+        PathBuf::from(&rendered).with_extension("")
+    } else {
+        PathBuf::from(&rendered)
+    };
+    Ok((out, is_template))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::template::vars::VarValue;
+
+    #[test]
+    fn tera_extension_is_stripped() {
+        let context = tera::Context::new();
+        let (out, is_template) = output_path(Path::new("Cargo.toml.tera"), &context).unwrap();
+        assert_eq!(out, Path::new("Cargo.toml"));
+        assert!(is_template);
+    }
+
+    #[test]
+    fn raw_copy_globs_match_nested_paths() {
+        let patterns = compile_globs(&["assets/**".to_string()]).unwrap();
+        assert!(matches_any(&patterns, Path::new("assets/models/ship.glb")));
+        assert!(!matches_any(&patterns, Path::new("src/main.rs.tera")));
+    }
+
+    #[test]
+    fn path_segments_are_rendered() {
+        let mut vars = BTreeMap::new();
+        vars.insert(
+            "project_name".to_string(),
+            VarValue::String("my_game".into()),
+        );
+        let context = context_from_vars(&vars);
+        let (out, _) = output_path(Path::new("src/{{ project_name }}.rs"), &context).unwrap();
+        assert_eq!(out, Path::new("src/my_game.rs"));
+    }
+}
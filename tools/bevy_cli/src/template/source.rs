@@ -0,0 +1,163 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+
+use crate::template::manifest::MANIFEST_FILE;
+
+/// A single file of a template, addressed by its path relative to the
+/// template root.
+pub struct TemplateEntry {
+    pub rel_path: PathBuf,
+    pub contents: Vec<u8>,
+}
+
+/// Where a template's files come from: a directory on disk, or one of the
+/// templates embedded in the binary.
+pub enum TemplateSource {
+    Dir(PathBuf),
+    Embedded(&'static [(&'static str, &'static str)]),
+}
+
+/// The minimal project template shipped inside the `bevy` binary, used when
+/// no `--template` is given.
+pub const EMBEDDED_DEFAULT: &[(&str, &str)] = &[
+    (
+        MANIFEST_FILE,
+        include_str!("../../templates/default/bevy_template.toml"),
+    ),
+    (
+        "Cargo.toml.tera",
+        include_str!("../../templates/default/Cargo.toml.tera"),
+    ),
+    (
+        "src/main.rs.tera",
+        include_str!("../../templates/default/src/main.rs.tera"),
+    ),
+    (
+        ".gitignore",
+        include_str!("../../templates/default/.gitignore"),
+    ),
+];
+
+/// The `--workspace` template: a Cargo workspace with a game binary crate, a
+/// gameplay library crate, and shared workspace-level lints and profiles.
+pub const EMBEDDED_WORKSPACE: &[(&str, &str)] = &[
+    (
+        MANIFEST_FILE,
+        include_str!("../../templates/workspace/bevy_template.toml"),
+    ),
+    (
+        "Cargo.toml.tera",
+        include_str!("../../templates/workspace/Cargo.toml.tera"),
+    ),
+    (
+        ".gitignore",
+        include_str!("../../templates/workspace/.gitignore"),
+    ),
+    (
+        "crates/{{ project_name }}/Cargo.toml.tera",
+        include_str!("../../templates/workspace/crates/{{ project_name }}/Cargo.toml.tera"),
+    ),
+    (
+        "crates/{{ project_name }}/src/main.rs.tera",
+        include_str!("../../templates/workspace/crates/{{ project_name }}/src/main.rs.tera"),
+    ),
+    (
+        "crates/{{ project_name }}_gameplay/Cargo.toml.tera",
+        include_str!(
+            "../../templates/workspace/crates/{{ project_name }}_gameplay/Cargo.toml.tera"
+        ),
+    ),
+    (
+        "crates/{{ project_name }}_gameplay/src/lib.rs.tera",
+        include_str!(
+            "../../templates/workspace/crates/{{ project_name }}_gameplay/src/lib.rs.tera"
+        ),
+    ),
+];
+
+/// The `--kind plugin` template: a publish-ready Bevy plugin library crate
+/// with a plugin struct, a doc-tested usage example, and a runnable example.
+pub const EMBEDDED_PLUGIN: &[(&str, &str)] = &[
+    (
+        MANIFEST_FILE,
+        include_str!("../../templates/plugin/bevy_template.toml"),
+    ),
+    (
+        "Cargo.toml.tera",
+        include_str!("../../templates/plugin/Cargo.toml.tera"),
+    ),
+    (
+        ".gitignore",
+        include_str!("../../templates/plugin/.gitignore"),
+    ),
+    (
+        "src/lib.rs.tera",
+        include_str!("../../templates/plugin/src/lib.rs.tera"),
+    ),
+    (
+        "examples/basic.rs.tera",
+        include_str!("../../templates/plugin/examples/basic.rs.tera"),
+    ),
+];
+
+impl TemplateSource {
+    /// Reads the manifest file of this template, if present.
+    pub fn manifest_contents(&self) -> anyhow::Result<Option<String>> {
+        match self {
+            TemplateSource::Dir(root) => {
+                let path = root.join(MANIFEST_FILE);
+                if path.exists() {
+                    Ok(Some(std::fs::read_to_string(&path).with_context(|| {
+                        format!("failed to read {}", path.display())
+                    })?))
+                } else {
+                    Ok(None)
+                }
+            }
+            TemplateSource::Embedded(files) => Ok(files
+                .iter()
+                .find(|(path, _)| *path == MANIFEST_FILE)
+                .map(|(_, contents)| contents.to_string())),
+        }
+    }
+
+    /// Collects every file of the template except the manifest itself.
+    pub fn entries(&self) -> anyhow::Result<Vec<TemplateEntry>> {
+        let mut entries = match self {
+            TemplateSource::Dir(root) => {
+                let mut entries = Vec::new();
+                collect_dir(root, root, &mut entries)?;
+                entries
+            }
+            TemplateSource::Embedded(files) => files
+                .iter()
+                .map(|(path, contents)| TemplateEntry {
+                    rel_path: PathBuf::from(path),
+                    contents: contents.as_bytes().to_vec(),
+                })
+                .collect(),
+        };
+        entries.retain(|entry| entry.rel_path != Path::new(MANIFEST_FILE));
+        entries.sort_by(|a, b| a.rel_path.cmp(&b.rel_path));
+        Ok(entries)
+    }
+}
+
+fn collect_dir(root: &Path, dir: &Path, entries: &mut Vec<TemplateEntry>) -> anyhow::Result<()> {
+    for entry in
+        std::fs::read_dir(dir).with_context(|| format!("failed to read {}", dir.display()))?
+    {
+        let path = entry?.path();
+        if path.is_dir() {
+            collect_dir(root, &path, entries)?;
+        } else {
+            entries.push(TemplateEntry {
+                rel_path: path.strip_prefix(root).unwrap().to_path_buf(),
+                contents: std::fs::read(&path)
+                    .with_context(|| format!("failed to read {}", path.display()))?,
+            });
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,233 @@
+use std::collections::BTreeMap;
+
+use anyhow::{bail, Context};
+use serde::Deserialize;
+
+/// The declared type and constraints of a single template variable.
+///
+/// Variables are declared in the `[vars]` table of a template manifest, e.g.:
+///
+/// ```toml
+/// [vars.use_physics]
+/// type = "bool"
+/// default = false
+///
+/// [vars.render_backend]
+/// type = "enum"
+/// values = ["vulkan", "metal", "dx12"]
+/// default = "vulkan"
+/// ```
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case", deny_unknown_fields)]
+pub enum VarSpec {
+    Bool {
+        #[serde(default)]
+        default: Option<bool>,
+    },
+    Int {
+        #[serde(default)]
+        default: Option<i64>,
+        #[serde(default)]
+        min: Option<i64>,
+        #[serde(default)]
+        max: Option<i64>,
+    },
+    Enum {
+        values: Vec<String>,
+        #[serde(default)]
+        default: Option<String>,
+    },
+    String {
+        #[serde(default)]
+        default: Option<String>,
+        /// A regex the value must match in full.
+        #[serde(default)]
+        pattern: Option<String>,
+    },
+}
+
+/// A validated variable value, ready to be inserted into the render context.
+#[derive(Debug, Clone, PartialEq)]
+pub enum VarValue {
+    Bool(bool),
+    Int(i64),
+    String(String),
+    /// Lists cannot be declared in manifests; they are built-in context
+    /// values such as `bevy_features`.
+    List(Vec<String>),
+}
+
+impl VarValue {
+    pub fn to_tera(&self) -> tera::Value {
+        match self {
+            VarValue::Bool(value) => tera::Value::Bool(*value),
+            VarValue::Int(value) => tera::Value::Number((*value).into()),
+            VarValue::String(value) => tera::Value::String(value.clone()),
+            VarValue::List(values) => {
+                tera::Value::Array(values.iter().cloned().map(tera::Value::String).collect())
+            }
+        }
+    }
+}
+
+impl VarSpec {
+    /// Parses and validates a raw `--var` value against this spec.
+    pub fn validate(&self, name: &str, raw: &str) -> anyhow::Result<VarValue> {
+        match self {
+            VarSpec::Bool { .. } => match raw {
+                "true" => Ok(VarValue::Bool(true)),
+                "false" => Ok(VarValue::Bool(false)),
+                _ => bail!("variable `{name}` expects `true` or `false`, got `{raw}`"),
+            },
+            VarSpec::Int { min, max, .. } => {
+                let value: i64 = raw.parse().with_context(|| {
+                    format!("variable `{name}` expects an integer, got `{raw}`")
+                })?;
+                if let Some(min) = min {
+                    if value < *min {
+                        bail!("variable `{name}` must be at least {min}, got {value}");
+                    }
+                }
+                if let Some(max) = max {
+                    if value > *max {
+                        bail!("variable `{name}` must be at most {max}, got {value}");
+                    }
+                }
+                Ok(VarValue::Int(value))
+            }
+            VarSpec::Enum { values, .. } => {
+                if values.iter().any(|v| v == raw) {
+                    Ok(VarValue::String(raw.to_string()))
+                } else {
+                    bail!(
+                        "variable `{name}` must be one of [{}], got `{raw}`",
+                        values.join(", ")
+                    );
+                }
+            }
+            VarSpec::String { pattern, .. } => {
+                if let Some(pattern) = pattern {
+                    let regex = regex::Regex::new(&format!("^(?:{pattern})$"))
+                        .with_context(|| format!("invalid pattern for variable `{name}`"))?;
+                    if !regex.is_match(raw) {
+                        bail!("variable `{name}` must match `{pattern}`, got `{raw}`");
+                    }
+                }
+                Ok(VarValue::String(raw.to_string()))
+            }
+        }
+    }
+
+    /// The default value declared in the manifest, if any.
+    pub fn default_value(&self) -> Option<VarValue> {
+        match self {
+            VarSpec::Bool { default } => default.map(VarValue::Bool),
+            VarSpec::Int { default, .. } => default.map(VarValue::Int),
+            VarSpec::Enum { default, .. } | VarSpec::String { default, .. } => {
+                default.clone().map(VarValue::String)
+            }
+        }
+    }
+}
+
+/// Splits a `key=value` pair passed via `--var`.
+pub fn parse_var_flag(flag: &str) -> anyhow::Result<(&str, &str)> {
+    flag.split_once('=')
+        .filter(|(key, _)| !key.is_empty())
+        .with_context(|| format!("expected `--var key=value`, got `{flag}`"))
+}
+
+/// Resolves the final set of variable values from the manifest declarations
+/// and the `--var` overrides given on the command line.
+///
+/// Every override must correspond to a declared variable and pass validation;
+/// declared variables without an override fall back to their default, and it
+/// is an error to leave a variable without a default unset.
+pub fn resolve(
+    specs: &BTreeMap<String, VarSpec>,
+    overrides: &[String],
+) -> anyhow::Result<BTreeMap<String, VarValue>> {
+    let mut values = BTreeMap::new();
+    for flag in overrides {
+        let (key, raw) = parse_var_flag(flag)?;
+        let spec = specs.get(key).with_context(|| {
+            format!(
+                "unknown variable `{key}`; this template declares [{}]",
+                specs.keys().cloned().collect::<Vec<_>>().join(", ")
+            )
+        })?;
+        values.insert(key.to_string(), spec.validate(key, raw)?);
+    }
+    for (name, spec) in specs {
+        if !values.contains_key(name) {
+            let default = spec.default_value().with_context(|| {
+                format!("variable `{name}` has no default; pass `--var {name}=...`")
+            })?;
+            values.insert(name.clone(), default);
+        }
+    }
+    Ok(values)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn specs(toml: &str) -> BTreeMap<String, VarSpec> {
+        toml::from_str(toml).unwrap()
+    }
+
+    #[test]
+    fn bool_accepts_only_true_false() {
+        let spec = VarSpec::Bool { default: None };
+        assert_eq!(spec.validate("x", "true").unwrap(), VarValue::Bool(true));
+        assert!(spec.validate("x", "yes").is_err());
+    }
+
+    #[test]
+    fn int_respects_bounds() {
+        let spec = VarSpec::Int {
+            default: None,
+            min: Some(1),
+            max: Some(8),
+        };
+        assert_eq!(spec.validate("x", "4").unwrap(), VarValue::Int(4));
+        assert!(spec.validate("x", "0").is_err());
+        assert!(spec.validate("x", "9").is_err());
+        assert!(spec.validate("x", "four").is_err());
+    }
+
+    #[test]
+    fn enum_rejects_unknown_values() {
+        let spec = VarSpec::Enum {
+            values: vec!["a".into(), "b".into()],
+            default: None,
+        };
+        assert!(spec.validate("x", "a").is_ok());
+        assert!(spec.validate("x", "c").is_err());
+    }
+
+    #[test]
+    fn string_pattern_must_match_in_full() {
+        let spec = VarSpec::String {
+            default: None,
+            pattern: Some("[a-z]+".into()),
+        };
+        assert!(spec.validate("x", "abc").is_ok());
+        assert!(spec.validate("x", "abc1").is_err());
+    }
+
+    #[test]
+    fn resolve_applies_defaults_and_rejects_unknown_keys() {
+        let specs = specs(
+            "[physics]\ntype = \"bool\"\ndefault = false\n\
+             [name]\ntype = \"string\"\n",
+        );
+        let values = resolve(&specs, &["name=game".to_string()]).unwrap();
+        assert_eq!(values["physics"], VarValue::Bool(false));
+        assert_eq!(values["name"], VarValue::String("game".into()));
+        assert!(resolve(&specs, &["typo=1".to_string()]).is_err());
+        // `name` has no default, so it must be provided.
+        assert!(resolve(&specs, &[]).is_err());
+    }
+}
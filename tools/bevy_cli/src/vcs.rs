@@ -0,0 +1,215 @@
+//! Version-control operations behind one trait.
+//!
+//! Registry fetching, template installs, and project init all talk to git;
+//! routing them through [`Vcs`] keeps network and auth behavior in one
+//! place, lets tests substitute a recording mock instead of spawning git,
+//! and leaves room for mercurial or jj backends later. [`ShellGit`] — the
+//! `git` binary driven through [`crate::subprocess`] — is the only real
+//! implementation today.
+
+use std::path::Path;
+
+use anyhow::Context;
+
+use crate::subprocess::Subprocess;
+
+pub trait Vcs {
+    /// Shallow-clones `url` into `dest`, optionally a specific branch or tag.
+    fn clone_repo(&self, url: &str, branch: Option<&str>, dest: &Path) -> anyhow::Result<()>;
+    /// Brings an existing checkout up to date with its remote.
+    fn update(&self, checkout: &Path) -> anyhow::Result<()>;
+    /// Switches a checkout to the given revision (branch, tag, or hash).
+    fn checkout(&self, checkout: &Path, rev: &str) -> anyhow::Result<()>;
+    /// Initializes a fresh repository in `dir`.
+    fn init(&self, dir: &Path) -> anyhow::Result<()>;
+    /// Registers a remote on an existing repository.
+    fn add_remote(&self, dir: &Path, name: &str, url: &str) -> anyhow::Result<()>;
+    /// Stages everything and commits it with the given message.
+    fn commit_all(&self, dir: &Path, message: &str) -> anyhow::Result<()>;
+    /// The URL a named remote of `dir` points at.
+    fn remote_url(&self, dir: &Path, name: &str) -> anyhow::Result<String>;
+    /// Force-pushes a refspec from `dir` to `url`.
+    fn push(&self, dir: &Path, url: &str, refspec: &str) -> anyhow::Result<()>;
+    /// The tracked files of a checkout, as `/`-separated relative paths.
+    fn files(&self, checkout: &Path) -> anyhow::Result<Vec<String>>;
+}
+
+/// The `git` binary on PATH. Clones are depth-1 — every caller wants a
+/// snapshot, not history — and updates are fast-forward only so a rewritten
+/// registry fails loudly instead of silently merging.
+#[derive(Default)]
+pub struct ShellGit {
+    /// Extra HTTP header — typically `Authorization: …` — sent with every
+    /// git invocation. Supplied through the `GIT_CONFIG_*` environment
+    /// rather than the remote URL, so the credential never appears in argv
+    /// and git never persists it into the checkout's `.git/config`.
+    auth_header: Option<String>,
+}
+
+impl ShellGit {
+    /// A git that authenticates every network operation with `header`.
+    pub fn with_auth_header(header: impl Into<String>) -> Self {
+        Self {
+            auth_header: Some(header.into()),
+        }
+    }
+
+    fn git(&self, checkout: Option<&Path>) -> Subprocess {
+        let mut git = Subprocess::new("git");
+        if let Some(header) = &self.auth_header {
+            git = git
+                .env("GIT_CONFIG_COUNT", "1")
+                .env("GIT_CONFIG_KEY_0", "http.extraHeader")
+                .env("GIT_CONFIG_VALUE_0", header.clone());
+        }
+        match checkout {
+            Some(dir) => git.arg("-C").arg(dir.to_string_lossy()),
+            None => git,
+        }
+    }
+}
+
+impl Vcs for ShellGit {
+    fn clone_repo(&self, url: &str, branch: Option<&str>, dest: &Path) -> anyhow::Result<()> {
+        let mut git = self.git(None).args(["clone", "--depth", "1"]);
+        if let Some(branch) = branch {
+            git = git.arg("--branch").arg(branch);
+        }
+        git.arg(url).arg(dest.to_string_lossy()).run()
+    }
+
+    fn update(&self, checkout: &Path) -> anyhow::Result<()> {
+        self.git(Some(checkout)).args(["pull", "--ff-only"]).run()
+    }
+
+    fn checkout(&self, checkout: &Path, rev: &str) -> anyhow::Result<()> {
+        self.git(Some(checkout)).arg("checkout").arg(rev).run()
+    }
+
+    fn init(&self, dir: &Path) -> anyhow::Result<()> {
+        self.git(None)
+            .arg("init")
+            .arg("--quiet")
+            .arg(dir.to_string_lossy())
+            .run()
+    }
+
+    fn add_remote(&self, dir: &Path, name: &str, url: &str) -> anyhow::Result<()> {
+        self.git(Some(dir)).args(["remote", "add", name, url]).run()
+    }
+
+    fn commit_all(&self, dir: &Path, message: &str) -> anyhow::Result<()> {
+        self.git(Some(dir)).args(["add", "-A"]).run()?;
+        self.git(Some(dir))
+            .args(["commit", "-q", "-m", message])
+            .run()
+    }
+
+    fn remote_url(&self, dir: &Path, name: &str) -> anyhow::Result<String> {
+        let stdout = self
+            .git(Some(dir))
+            .args(["remote", "get-url", name])
+            .capture()?;
+        Ok(stdout.trim().to_string())
+    }
+
+    fn push(&self, dir: &Path, url: &str, refspec: &str) -> anyhow::Result<()> {
+        self.git(Some(dir))
+            .args(["push", "--force", url, refspec])
+            .run()
+    }
+
+    fn files(&self, checkout: &Path) -> anyhow::Result<Vec<String>> {
+        let stdout = self.git(Some(checkout)).arg("ls-files").capture()?;
+        Ok(stdout.lines().map(str::to_string).collect())
+    }
+}
+
+/// Clones `url` into `dest` on first use and updates the checkout on later
+/// calls — the common registry/template pattern, shared so every caller
+/// behaves the same when a cache entry already exists.
+pub fn ensure_checkout(
+    vcs: &dyn Vcs,
+    url: &str,
+    branch: Option<&str>,
+    dest: &Path,
+) -> anyhow::Result<()> {
+    if dest.join(".git").exists() {
+        vcs.update(dest)
+    } else {
+        if let Some(parent) = dest.parent() {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("failed to create {}", parent.display()))?;
+        }
+        vcs.clone_repo(url, branch, dest)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+
+    /// Records the operations performed on it instead of touching git.
+    struct RecordingVcs {
+        calls: RefCell<Vec<String>>,
+    }
+
+    impl Vcs for RecordingVcs {
+        fn clone_repo(&self, url: &str, branch: Option<&str>, _: &Path) -> anyhow::Result<()> {
+            self.calls
+                .borrow_mut()
+                .push(format!("clone {url} {}", branch.unwrap_or("-")));
+            Ok(())
+        }
+        fn update(&self, _: &Path) -> anyhow::Result<()> {
+            self.calls.borrow_mut().push("update".to_string());
+            Ok(())
+        }
+        fn checkout(&self, _: &Path, rev: &str) -> anyhow::Result<()> {
+            self.calls.borrow_mut().push(format!("checkout {rev}"));
+            Ok(())
+        }
+        fn init(&self, _: &Path) -> anyhow::Result<()> {
+            self.calls.borrow_mut().push("init".to_string());
+            Ok(())
+        }
+        fn add_remote(&self, _: &Path, name: &str, url: &str) -> anyhow::Result<()> {
+            self.calls.borrow_mut().push(format!("remote {name} {url}"));
+            Ok(())
+        }
+        fn commit_all(&self, _: &Path, message: &str) -> anyhow::Result<()> {
+            self.calls.borrow_mut().push(format!("commit {message}"));
+            Ok(())
+        }
+        fn remote_url(&self, _: &Path, name: &str) -> anyhow::Result<String> {
+            Ok(format!("https://example.com/{name}.git"))
+        }
+        fn push(&self, _: &Path, url: &str, refspec: &str) -> anyhow::Result<()> {
+            self.calls
+                .borrow_mut()
+                .push(format!("push {url} {refspec}"));
+            Ok(())
+        }
+        fn files(&self, _: &Path) -> anyhow::Result<Vec<String>> {
+            Ok(Vec::new())
+        }
+    }
+
+    #[test]
+    fn missing_checkouts_are_cloned_and_existing_ones_updated() {
+        let vcs = RecordingVcs {
+            calls: RefCell::new(Vec::new()),
+        };
+        let dir = std::env::temp_dir().join("bevy_cli_vcs_test");
+        std::fs::create_dir_all(dir.join(".git")).unwrap();
+        ensure_checkout(&vcs, "https://example.com/a.git", None, &dir).unwrap();
+        let fresh = dir.join("fresh");
+        ensure_checkout(&vcs, "https://example.com/a.git", Some("v0.12"), &fresh).unwrap();
+        assert_eq!(
+            *vcs.calls.borrow(),
+            vec!["update", "clone https://example.com/a.git v0.12"]
+        );
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
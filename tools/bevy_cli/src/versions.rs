@@ -0,0 +1,80 @@
+//! Resolution of the Bevy version written into generated projects.
+
+use anyhow::Context;
+
+use crate::i18n::localize;
+use crate::output;
+
+/// Version used when `latest` cannot be resolved (e.g. offline).
+pub const FALLBACK_BEVY_VERSION: &str = "0.12";
+
+/// Resolves a `--bevy-version` argument: explicit versions are validated and
+/// used as-is, `latest` is looked up on crates.io with an offline fallback to
+/// [`FALLBACK_BEVY_VERSION`].
+pub fn resolve(requested: &str) -> anyhow::Result<String> {
+    if requested != "latest" {
+        anyhow::ensure!(
+            is_version_req(requested),
+            "`{requested}` is not a valid Bevy version requirement"
+        );
+        return Ok(requested.to_string());
+    }
+    match latest_of("bevy") {
+        Ok(version) => Ok(version),
+        Err(error) => {
+            output::warn(&localize!(
+                "bevy-version-fallback",
+                version = FALLBACK_BEVY_VERSION,
+                error = format!("{error:#}")
+            ));
+            Ok(FALLBACK_BEVY_VERSION.to_string())
+        }
+    }
+}
+
+/// Accepts plain versions (`0.12`, `0.12.1`) and simple requirement
+/// prefixes (`^0.12`, `=0.12.1`, `~0.12`).
+fn is_version_req(version: &str) -> bool {
+    let digits = version.trim_start_matches(['^', '=', '~']);
+    !digits.is_empty()
+        && digits.split('.').all(|part| {
+            !part.is_empty()
+                && part
+                    .chars()
+                    .all(|c| c.is_ascii_digit() || c == '-' || c.is_ascii_alphanumeric())
+        })
+}
+
+/// Asks crates.io for the newest stable release of a crate.
+pub fn latest_of(name: &str) -> anyhow::Result<String> {
+    let response: serde_json::Value = ureq::get(&format!("https://crates.io/api/v1/crates/{name}"))
+        .timeout(std::time::Duration::from_secs(10))
+        .call()
+        .context("crates.io is unreachable")?
+        .into_json()
+        .context("crates.io returned invalid JSON")?;
+    response["crate"]["max_stable_version"]
+        .as_str()
+        .map(str::to_string)
+        .context("crates.io response has no max_stable_version")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn explicit_versions_are_validated() {
+        assert!(is_version_req("0.12"));
+        assert!(is_version_req("0.12.1"));
+        assert!(is_version_req("^0.11"));
+        assert!(!is_version_req(""));
+        assert!(!is_version_req("not a version"));
+    }
+
+    #[test]
+    fn explicit_versions_pass_through() {
+        assert_eq!(resolve("0.11.3").unwrap(), "0.11.3");
+        assert!(resolve("bogus version").is_err());
+    }
+}
@@ -0,0 +1,272 @@
+//! Line-based prompts for `bevy new --interactive`.
+//!
+//! The wizard walks through the choices that otherwise need a wall of
+//! flags and fills them into [`NewArgs`]; generation itself runs through
+//! the exact same path as a non-interactive invocation.
+
+use std::io::Write;
+use std::path::PathBuf;
+
+use anyhow::Context;
+
+use crate::commands::new::{self, NewArgs, ProjectKind, TargetPlatform, Vcs};
+
+/// Licenses offered for the built-in templates.
+const LICENSES: &[&str] = &["MIT OR Apache-2.0", "MIT", "Apache-2.0", "CC0-1.0"];
+
+/// Prompts for everything `bevy new` needs and fills the answers into
+/// `args`; flags given on the command line are kept as the defaults.
+pub fn fill(args: &mut NewArgs) -> anyhow::Result<()> {
+    args.name = Some(prompt_name(args.name.as_deref())?);
+
+    let template = line(
+        "Template (path or installed name, empty for the default)",
+        "",
+    )?;
+    if !template.is_empty() {
+        args.template = Some(PathBuf::from(template));
+    }
+
+    if args.template.is_none() {
+        let kind = menu("Project kind", &["game", "plugin"], 0)?;
+        args.kind = if kind == 1 {
+            ProjectKind::Plugin
+        } else {
+            ProjectKind::Game
+        };
+        if args.kind == ProjectKind::Game {
+            args.workspace = confirm("Generate a Cargo workspace (game + gameplay crate)?")?;
+        }
+        let license = menu("License", LICENSES, 0)?;
+        args.vars.push(format!("license={}", LICENSES[license]));
+    }
+
+    let features = line(
+        "Extra Bevy features, comma-separated (e.g. wayland,mp3; empty for none)",
+        "",
+    )?;
+    args.bevy_features = features
+        .split(',')
+        .map(str::trim)
+        .filter(|feature| !feature.is_empty())
+        .map(str::to_string)
+        .collect();
+
+    let target = menu(
+        "Additional target platform",
+        &["none", "web", "android", "ios"],
+        0,
+    )?;
+    args.target = match target {
+        1 => Some(TargetPlatform::Web),
+        2 => Some(TargetPlatform::Android),
+        3 => Some(TargetPlatform::Ios),
+        _ => None,
+    };
+
+    if confirm("Write a basic GitHub Actions CI workflow?")? {
+        args.ci = true;
+    }
+    if confirm("Initialize a git repository?")? {
+        args.vcs = Vcs::Git;
+    }
+    Ok(())
+}
+
+/// Asks for a project name until a valid crate name is given, offering a
+/// sanitized version of an invalid answer as the next default.
+fn prompt_name(initial: Option<&str>) -> anyhow::Result<String> {
+    let mut default = initial.unwrap_or_default().to_string();
+    loop {
+        let answer = line("Project name", &default)?;
+        if new::validate_project_name(&answer).is_ok() {
+            return Ok(answer);
+        }
+        let suggestion = new::sanitize_project_name(&answer);
+        println!("`{answer}` is not a valid crate name; suggesting `{suggestion}`");
+        default = suggestion;
+    }
+}
+
+/// Asks a free-form question; an empty answer takes the default.
+fn line(question: &str, default: &str) -> anyhow::Result<String> {
+    if default.is_empty() {
+        print!("{question}: ");
+    } else {
+        print!("{question} [{default}]: ");
+    }
+    std::io::stdout().flush()?;
+    let mut answer = String::new();
+    std::io::stdin()
+        .read_line(&mut answer)
+        .context("failed to read from stdin")?;
+    let answer = answer.trim();
+    Ok(if answer.is_empty() {
+        default.to_string()
+    } else {
+        answer.to_string()
+    })
+}
+
+/// Shows a numbered menu and returns the chosen index.
+fn menu(question: &str, options: &[&str], default: usize) -> anyhow::Result<usize> {
+    println!("{question}:");
+    for (index, option) in options.iter().enumerate() {
+        println!("  {}) {option}", index + 1);
+    }
+    loop {
+        let answer = line("Choice", &(default + 1).to_string())?;
+        match answer.parse::<usize>() {
+            Ok(choice) if (1..=options.len()).contains(&choice) => return Ok(choice - 1),
+            _ => println!("Enter a number between 1 and {}", options.len()),
+        }
+    }
+}
+
+/// Asks a yes/no question, defaulting to no.
+fn confirm(question: &str) -> anyhow::Result<bool> {
+    let answer = line(&format!("{question} (y/N)"), "n")?;
+    Ok(answer.eq_ignore_ascii_case("y") || answer.eq_ignore_ascii_case("yes"))
+}
+
+/// Name of the answers file recorded next to a wizard-generated project,
+/// replayable with `bevy new --replay`.
+pub const REPLAY_FILE: &str = "bevy-replay.toml";
+
+/// The wizard answers worth replaying. A subset of [`NewArgs`]: everything
+/// the prompts can set, minus anything personal or secret-shaped.
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Replay {
+    pub name: Option<String>,
+    pub template: Option<PathBuf>,
+    pub kind: Option<String>,
+    pub workspace: bool,
+    pub bevy_features: Vec<String>,
+    pub target: Option<String>,
+    pub ci: bool,
+    pub vcs: Option<String>,
+    pub vars: Vec<String>,
+}
+
+/// Key substrings that keep a `--var` out of the replay file; replays are
+/// meant to be committed and shared.
+const SECRET_KEY_HINTS: &[&str] = &["token", "secret", "password", "key"];
+
+fn is_secret_var(flag: &str) -> bool {
+    let key = flag.split('=').next().unwrap_or(flag).to_lowercase();
+    SECRET_KEY_HINTS.iter().any(|hint| key.contains(hint))
+}
+
+fn enum_name(value: &impl clap::ValueEnum) -> String {
+    value
+        .to_possible_value()
+        .expect("no skipped variants")
+        .get_name()
+        .to_string()
+}
+
+/// Records the answers of a finished wizard run.
+pub fn write_replay(args: &NewArgs, path: &std::path::Path) -> anyhow::Result<()> {
+    let replay = Replay {
+        name: args.name.clone(),
+        template: args.template.clone(),
+        kind: Some(enum_name(&args.kind)),
+        workspace: args.workspace,
+        bevy_features: args.bevy_features.clone(),
+        target: args.target.map(|target| enum_name(&target)),
+        ci: args.ci,
+        vcs: Some(enum_name(&args.vcs)),
+        vars: args
+            .vars
+            .iter()
+            .filter(|flag| !is_secret_var(flag))
+            .cloned()
+            .collect(),
+    };
+    crate::fs_util::write_file(path, toml::to_string_pretty(&replay)?.as_bytes(), false)
+}
+
+/// Applies a replay file to `args`, touching only what the command line
+/// left at its default so explicit flags (and a positional name) win.
+pub fn apply_replay(args: &mut NewArgs, path: &std::path::Path) -> anyhow::Result<()> {
+    let contents = std::fs::read_to_string(path)
+        .with_context(|| format!("failed to read {}", path.display()))?;
+    let replay: Replay =
+        toml::from_str(&contents).with_context(|| format!("failed to parse {}", path.display()))?;
+    fn parse<T: clap::ValueEnum>(path: &std::path::Path, name: &str) -> anyhow::Result<T> {
+        T::from_str(name, true)
+            .map_err(|_| anyhow::anyhow!("{}: unknown value `{name}`", path.display()))
+    }
+    if args.name.is_none() {
+        args.name = replay.name;
+    }
+    if args.template.is_none() {
+        args.template = replay.template;
+    }
+    if args.kind == ProjectKind::Game {
+        if let Some(kind) = &replay.kind {
+            args.kind = parse(path, kind)?;
+        }
+    }
+    args.workspace |= replay.workspace;
+    if args.bevy_features.is_empty() {
+        args.bevy_features = replay.bevy_features;
+    }
+    if args.target.is_none() {
+        args.target = match replay.target.as_deref() {
+            Some(target) => Some(parse(path, target)?),
+            None => None,
+        };
+    }
+    args.ci |= replay.ci;
+    if args.vcs == Vcs::None {
+        if let Some(vcs) = &replay.vcs {
+            args.vcs = parse(path, vcs)?;
+        }
+    }
+    // Recorded vars fill in behind explicit `--var` flags, key by key.
+    for flag in replay.vars {
+        let key = flag.split('=').next().unwrap_or_default();
+        if !args
+            .vars
+            .iter()
+            .any(|existing| existing.split('=').next() == Some(key))
+        {
+            args.vars.push(flag);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn secretish_vars_stay_out_of_replays() {
+        assert!(is_secret_var("api_token=abc"));
+        assert!(is_secret_var("STEAM_KEY=xyz"));
+        assert!(!is_secret_var("license=MIT"));
+    }
+
+    #[test]
+    fn explicit_flags_win_over_replayed_answers() {
+        let mut args = crate::ProjectBuilder::new("from_cli").into_args();
+        args.vars.push("license=CC0-1.0".to_string());
+        let dir = std::env::temp_dir().join("bevy_cli_replay_test");
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join(REPLAY_FILE);
+        std::fs::write(
+            &path,
+            "name = \"recorded\"\nci = true\nvcs = \"git\"\nvars = [\"license=MIT\"]\n",
+        )
+        .unwrap();
+        apply_replay(&mut args, &path).unwrap();
+        assert_eq!(args.name.as_deref(), Some("from_cli"));
+        assert!(args.ci);
+        assert_eq!(args.vcs, Vcs::Git);
+        assert_eq!(args.vars, vec!["license=CC0-1.0"]);
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,79 @@
+//! Runtime asset integrity checking against the `assets/manifest.ron`
+//! written by `bevy bundle`.
+//!
+//! Dependency-free: the manifest format and the FNV-1a hash match what the
+//! CLI writes, so a corrupted download or a modified file is caught before
+//! the game tries to load it. Call [`verify_assets`] at startup (ideally on
+//! a background thread) and decide how loudly to complain.
+
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+/// One problem found while checking the shipped assets.
+#[derive(Debug)]
+pub enum AssetProblem {
+    /// The file's size or content hash no longer matches the manifest.
+    Modified(PathBuf),
+    /// A file listed in the manifest is gone.
+    Missing(PathBuf),
+}
+
+/// Checks every file listed in `assets/manifest.ron` and returns the
+/// problems found; an empty vector means the bundle is intact. Errors only
+/// when the manifest itself cannot be read or parsed.
+pub fn verify_assets(assets_dir: &Path) -> std::io::Result<Vec<AssetProblem>> {
+    let manifest = std::fs::read_to_string(assets_dir.join("manifest.ron"))?;
+    let mut problems = Vec::new();
+    for (listed, size, hash) in parse_manifest(&manifest)? {
+        let path = assets_dir.join(&listed);
+        let mut contents = Vec::new();
+        match std::fs::File::open(&path) {
+            Ok(mut file) => file.read_to_end(&mut contents)?,
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => {
+                problems.push(AssetProblem::Missing(PathBuf::from(listed)));
+                continue;
+            }
+            Err(error) => return Err(error),
+        };
+        if contents.len() as u64 != size || fnv1a64(&contents) != hash {
+            problems.push(AssetProblem::Modified(PathBuf::from(listed)));
+        }
+    }
+    Ok(problems)
+}
+
+/// Parses the manifest rows:
+/// `(path: "...", kind: word, size: N, hash: "16 hex digits"),`.
+fn parse_manifest(contents: &str) -> std::io::Result<Vec<(String, u64, u64)>> {
+    let corrupt =
+        || std::io::Error::new(std::io::ErrorKind::InvalidData, "corrupt asset manifest");
+    let mut entries = Vec::new();
+    for line in contents.lines() {
+        let line = line.trim();
+        if !line.starts_with("(path: ") {
+            continue;
+        }
+        let rest = line.strip_prefix("(path: \"").ok_or_else(corrupt)?;
+        let (path, rest) = rest.split_once("\", kind: ").ok_or_else(corrupt)?;
+        let (_kind, rest) = rest.split_once(", size: ").ok_or_else(corrupt)?;
+        let (size, rest) = rest.split_once(", hash: \"").ok_or_else(corrupt)?;
+        let hash = rest.strip_suffix("\"),").ok_or_else(corrupt)?;
+        entries.push((
+            path.to_string(),
+            size.parse().map_err(|_| corrupt())?,
+            u64::from_str_radix(hash, 16).map_err(|_| corrupt())?,
+        ));
+    }
+    Ok(entries)
+}
+
+/// FNV-1a, 64-bit — the same stable hash the CLI used when it wrote the
+/// manifest.
+fn fnv1a64(bytes: &[u8]) -> u64 {
+    let mut hash = 0xcbf2_9ce4_8422_2325u64;
+    for &byte in bytes {
+        hash ^= u64::from(byte);
+        hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+    }
+    hash
+}
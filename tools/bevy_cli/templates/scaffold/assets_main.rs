@@ -0,0 +1,28 @@
+use bevy::prelude::*;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_systems(Startup, setup)
+        .run();
+}
+
+/// Loads the placeholder assets, demonstrating the asset pipeline: swap the
+/// files under `assets/` for real ones and the paths keep working.
+fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.spawn(Camera2dBundle::default());
+    commands.spawn(SpriteBundle {
+        texture: asset_server.load("sprites/placeholder.png"),
+        ..default()
+    });
+    commands.spawn(AudioBundle {
+        source: asset_server.load("sounds/blip.wav"),
+        ..default()
+    });
+    commands.spawn(TextBundle::from_section(
+        "Placeholder assets loaded",
+        // Uses the default font; drop a .ttf under assets/fonts and load it
+        // here instead.
+        TextStyle::default(),
+    ));
+}
@@ -0,0 +1,16 @@
+//! Criterion harness benchmarking a headless app tick; run with
+//! `cargo bench`.
+
+use bevy::app::App;
+use criterion::{criterion_group, criterion_main, Criterion};
+
+fn app_update(criterion: &mut Criterion) {
+    criterion.bench_function("app_update", |bencher| {
+        let mut app = App::new();
+        app.update();
+        bencher.iter(|| app.update());
+    });
+}
+
+criterion_group!(benches, app_update);
+criterion_main!(benches);
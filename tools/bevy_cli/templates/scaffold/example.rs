@@ -0,0 +1,16 @@
+//! A minimal runnable example; run with `cargo run --example hello`.
+
+use bevy::prelude::*;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_systems(Update, greet)
+        .run();
+}
+
+fn greet(time: Res<Time>) {
+    if time.elapsed_seconds() < f32::EPSILON {
+        info!("hello from the example!");
+    }
+}
@@ -0,0 +1,11 @@
+use bevy::prelude::*;
+
+mod player;
+mod ui;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins((player::PlayerPlugin, ui::UiPlugin))
+        .run();
+}
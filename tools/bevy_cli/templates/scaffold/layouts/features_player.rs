@@ -0,0 +1,34 @@
+//! Everything about the player lives in this module: components, systems,
+//! and the plugin wiring them up.
+
+use bevy::prelude::*;
+
+pub struct PlayerPlugin;
+
+impl Plugin for PlayerPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Startup, spawn).add_systems(Update, movement);
+    }
+}
+
+#[derive(Component)]
+pub struct Player {
+    pub speed: f32,
+}
+
+fn spawn(mut commands: Commands) {
+    commands.spawn((Player { speed: 200.0 }, SpatialBundle::default()));
+}
+
+fn movement(time: Res<Time>, input: Res<Input<KeyCode>>, mut players: Query<(&Player, &mut Transform)>) {
+    let mut direction = Vec2::ZERO;
+    if input.pressed(KeyCode::Left) {
+        direction.x -= 1.0;
+    }
+    if input.pressed(KeyCode::Right) {
+        direction.x += 1.0;
+    }
+    for (player, mut transform) in &mut players {
+        transform.translation += (direction * player.speed * time.delta_seconds()).extend(0.0);
+    }
+}
@@ -0,0 +1,10 @@
+use bevy::prelude::*;
+
+mod plugins;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins((plugins::camera::CameraPlugin, plugins::player::PlayerPlugin))
+        .run();
+}
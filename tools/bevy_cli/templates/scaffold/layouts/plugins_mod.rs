@@ -0,0 +1,4 @@
+//! One plugin per file; register new plugins in `main.rs`.
+
+pub mod camera;
+pub mod player;
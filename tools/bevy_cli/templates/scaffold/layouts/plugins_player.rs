@@ -0,0 +1,16 @@
+use bevy::prelude::*;
+
+pub struct PlayerPlugin;
+
+impl Plugin for PlayerPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Startup, spawn_player);
+    }
+}
+
+#[derive(Component)]
+pub struct Player;
+
+fn spawn_player(mut commands: Commands) {
+    commands.spawn((Player, SpatialBundle::default()));
+}
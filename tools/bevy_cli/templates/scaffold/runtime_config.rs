@@ -0,0 +1,33 @@
+//! Typed runtime configuration, loaded from environment variables and an
+//! optional `.env` file. Generated by `bevy env file`; edit freely.
+//!
+//! Requires the `figment` crate with the `env` feature and `serde`:
+//!
+//! ```toml
+//! dotenvy = "0.15"
+//! figment = { version = "0.10", features = ["env"] }
+//! serde = { version = "1", features = ["derive"] }
+//! ```
+
+use figment::providers::Env;
+use figment::Figment;
+use serde::Deserialize;
+
+/// Values from `.env`; see `.env.example` for the expected keys.
+#[derive(Debug, Clone, Deserialize)]
+pub struct RuntimeConfig {
+    pub log_level: String,
+    pub window_width: u32,
+    pub window_height: u32,
+    pub fullscreen: bool,
+    pub asset_root: String,
+}
+
+impl RuntimeConfig {
+    /// Loads configuration from the process environment, after loading
+    /// `.env` into it if the file exists.
+    pub fn load() -> Result<Self, figment::Error> {
+        let _ = dotenvy::dotenv();
+        Figment::new().merge(Env::raw()).extract()
+    }
+}
@@ -0,0 +1,97 @@
+//! Sprite-sheet loading for descriptors produced by `bevy assets atlas`.
+//!
+//! Add `ron` and `serde` to your dependencies, register
+//! [`SpriteSheetPlugin`], and insert an [`Animation`] next to a
+//! `TextureAtlasSprite` to play a tagged range.
+
+use bevy::prelude::*;
+use serde::Deserialize;
+
+/// Mirror of the `.anim.ron` descriptor format.
+#[derive(Debug, Deserialize, Resource)]
+pub struct AnimationDescriptor {
+    /// A pre-packed sheet image, when the descriptor came from an Aseprite
+    /// export; `None` when the frames are individual files.
+    pub image: Option<String>,
+    #[serde(default)]
+    pub frame_size: (u32, u32),
+    #[serde(default)]
+    pub frame_duration_ms: u64,
+    #[serde(default)]
+    pub frames: Vec<String>,
+    #[serde(default)]
+    pub rects: Vec<FrameRect>,
+    pub tags: Vec<Tag>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct FrameRect {
+    pub x: u32,
+    pub y: u32,
+    pub w: u32,
+    pub h: u32,
+    pub duration_ms: u64,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Tag {
+    pub name: String,
+    pub from: usize,
+    pub to: usize,
+}
+
+/// Plays the tag's frame range on the entity's `TextureAtlasSprite`.
+#[derive(Component)]
+pub struct Animation {
+    pub tag: String,
+    pub timer: Timer,
+}
+
+impl Animation {
+    pub fn new(tag: impl Into<String>, descriptor: &AnimationDescriptor) -> Self {
+        Self {
+            tag: tag.into(),
+            timer: Timer::new(
+                std::time::Duration::from_millis(descriptor.frame_duration_ms.max(1)),
+                TimerMode::Repeating,
+            ),
+        }
+    }
+}
+
+pub struct SpriteSheetPlugin {
+    /// Path to the `.anim.ron` descriptor, relative to `assets/`.
+    pub descriptor: &'static str,
+}
+
+impl Plugin for SpriteSheetPlugin {
+    fn build(&self, app: &mut App) {
+        let path = std::path::Path::new("assets").join(self.descriptor);
+        let descriptor: AnimationDescriptor = ron::from_str(
+            &std::fs::read_to_string(&path)
+                .unwrap_or_else(|error| panic!("cannot read {}: {error}", path.display())),
+        )
+        .unwrap_or_else(|error| panic!("cannot parse {}: {error}", path.display()));
+        app.insert_resource(descriptor).add_systems(Update, animate);
+    }
+}
+
+fn animate(
+    time: Res<Time>,
+    descriptor: Res<AnimationDescriptor>,
+    mut sprites: Query<(&mut Animation, &mut TextureAtlasSprite)>,
+) {
+    for (mut animation, mut sprite) in &mut sprites {
+        let Some(tag) = descriptor.tags.iter().find(|tag| tag.name == animation.tag) else {
+            continue;
+        };
+        animation.timer.tick(time.delta());
+        if animation.timer.just_finished() {
+            sprite.index = if sprite.index < tag.from || sprite.index >= tag.to {
+                tag.from
+            } else {
+                sprite.index + 1
+            };
+        }
+    }
+}
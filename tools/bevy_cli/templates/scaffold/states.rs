@@ -0,0 +1,89 @@
+//! The canonical Bevy app structure: a [`GameState`] driving one plugin per
+//! state, with transition systems moving the app forward.
+
+use bevy::prelude::*;
+
+/// The top-level states the app moves through.
+#[derive(States, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum GameState {
+    /// Assets are loading; swap in your loading screen here.
+    #[default]
+    Loading,
+    /// The main menu.
+    Menu,
+    /// Gameplay.
+    InGame,
+}
+
+/// Runs while [`GameState::Loading`] is active and moves on to the menu.
+pub struct LoadingPlugin;
+
+impl Plugin for LoadingPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            finish_loading.run_if(in_state(GameState::Loading)),
+        );
+    }
+}
+
+/// Replace this with real asset tracking; it transitions immediately.
+fn finish_loading(mut next_state: ResMut<NextState<GameState>>) {
+    next_state.set(GameState::Menu);
+}
+
+/// Shows the menu and starts the game on space.
+pub struct MenuPlugin;
+
+impl Plugin for MenuPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(GameState::Menu), setup_menu)
+            .add_systems(Update, start_game.run_if(in_state(GameState::Menu)))
+            .add_systems(OnExit(GameState::Menu), cleanup::<MenuScreen>);
+    }
+}
+
+/// Marker for entities that only live while the menu is open.
+#[derive(Component)]
+struct MenuScreen;
+
+fn setup_menu(mut commands: Commands) {
+    commands.spawn((
+        MenuScreen,
+        TextBundle::from_section("Press space to play", TextStyle::default()),
+    ));
+}
+
+fn start_game(
+    keyboard: Res<Input<KeyCode>>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    if keyboard.just_pressed(KeyCode::Space) {
+        next_state.set(GameState::InGame);
+    }
+}
+
+/// The gameplay itself.
+pub struct InGamePlugin;
+
+impl Plugin for InGamePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(GameState::InGame), setup_game)
+            .add_systems(OnExit(GameState::InGame), cleanup::<InGame>);
+    }
+}
+
+/// Marker for entities that only live during gameplay.
+#[derive(Component)]
+struct InGame;
+
+fn setup_game(mut commands: Commands) {
+    commands.spawn((InGame, Camera2dBundle::default()));
+}
+
+/// Despawns everything scoped to the state being left.
+fn cleanup<T: Component>(mut commands: Commands, entities: Query<Entity, With<T>>) {
+    for entity in &entities {
+        commands.entity(entity).despawn_recursive();
+    }
+}
@@ -0,0 +1,13 @@
+use bevy::prelude::*;
+
+mod states;
+
+use states::{GameState, InGamePlugin, LoadingPlugin, MenuPlugin};
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_state::<GameState>()
+        .add_plugins((LoadingPlugin, MenuPlugin, InGamePlugin))
+        .run();
+}
@@ -0,0 +1,70 @@
+//! Project automation tasks, run as `cargo xtask <task>`.
+//!
+//! Tasks shell out to cargo so they work with nothing but a toolchain
+//! installed; extend them with project-specific steps as the project grows.
+
+use std::process::{Command, ExitCode};
+
+fn main() -> ExitCode {
+    let task = std::env::args().nth(1).unwrap_or_default();
+    let ok = match task.as_str() {
+        "dist" => dist(),
+        "assets" => assets(),
+        "ci" => ci(),
+        _ => {
+            eprintln!("usage: cargo xtask <dist|assets|ci>");
+            return ExitCode::FAILURE;
+        }
+    };
+    if ok {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+/// Builds a release binary ready for packaging.
+fn dist() -> bool {
+    run("cargo", &["build", "--release"])
+}
+
+/// Reports on the asset tree; hook asset processing in here.
+fn assets() -> bool {
+    let count = walk("assets");
+    println!("{count} asset files");
+    true
+}
+
+/// Runs the same checks CI does.
+fn ci() -> bool {
+    run("cargo", &["fmt", "--all", "--check"])
+        && run("cargo", &["clippy", "--all-targets", "--", "-D", "warnings"])
+        && run("cargo", &["test"])
+}
+
+fn run(program: &str, args: &[&str]) -> bool {
+    Command::new(program)
+        .args(args)
+        .status()
+        .map(|status| status.success())
+        .unwrap_or(false)
+}
+
+fn walk(dir: &str) -> usize {
+    fn visit(dir: &std::path::Path, count: &mut usize) {
+        let Ok(entries) = std::fs::read_dir(dir) else {
+            return;
+        };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.is_dir() {
+                visit(&path, count);
+            } else {
+                *count += 1;
+            }
+        }
+    }
+    let mut count = 0;
+    visit(std::path::Path::new(dir), &mut count);
+    count
+}
@@ -0,0 +1,325 @@
+//! End-to-end suite driving the built `bevy` binary through a matrix of
+//! locales, template sources, CI, and VCS choices.
+//!
+//! Every run is hermetic: `HOME`, `XDG_CONFIG_HOME`, and `XDG_DATA_HOME`
+//! point into a fresh temp directory so per-user config and caches never
+//! leak between cases or from the developer's machine, and template
+//! sources are embedded or on-disk fixtures so nothing touches the
+//! network.
+
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+#[derive(Clone, Copy)]
+enum Source {
+    /// The template embedded in the binary.
+    Builtin,
+    /// A minimal on-disk template directory fixture.
+    Directory,
+    /// A template installed with `bevy install` from a local `file://`
+    /// registry — the git-registry code path, minus the network.
+    Registry,
+}
+
+#[derive(Clone, Copy)]
+struct Case {
+    locale: &'static str,
+    source: Source,
+    /// CI provider passed to `--ci-provider`, or "none" for no `--ci`.
+    ci: &'static str,
+    vcs: &'static str,
+}
+
+/// The full matrix; kept explicit so a failure names its exact cell.
+/// Every template source, every CI provider, and both VCS choices appear
+/// in at least one cell of each locale.
+const MATRIX: &[Case] = &[
+    Case {
+        locale: "en",
+        source: Source::Builtin,
+        ci: "none",
+        vcs: "none",
+    },
+    Case {
+        locale: "en",
+        source: Source::Builtin,
+        ci: "github",
+        vcs: "git",
+    },
+    Case {
+        locale: "en",
+        source: Source::Directory,
+        ci: "gitlab",
+        vcs: "git",
+    },
+    Case {
+        locale: "en",
+        source: Source::Registry,
+        ci: "azure",
+        vcs: "none",
+    },
+    Case {
+        locale: "fr",
+        source: Source::Builtin,
+        ci: "circleci",
+        vcs: "git",
+    },
+    Case {
+        locale: "fr",
+        source: Source::Directory,
+        ci: "woodpecker",
+        vcs: "none",
+    },
+    Case {
+        locale: "fr",
+        source: Source::Registry,
+        ci: "forgejo",
+        vcs: "git",
+    },
+];
+
+/// Where a `--ci-provider` writes its pipeline, relative to the project;
+/// `None` for the no-CI cells.
+fn ci_config_file(provider: &str) -> Option<&'static str> {
+    match provider {
+        "github" => Some(".github/workflows/ci.yml"),
+        "gitlab" => Some(".gitlab-ci.yml"),
+        "azure" => Some("azure-pipelines.yml"),
+        "circleci" => Some(".circleci/config.yml"),
+        "woodpecker" => Some(".woodpecker/check.yml"),
+        "forgejo" => Some(".forgejo/workflows/ci.yml"),
+        _ => None,
+    }
+}
+
+/// A scratch area acting as both workdir and fake home; removed on drop.
+struct Sandbox {
+    root: PathBuf,
+}
+
+impl Sandbox {
+    fn new(label: &str) -> Self {
+        let root = std::env::temp_dir().join(format!(
+            "bevy_cli_e2e_{label}_{}_{:?}",
+            std::process::id(),
+            std::thread::current().id()
+        ));
+        let _ = std::fs::remove_dir_all(&root);
+        std::fs::create_dir_all(root.join("home")).unwrap();
+        Self { root }
+    }
+
+    fn bevy(&self, locale: &str) -> Command {
+        let mut command = Command::new(env!("CARGO_BIN_EXE_bevy"));
+        command
+            .current_dir(&self.root)
+            .env("BEVY_CLI_LOCALE", locale)
+            .env("HOME", self.root.join("home"))
+            .env("XDG_CONFIG_HOME", self.root.join("home/.config"))
+            .env("XDG_DATA_HOME", self.root.join("home/.local/share"));
+        command
+    }
+
+    /// Writes the minimal directory-template fixture and returns its path.
+    fn template_fixture(&self) -> PathBuf {
+        let template = self.root.join("fixture-template");
+        write_template(&template);
+        template
+    }
+
+    /// Writes a one-template registry fixture and points the sandboxed
+    /// per-user config at it over `file://`, so `bevy install fixture`
+    /// resolves without the network.
+    fn registry_fixture(&self) {
+        let registry = self.root.join("fixture-registry");
+        std::fs::create_dir_all(&registry).unwrap();
+        std::fs::write(
+            registry.join("templates.toml"),
+            "[[templates]]\nname = \"fixture\"\ndescription = \"e2e fixture\"\npath = \"templates/fixture\"\n",
+        )
+        .unwrap();
+        write_template(&registry.join("templates/fixture"));
+        let config = self.root.join("home/.config/bevy");
+        std::fs::create_dir_all(&config).unwrap();
+        std::fs::write(
+            config.join("config.toml"),
+            format!(
+                "[[registries]]\nname = \"e2e\"\nurl = \"file://{}\"\n",
+                registry.display()
+            ),
+        )
+        .unwrap();
+    }
+}
+
+/// Writes the minimal template used by the directory and registry fixtures.
+fn write_template(template: &Path) {
+    std::fs::create_dir_all(template.join("src")).unwrap();
+    std::fs::write(
+        template.join("bevy_template.toml"),
+        "name = \"fixture\"\ndescription = \"e2e fixture\"\n",
+    )
+    .unwrap();
+    std::fs::write(
+        template.join("Cargo.toml.tera"),
+        "[package]\nname = \"{{ project_name }}\"\nversion = \"0.1.0\"\nedition = \"2021\"\n",
+    )
+    .unwrap();
+    std::fs::write(template.join("src/main.rs"), "fn main() {}\n").unwrap();
+}
+
+impl Drop for Sandbox {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.root);
+    }
+}
+
+fn run_case(sandbox: &Sandbox, case: &Case, name: &str) -> Output {
+    let mut command = sandbox.bevy(case.locale);
+    command.args(["new", name, "--bevy-version", "0.12", "--vcs", case.vcs]);
+    match case.source {
+        Source::Builtin => {
+            command.arg("--builtin-only");
+        }
+        Source::Directory => {
+            command.arg("--template").arg(sandbox.template_fixture());
+        }
+        Source::Registry => {
+            sandbox.registry_fixture();
+            let install = sandbox
+                .bevy(case.locale)
+                .args(["install", "fixture"])
+                .stdin(std::process::Stdio::null())
+                .output()
+                .unwrap();
+            assert!(
+                install.status.success(),
+                "install failed:\n{}",
+                String::from_utf8_lossy(&install.stderr)
+            );
+            command.args(["--template", "fixture"]);
+        }
+    }
+    if case.ci != "none" {
+        command.args(["--ci", "--ci-provider", case.ci]);
+    }
+    command.stdin(std::process::Stdio::null());
+    command.output().unwrap()
+}
+
+fn assert_generated(root: &Path, case: &Case, name: &str, output: &Output) {
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let cell = format!(
+        "locale={} ci={} vcs={}\nstdout:\n{stdout}\nstderr:\n{stderr}",
+        case.locale, case.ci, case.vcs
+    );
+    assert!(output.status.success(), "generation failed for {cell}");
+    let project = root.join(name);
+    assert!(
+        project.join("Cargo.toml").is_file(),
+        "no Cargo.toml for {cell}"
+    );
+    assert_eq!(
+        project.join(".git").is_dir(),
+        case.vcs == "git",
+        "vcs mismatch for {cell}"
+    );
+    match ci_config_file(case.ci) {
+        Some(ci_file) => assert!(
+            project.join(ci_file).is_file(),
+            "missing {ci_file} for {cell}"
+        ),
+        None => assert!(
+            !project.join(".github/workflows/ci.yml").exists(),
+            "unexpected ci config for {cell}"
+        ),
+    }
+    // The closing message comes from the locale under test.
+    let expected = match case.locale {
+        "fr" => "créé dans",
+        _ => "Created `",
+    };
+    assert!(
+        stdout.contains(expected),
+        "missing localized output for {cell}"
+    );
+}
+
+#[test]
+fn generation_matrix_covers_locales_sources_ci_and_vcs() {
+    for (index, case) in MATRIX.iter().enumerate() {
+        let sandbox = Sandbox::new("matrix");
+        let name = format!("demo{index}");
+        let output = run_case(&sandbox, case, &name);
+        assert_generated(&sandbox.root, case, &name, &output);
+    }
+}
+
+#[test]
+fn json_progress_mode_emits_parseable_events() {
+    let sandbox = Sandbox::new("json");
+    let mut command = sandbox.bevy("en");
+    command
+        .args(["--progress", "json"])
+        .args(["new", "demo", "--builtin-only", "--bevy-version", "0.12"])
+        .stdin(std::process::Stdio::null());
+    let output = command.output().unwrap();
+    assert!(output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let events: Vec<serde_json::Value> = stderr
+        .lines()
+        .filter(|line| line.starts_with('{'))
+        .map(|line| serde_json::from_str(line).expect("progress lines are JSON"))
+        .collect();
+    assert!(!events.is_empty(), "no progress events in:\n{stderr}");
+    for event in &events {
+        assert!(event["phase"].is_string(), "event without phase: {event}");
+        assert!(event["percent"].is_u64(), "event without percent: {event}");
+    }
+}
+
+#[test]
+fn help_screens_follow_the_locale() {
+    let sandbox = Sandbox::new("help");
+    let expected = [
+        ("en", "Project scaffolding and workflow CLI for Bevy"),
+        (
+            "fr",
+            "CLI d'échafaudage de projets et de flux de travail pour Bevy",
+        ),
+    ];
+    for (locale, about) in expected {
+        let output = sandbox
+            .bevy(locale)
+            .arg("--help")
+            .stdin(std::process::Stdio::null())
+            .output()
+            .unwrap();
+        assert!(output.status.success());
+        let help = String::from_utf8_lossy(&output.stdout);
+        assert!(
+            help.contains(about),
+            "wrong about line for {locale}:\n{help}"
+        );
+        // The command list itself is stable across locales.
+        for fragment in ["Usage:", "new", "build", "assets"] {
+            assert!(
+                help.contains(fragment),
+                "no `{fragment}` for {locale}:\n{help}"
+            );
+        }
+    }
+}
+
+#[test]
+fn unknown_locales_fall_back_to_english() {
+    let sandbox = Sandbox::new("fallback");
+    let mut command = sandbox.bevy("tlh");
+    command
+        .args(["new", "demo", "--builtin-only", "--bevy-version", "0.12"])
+        .stdin(std::process::Stdio::null());
+    let output = command.output().unwrap();
+    assert!(output.status.success());
+    assert!(String::from_utf8_lossy(&output.stdout).contains("Created `"));
+}